@@ -13,9 +13,15 @@ import ffmpeg from 'fluent-ffmpeg'
 import path from 'path'
 import chalk from 'chalk'
 import mimeTypes from 'mime-types'
+import crypto from 'crypto'
+import { blake2AsU8a } from '@polkadot/util-crypto'
+import { u8aConcat } from '@polkadot/util'
 
 ffmpeg.setFfprobePath(ffprobeInstaller.path)
 
+// Size of the content chunks the merkle root is computed over (matches the runtime DATA_OBJECT_CHUNK_SIZE)
+const DATA_OBJECT_CHUNK_SIZE = 262144
+
 /**
  * Abstract base class for commands that require uploading functionality
  */
@@ -125,6 +131,42 @@ export default abstract class UploadCommandBase extends ContentDirectoryCommandB
     return hash
   }
 
+  async calculateFileSha256(filePath: string): Promise<Buffer> {
+    const { fileStream } = this.createReadStreamWithProgressBar(filePath, 'Calculating file sha256')
+    const hash = crypto.createHash('sha256')
+
+    return new Promise<Buffer>((resolve) => {
+      fileStream
+        .on('data', (data) => hash.update(data))
+        .on('end', () => resolve(hash.digest()))
+        .resume()
+    })
+  }
+
+  // Leaves are the chunk hashes, the last node of a level without a sibling is promoted to the next level
+  calculateFileChunksMerkleRoot(filePath: string): Uint8Array {
+    const fd = fs.openSync(filePath, 'r')
+    const buffer = Buffer.alloc(DATA_OBJECT_CHUNK_SIZE)
+    let level: Uint8Array[] = []
+    try {
+      let bytesRead: number
+      while ((bytesRead = fs.readSync(fd, buffer, 0, DATA_OBJECT_CHUNK_SIZE, null)) > 0) {
+        level.push(blake2AsU8a(buffer.subarray(0, bytesRead)))
+      }
+    } finally {
+      fs.closeSync(fd)
+    }
+    // Empty file consists of a single empty chunk
+    if (!level.length) {
+      level.push(blake2AsU8a(new Uint8Array()))
+    }
+    while (level.length > 1) {
+      level = _.chunk(level, 2).map(([left, right]) => (right ? blake2AsU8a(u8aConcat(left, right)) : left))
+    }
+
+    return level[0]
+  }
+
   validateFile(filePath: string): void {
     // Basic file validation
     if (!fs.existsSync(filePath)) {
@@ -158,6 +200,8 @@ export default abstract class UploadCommandBase extends ContentDirectoryCommandB
       type_id: type,
       size: this.getFileSize(filePath),
       ipfs_content_id: await this.calculateFileIpfsHash(filePath),
+      content_hash: { algorithm: 'Sha256', digest: await this.calculateFileSha256(filePath) },
+      chunks_merkle_root: this.calculateFileChunksMerkleRoot(filePath),
     })
   }
 
@@ -1,11 +1,19 @@
 use codec::Decode;
 use node_runtime::{
-    common::storage::StorageObjectOwner, data_directory::*, ChannelId, ContentId, DAOId,
-    DataDirectoryConfig, MemberId, Runtime,
+    common::storage::StorageObjectOwner,
+    content::{
+        Channel, ChannelCategory, ContentActorAuthenticator, CuratorGroup, Video, VideoCategory,
+    },
+    data_directory::*,
+    storage_migration::OldDataObject,
+    BlockNumber, ChannelCategoryId, ChannelId, ContentConfig, ContentId, DAOId,
+    DataDirectoryConfig, MemberId, Runtime, SeriesId, VideoCategoryId, VideoId,
 };
 use serde::Deserialize;
 use std::{fs, path::Path};
 
+type CuratorGroupId = <Runtime as ContentActorAuthenticator>::CuratorGroupId;
+
 // Because of the way that the @joystream/types were implemented the getters for
 // the string types return a `string` not the `Text` type so when we are serializing
 // them to json we get a string rather than an array of bytes, so deserializing them
@@ -52,9 +60,17 @@ impl EncodedContent {
             .expect("failed to parse content_id hex string");
         let encoded_voucher = hex::decode(&self.voucher[2..].as_bytes())
             .expect("failed to parse data_object hex string");
+        // Data objects exported before the content hashes lack the content hash and
+        // the chunks merkle root, they are imported without them
+        let data_object = DataObject::<Runtime>::decode(&mut encoded_data_object.as_slice())
+            .or_else(|_| {
+                OldDataObject::<Runtime>::decode(&mut encoded_data_object.as_slice())
+                    .map(|old_data_object| old_data_object.migrate())
+            })
+            .unwrap();
         Content {
             content_id: Decode::decode(&mut encoded_content_id.as_slice()).unwrap(),
-            data_object: Decode::decode(&mut encoded_data_object.as_slice()).unwrap(),
+            data_object,
             storage_object_owner: Decode::decode(&mut encoded_storage_object_owner.as_slice())
                 .unwrap(),
             voucher: Decode::decode(&mut encoded_voucher.as_slice()).unwrap(),
@@ -165,3 +181,115 @@ pub fn data_directory_config_from_json(data_file: &Path) -> DataDirectoryConfig
         uploading_blocked: content.uploading_blocked,
     }
 }
+
+#[derive(Decode)]
+struct ContentDirectoryData {
+    curator_groups: Vec<(CuratorGroupId, CuratorGroup<Runtime>)>,
+    channel_categories: Vec<(ChannelCategoryId, ChannelCategory<Runtime>)>,
+    channels: Vec<(ChannelId, Channel<Runtime>)>,
+    video_categories: Vec<(VideoCategoryId, VideoCategory<Runtime>)>,
+    videos: Vec<(
+        VideoId,
+        Video<ChannelId, SeriesId, ContentId, VideoCategoryId, BlockNumber>,
+    )>,
+}
+
+#[derive(Deserialize)]
+struct EncodedContentDirectoryData {
+    /// hex encoded Vec<(CuratorGroupId, CuratorGroup)>
+    curator_groups: String,
+    /// hex encoded Vec<(ChannelCategoryId, ChannelCategory)>
+    channel_categories: String,
+    /// hex encoded Vec<(ChannelId, Channel)>
+    channels: String,
+    /// hex encoded Vec<(VideoCategoryId, VideoCategory)>
+    video_categories: String,
+    /// hex encoded Vec<(VideoId, Video)>
+    videos: String,
+}
+
+fn decode_hex<T: Decode>(encoded: &str, name: &str) -> T {
+    // hex string must not include '0x' prefix!
+    let bytes = hex::decode(&encoded[2..].as_bytes())
+        .unwrap_or_else(|_| panic!("failed to parse {} hex string", name));
+
+    Decode::decode(&mut bytes.as_slice()).unwrap_or_else(|_| panic!("failed to decode {}", name))
+}
+
+impl EncodedContentDirectoryData {
+    fn decode(&self) -> ContentDirectoryData {
+        ContentDirectoryData {
+            curator_groups: decode_hex(&self.curator_groups, "curator_groups"),
+            channel_categories: decode_hex(&self.channel_categories, "channel_categories"),
+            channels: decode_hex(&self.channels, "channels"),
+            video_categories: decode_hex(&self.video_categories, "video_categories"),
+            videos: decode_hex(&self.videos, "videos"),
+        }
+    }
+}
+
+fn parse_content_directory_data(data_file: &Path) -> EncodedContentDirectoryData {
+    let data = fs::read_to_string(data_file).expect("Failed reading file");
+    serde_json::from_str(&data).expect("failed parsing content directory data")
+}
+
+// Next id following the highest imported one
+fn next_id<Id: Copy + Ord + From<u8> + std::ops::Add<Output = Id>, Record>(
+    records: &[(Id, Record)],
+) -> Id {
+    records
+        .iter()
+        .map(|(id, _)| *id + Id::from(1))
+        .max()
+        .unwrap_or_else(|| Id::from(1))
+}
+
+/// Generates a basic empty `ContentConfig` genesis config
+pub fn empty_content_config() -> ContentConfig {
+    let default_text_constraint = node_runtime::working_group::default_text_constraint();
+
+    ContentConfig {
+        curator_group_by_id: vec![],
+        channel_category_by_id: vec![],
+        channel_by_id: vec![],
+        video_category_by_id: vec![],
+        video_by_id: vec![],
+        next_curator_group_id: 1,
+        next_channel_category_id: 1,
+        next_channel_id: 1,
+        next_video_category_id: 1,
+        next_video_id: 1,
+        next_playlist_id: 1,
+        next_series_id: 1,
+        next_person_id: 1,
+        next_channel_transfer_request_id: 1,
+        next_censorship_appeal_id: 1,
+        next_comment_id: 1,
+        next_content_report_id: 1,
+        next_subscription_tier_id: 1,
+        comment_text_constraint: default_text_constraint,
+        comment_moderation_rationale_constraint: default_text_constraint,
+        content_mint_capacity: 0,
+    }
+}
+
+/// Generates a `ContentConfig` genesis config
+/// pre-populated with curator groups, categories, channels and videos parsed from
+/// a json file serialized as a `ContentDirectoryData` struct
+pub fn content_config_from_json(data_file: &Path) -> ContentConfig {
+    let content = parse_content_directory_data(data_file).decode();
+
+    ContentConfig {
+        next_curator_group_id: next_id(&content.curator_groups),
+        next_channel_category_id: next_id(&content.channel_categories),
+        next_channel_id: next_id(&content.channels),
+        next_video_category_id: next_id(&content.video_categories),
+        next_video_id: next_id(&content.videos),
+        curator_group_by_id: content.curator_groups,
+        channel_category_by_id: content.channel_categories,
+        channel_by_id: content.channels,
+        video_category_by_id: content.video_categories,
+        video_by_id: content.videos,
+        ..empty_content_config()
+    }
+}
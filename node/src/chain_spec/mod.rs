@@ -139,6 +139,7 @@ impl Alternative {
                         initial_members::none(),
                         forum_config::empty(get_account_id_from_seed::<sr25519::Public>("Alice")),
                         content_config::empty_data_directory_config(),
+                        content_config::empty_content_config(),
                         vec![],
                     )
                 },
@@ -177,6 +178,7 @@ impl Alternative {
                         initial_members::none(),
                         forum_config::empty(get_account_id_from_seed::<sr25519::Public>("Alice")),
                         content_config::empty_data_directory_config(),
+                        content_config::empty_content_config(),
                         vec![],
                     )
                 },
@@ -220,6 +222,7 @@ pub fn testnet_genesis(
     members: Vec<membership::genesis::Member<u64, AccountId, Moment>>,
     forum_config: ForumConfig,
     data_directory_config: DataDirectoryConfig,
+    content_config: ContentConfig,
     initial_balances: Vec<(AccountId, Balance)>,
 ) -> GenesisConfig {
     const STASH: Balance = 5_000;
@@ -342,19 +345,7 @@ pub fn testnet_genesis(
             worker_exit_rationale_text_constraint: default_text_constraint,
             worker_storage_size_constraint: default_storage_size_constraint,
         }),
-        content: Some({
-            ContentConfig {
-                next_curator_group_id: 1,
-                next_channel_category_id: 1,
-                next_channel_id: 1,
-                next_video_category_id: 1,
-                next_video_id: 1,
-                next_playlist_id: 1,
-                next_series_id: 1,
-                next_person_id: 1,
-                next_channel_transfer_request_id: 1,
-            }
-        }),
+        content: Some(content_config),
         proposals_codex: Some(ProposalsCodexConfig {
             set_validator_count_proposal_voting_period: cpcp
                 .set_validator_count_proposal_voting_period,
@@ -421,6 +412,7 @@ pub(crate) mod tests {
             initial_members::none(),
             forum_config::empty(get_account_id_from_seed::<sr25519::Public>("Alice")),
             content_config::empty_data_directory_config(),
+            content_config::empty_content_config(),
             vec![],
         )
     }
@@ -455,6 +447,7 @@ pub(crate) mod tests {
             initial_members::none(),
             forum_config::empty(get_account_id_from_seed::<sr25519::Public>("Alice")),
             content_config::empty_data_directory_config(),
+            content_config::empty_content_config(),
             vec![],
         )
     }
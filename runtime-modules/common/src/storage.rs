@@ -11,6 +11,48 @@ pub struct ContentParameters<ContentId, DataObjectTypeId> {
     pub type_id: DataObjectTypeId,
     pub size: u64,
     pub ipfs_content_id: Vec<u8>,
+    pub content_hash: ContentHash,
+    pub chunks_merkle_root: Vec<u8>,
+}
+
+// Hashing algorithm of the content hash, the multihash code is given for each algorithm
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum ContentHashAlgorithm {
+    Sha256,     // multihash code 0x12
+    Sha512,     // multihash code 0x13
+    Blake2b256, // multihash code 0xb220
+}
+
+impl Default for ContentHashAlgorithm {
+    fn default() -> Self {
+        Self::Sha256
+    }
+}
+
+impl ContentHashAlgorithm {
+    // Length of the digest in bytes
+    pub fn digest_length(&self) -> usize {
+        match self {
+            Self::Sha256 | Self::Blake2b256 => 32,
+            Self::Sha512 => 64,
+        }
+    }
+}
+
+// Multihash of the whole content: the hashing algorithm tag along with the digest
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug, Default)]
+pub struct ContentHash {
+    pub algorithm: ContentHashAlgorithm,
+    pub digest: Vec<u8>,
+}
+
+impl ContentHash {
+    // Checks the digest length matches the hashing algorithm
+    pub fn is_valid(&self) -> bool {
+        self.digest.len() == self.algorithm.digest_length()
+    }
 }
 
 // New owner type for storage object struct
@@ -49,9 +91,22 @@ pub trait StorageSystem<T: crate::StorageOwnership + crate::MembershipTypes> {
         content_ids: &[T::ContentId],
     ) -> DispatchResult;
 
+    // Removes all content of the given owner and releases its voucher.
+    // Given content ids must cover all the content the owner has in the storage frame_system.
+    fn atomically_remove_all_content(
+        owner: &StorageObjectOwner<T::MemberId, T::ChannelId, T::DAOId>,
+        content_ids: &[T::ContentId],
+    ) -> DispatchResult;
+
     // Checks if given owner can remove content under given content ids from the storage frame_system
     fn can_remove_content(
         owner: &StorageObjectOwner<T::MemberId, T::ChannelId, T::DAOId>,
         content_ids: &[T::ContentId],
     ) -> DispatchResult;
+
+    // Lists all the content in the storage frame_system together with its owner
+    fn content_owners() -> Vec<(
+        T::ContentId,
+        StorageObjectOwner<T::MemberId, T::ChannelId, T::DAOId>,
+    )>;
 }
@@ -62,6 +62,228 @@ decl_error! {
         VideoInSeason,
 
         /// Curators can only censor non-curator group owned channels
-        CannotCensoreCuratorGroupOwnedChannels
+        CannotCensoreCuratorGroupOwnedChannels,
+
+        /// Channel ownership transfer request does not exist
+        ChannelOwnershipTransferRequestDoesNotExist,
+
+        /// Requested new owner already owns the channel
+        ActorIsAlreadyChannelOwner,
+
+        /// Playlist does not exist
+        PlaylistDoesNotExist,
+
+        /// Playlist does not belong to the given channel
+        PlaylistNotInChannel,
+
+        /// Max number of videos per playlist limit reached
+        VideosPerPlaylistLimitReached,
+
+        /// Video is included more than once in a playlist
+        DuplicateVideoInPlaylist,
+
+        /// Video does not belong to the channel of a non cross channel playlist
+        VideoNotInPlaylistChannel,
+
+        /// Series does not exist
+        SeriesDoesNotExist,
+
+        /// Parameters must be provided for seasons extending a series
+        SeasonParametersMissing,
+
+        /// Parameters must be provided for episodes extending a season
+        EpisodeParametersMissing,
+
+        /// Video is used as an episode more than once in a series
+        DuplicateEpisodeInSeries,
+
+        /// Video does not belong to the channel of the series
+        VideoNotInSeriesChannel,
+
+        /// Video is already an episode of another series
+        VideoAlreadyInSeries,
+
+        /// Person does not exist
+        PersonDoesNotExist,
+
+        /// Person is already credited in the video
+        PersonAlreadyInVideo,
+
+        /// Person is not credited in the video
+        PersonNotInVideo,
+
+        /// DAO authentication failed
+        DaoAuthFailed,
+
+        /// Asset is not an uploaded asset of the channel
+        ChannelAssetNotFound,
+
+        /// Asset is not an uploaded asset of the video
+        VideoAssetNotFound,
+
+        /// Curator group is not permitted to perform given action
+        CuratorGroupLacksPermission,
+
+        /// Channel or video is not censored
+        ContentIsNotCensored,
+
+        /// Censorship of the channel or video was already appealed
+        CensorshipAlreadyAppealed,
+
+        /// Balance too low to reserve the censorship appeal stake
+        InsufficientBalanceForAppealStake,
+
+        /// Censorship appeal does not exist
+        CensorshipAppealDoesNotExist,
+
+        /// Censorship appeal was already resolved
+        CensorshipAppealIsNotPending,
+
+        /// Category cannot be nested any deeper
+        CategoryDepthLimitReached,
+
+        /// Category still has channels or videos assigned
+        CategoryHasItems,
+
+        /// Category still has subcategories
+        CategoryHasSubcategories,
+
+        /// Channel collaborator must be granted at least one permission
+        NoCollaboratorPermissions,
+
+        /// Member is not a collaborator of the channel
+        CollaboratorDoesNotExist,
+
+        /// Member already liked the video
+        VideoAlreadyLiked,
+
+        /// Member has not liked the video
+        VideoNotLiked,
+
+        /// Comments are disabled for the video
+        VideoCommentsDisabled,
+
+        /// Comment does not exist in the thread of the video
+        CommentDoesNotExist,
+
+        /// Comment was already moderated
+        CommentAlreadyModerated,
+
+        /// Comment text too short
+        CommentTextTooShort,
+
+        /// Comment text too long
+        CommentTextTooLong,
+
+        /// Comment moderation rationale too short
+        CommentModerationRationaleTooShort,
+
+        /// Comment moderation rationale too long
+        CommentModerationRationaleTooLong,
+
+        /// Scheduled video must not be public already
+        VideoIsAlreadyPublic,
+
+        /// Video can only be scheduled for publishing at a future block
+        VideoPublishBlockNotInFuture,
+
+        /// NFT was already issued for the video
+        NftAlreadyIssued,
+
+        /// NFT was not issued for the video
+        NftDoesNotExist,
+
+        /// Creator royalty exceeds the maximum allowed
+        CreatorRoyaltyTooHigh,
+
+        /// NFT is on sale
+        NftIsOnSale,
+
+        /// NFT is not on sale
+        NftIsNotOnSale,
+
+        /// NFT is not sold in an auction
+        NftIsNotInAuction,
+
+        /// Auction duration is shorter than the minimum allowed
+        AuctionDurationTooShort,
+
+        /// Auction duration is longer than the maximum allowed
+        AuctionDurationTooLong,
+
+        /// Bid is lower than the starting price or does not exceed the top bid by the minimal step
+        BidTooLow,
+
+        /// Auction which received bids cannot be canceled
+        AuctionHasBids,
+
+        /// Video NFT must be held by the channel owner and not on sale for the video to be removed
+        VideoNftIsNotRemovable,
+
+        /// No payment from the account is waiting to be claimed by the sender
+        NoUnclaimedPayment,
+
+        /// Content mint was not created
+        ContentMintNotFound,
+
+        /// Content mint capacity is too low to pay the reward
+        InsufficientContentMintCapacity,
+
+        /// Channel reward account must be set to claim rewards
+        ChannelRewardAccountNotSet,
+
+        /// Reward claim does not match the rewards commitment
+        RewardClaimProofInvalid,
+
+        /// Channel earnings were claimed already
+        RewardAlreadyClaimed,
+
+        /// Number of featured videos exceeds the maximum allowed
+        FeaturedVideosLimitExceeded,
+
+        /// Censored videos and videos of censored channels cannot be featured
+        CensoredVideoCannotBeFeatured,
+
+        /// Only public videos can be featured
+        NonPublicVideoCannotBeFeatured,
+
+        /// Video batch is empty or exceeds the maximum number of videos per batch
+        InvalidVideoBatchSize,
+
+        /// Video does not belong to the channel
+        VideoNotInChannel,
+
+        /// Video is included more than once in the batch
+        DuplicateVideoInBatch,
+
+        /// Censored channels and videos cannot be reported
+        ContentIsAlreadyCensored,
+
+        /// Member already has a pending report of the channel or video
+        ContentAlreadyReported,
+
+        /// Insufficient balance to reserve the report stake
+        InsufficientBalanceForReportStake,
+
+        /// Channel or video has no pending reports
+        NoPendingContentReports,
+
+        /// Subscription tier does not exist
+        SubscriptionTierDoesNotExist,
+
+        /// Subscription tier must have a price
+        SubscriptionPriceIsZero,
+
+        /// Subscription period must be at least the minimum subscription period
+        SubscriptionPeriodTooShort,
+
+        /// Member is already subscribed to the channel
+        AlreadySubscribedToChannel,
+
+        /// Member is not subscribed to the channel
+        NotSubscribedToChannel,
+
+        /// Insufficient balance to pay the subscription
+        InsufficientBalanceForSubscription
     }
 }
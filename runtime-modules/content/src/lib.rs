@@ -6,10 +6,21 @@
 mod tests;
 
 mod errors;
+mod migration;
+mod nft;
 mod permissions;
+mod reports;
+mod rewards;
+mod runtime_api;
+mod subscriptions;
 
 pub use errors::*;
+pub use nft::*;
 pub use permissions::*;
+pub use reports::*;
+pub use rewards::*;
+pub use runtime_api::*;
+pub use subscriptions::*;
 
 use core::hash::Hash;
 
@@ -17,13 +28,20 @@ use codec::Codec;
 use codec::{Decode, Encode};
 
 use frame_support::{
-    decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, traits::Get, Parameter,
+    decl_event, decl_module, decl_storage,
+    dispatch::DispatchResult,
+    ensure,
+    storage::IterableStorageDoubleMap,
+    traits::{BalanceStatus, Currency, ExistenceRequirement, Get, ReservableCurrency},
+    weights::Weight,
+    Parameter, StorageDoubleMap,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_root, ensure_signed};
 #[cfg(feature = "std")]
 pub use serde::{Deserialize, Serialize};
-use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
-use sp_runtime::traits::{MaybeSerializeDeserialize, Member};
+use sp_arithmetic::traits::{BaseArithmetic, One, Saturating, Zero};
+use sp_runtime::traits::{AccountIdConversion, MaybeSerializeDeserialize, Member};
+use sp_runtime::{ModuleId, Perbill};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -34,6 +52,7 @@ pub use common::storage::{
 };
 
 pub use common::{
+    constraints::InputValidationLengthConstraint,
     currency::{BalanceOf, GovernanceCurrency},
     working_group::WorkingGroup,
     MembershipTypes, StorageOwnership, Url,
@@ -82,6 +101,7 @@ pub trait Trait:
     + StorageOwnership
     + MembershipTypes
     + GovernanceCurrency
+    + minting::Trait
 {
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -89,6 +109,12 @@ pub trait Trait:
     /// Channel Transfer Payments Escrow Account seed for ModuleId to compute deterministic AccountId
     type ChannelOwnershipPaymentEscrowId: Get<[u8; 8]>;
 
+    /// Video NFT Payments Escrow Account seed for ModuleId to compute deterministic AccountId
+    type NftPaymentEscrowId: Get<[u8; 8]>;
+
+    /// Content Report Stake Pool Account seed for ModuleId to compute deterministic AccountId
+    type ContentReportPoolId: Get<[u8; 8]>;
+
     /// Type of identifier for Videos
     type VideoId: NumericIdentifier;
 
@@ -110,9 +136,60 @@ pub trait Trait:
     /// Type of identifier for Channel transfer requests
     type ChannelOwnershipTransferRequestId: NumericIdentifier;
 
+    /// Type of identifier for censorship appeals
+    type CensorshipAppealId: NumericIdentifier;
+
+    /// Type of identifier for video comments
+    type CommentId: NumericIdentifier;
+
+    /// Type of identifier for content reports
+    type ContentReportId: NumericIdentifier;
+
+    /// Type of identifier for channel subscription tiers
+    type SubscriptionTierId: NumericIdentifier;
+
+    /// Stake reserved from the appellant when filing a censorship appeal
+    type CensorshipAppealStake: Get<BalanceOf<Self>>;
+
+    /// Number of blocks the lead has to resolve a censorship appeal before it is granted
+    type CensorshipAppealPeriod: Get<Self::BlockNumber>;
+
+    /// Stake reserved from the reporter when reporting a channel or video
+    type ContentReportStake: Get<BalanceOf<Self>>;
+
+    /// Reward paid from the report stake pool to the reporter when reported content is censored
+    type ContentReportReward: Get<BalanceOf<Self>>;
+
     /// The maximum number of curators per group constraint
     type MaxNumberOfCuratorsPerGroup: Get<MaxNumber>;
 
+    /// The maximum number of videos per playlist constraint
+    type MaxNumberOfVideosPerPlaylist: Get<MaxNumber>;
+
+    /// The maximum number of featured videos per list constraint
+    type MaxNumberOfFeaturedVideos: Get<MaxNumber>;
+
+    /// The maximum number of videos created, updated or deleted in a single batch
+    type MaxNumberOfVideosPerBatch: Get<MaxNumber>;
+
+    /// The maximum depth of channel and video subcategories, top level categories have depth zero
+    type MaxCategoryDepth: Get<MaxNumber>;
+
+    /// The maximum share of video NFT secondary sales paid to the channel reward account
+    type MaxCreatorRoyalty: Get<Perbill>;
+
+    /// The minimum number of blocks a video NFT auction accepts bids for
+    type MinAuctionDuration: Get<Self::BlockNumber>;
+
+    /// The maximum number of blocks a video NFT auction accepts bids for
+    type MaxAuctionDuration: Get<Self::BlockNumber>;
+
+    /// The minimum number of blocks paid for by a single channel subscription payment
+    type MinSubscriptionPeriod: Get<Self::BlockNumber>;
+
+    /// The maximum number of channel subscriptions renewed or expired in a single block
+    type MaxSubscriptionRenewalsPerBlock: Get<u32>;
+
     // Type that handles asset uploads to storage frame_system
     type StorageSystem: StorageSystem<Self>;
 }
@@ -141,6 +218,18 @@ pub enum ChannelOwner<MemberId, CuratorGroupId, DAOId> {
     Dao(DAOId),
 }
 
+/// Actions a channel collaborator can be permitted to perform on behalf of the channel owner
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum ChannelCollaboratorPermission {
+    /// Create and delete videos and series of the channel
+    UploadVideos,
+    /// Update channel and video metadata and assets
+    EditMetadata,
+    /// Create, update and delete playlists of the channel
+    ManagePlaylists,
+}
+
 // simplification type
 pub(crate) type ActorToChannelOwnerResult<T> = Result<
     ChannelOwner<
@@ -161,19 +250,43 @@ impl<MemberId: Default, CuratorGroupId, DAOId> Default
     }
 }
 
-/// A category which channels can belong to.
+/// A category which channels or videos can belong to.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct ChannelCategory {
-    // No runtime information is currently stored for a Category.
+pub struct CategoryRecord<CategoryId> {
+    /// Category this category is nested in, if any
+    pub parent_category_id: Option<CategoryId>,
+    /// Number of ancestors of the category
+    pub depth: MaxNumber,
+    /// Number of categories nested directly in this category
+    pub subcategories_count: u32,
+    /// Number of channels or videos assigned to this category
+    pub items_count: u64,
+}
+
+impl<CategoryId> CategoryRecord<CategoryId> {
+    /// Ensure there are no channels, videos or subcategories referencing the category
+    fn ensure_is_unreferenced<T: Trait>(&self) -> DispatchResult {
+        ensure!(self.items_count == 0, Error::<T>::CategoryHasItems);
+        ensure!(
+            self.subcategories_count == 0,
+            Error::<T>::CategoryHasSubcategories
+        );
+        Ok(())
+    }
 }
 
+/// A category which channels can belong to.
+pub type ChannelCategory<T> = CategoryRecord<<T as Trait>::ChannelCategoryId>;
+
 /// Information on the category being created.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct ChannelCategoryCreationParameters {
+pub struct ChannelCategoryCreationParameters<ChannelCategoryId> {
     /// Metadata for the category.
     meta: Vec<u8>,
+    /// If set, category the new category is nested in.
+    parent_category_id: Option<ChannelCategoryId>,
 }
 
 /// Information on the category being updated.
@@ -189,8 +302,17 @@ pub struct ChannelCategoryUpdateParameters {
 /// If a channel is deleted, all videos, playlists and series will also be deleted.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct ChannelRecord<MemberId, CuratorGroupId, DAOId, AccountId, VideoId, PlaylistId, SeriesId>
-{
+pub struct ChannelRecord<
+    MemberId,
+    CuratorGroupId,
+    DAOId,
+    AccountId,
+    VideoId,
+    PlaylistId,
+    SeriesId,
+    ContentId,
+    ChannelCategoryId,
+> {
     /// The owner of a channel
     owner: ChannelOwner<MemberId, CuratorGroupId, DAOId>,
     /// The videos under this channel
@@ -203,6 +325,10 @@ pub struct ChannelRecord<MemberId, CuratorGroupId, DAOId, AccountId, VideoId, Pl
     is_censored: bool,
     /// Reward account where revenue is sent if set.
     reward_account: Option<AccountId>,
+    /// Content ids of the channel assets uploaded to the storage system
+    pub assets: Vec<ContentId>,
+    /// Category the channel is assigned to
+    pub category: Option<ChannelCategoryId>,
 }
 
 // Channel alias type for simplification.
@@ -214,6 +340,8 @@ pub type Channel<T> = ChannelRecord<
     <T as Trait>::VideoId,
     <T as Trait>::PlaylistId,
     <T as Trait>::SeriesId,
+    ContentId<T>,
+    <T as Trait>::ChannelCategoryId,
 >;
 
 /// A request to buy a channel by a new ChannelOwner.
@@ -227,9 +355,15 @@ pub struct ChannelOwnershipTransferRequestRecord<
     Balance,
     AccountId,
 > {
+    /// The channel being requested
     channel_id: ChannelId,
+    /// The requested new owner of the channel
     new_owner: ChannelOwner<MemberId, CuratorGroupId, DAOId>,
+    /// Amount offered to the current owner, held in escrow until the request is resolved
     payment: Balance,
+    /// Account the payment is taken from, and refunded to if the request is withdrawn
+    payer: AccountId,
+    /// Reward account of the channel after the transfer
     new_reward_account: Option<AccountId>,
 }
 
@@ -243,6 +377,104 @@ pub type ChannelOwnershipTransferRequest<T> = ChannelOwnershipTransferRequestRec
     <T as frame_system::Trait>::AccountId,
 >;
 
+/// Information about the channel ownership transfer being requested.
+/// The payment is taken from the account signing the request.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ChannelOwnershipTransferRequestParametersRecord<
+    ChannelId,
+    MemberId,
+    CuratorGroupId,
+    DAOId,
+    Balance,
+    AccountId,
+> {
+    /// The channel being requested
+    channel_id: ChannelId,
+    /// The requested new owner of the channel
+    new_owner: ChannelOwner<MemberId, CuratorGroupId, DAOId>,
+    /// Amount offered to the current owner, held in escrow until the request is resolved
+    payment: Balance,
+    /// Reward account of the channel after the transfer
+    new_reward_account: Option<AccountId>,
+}
+
+// ChannelOwnershipTransferRequestParameters type alias for simplification.
+pub type ChannelOwnershipTransferRequestParameters<T> =
+    ChannelOwnershipTransferRequestParametersRecord<
+        <T as StorageOwnership>::ChannelId,
+        <T as MembershipTypes>::MemberId,
+        <T as ContentActorAuthenticator>::CuratorGroupId,
+        <T as StorageOwnership>::DAOId,
+        BalanceOf<T>,
+        <T as frame_system::Trait>::AccountId,
+    >;
+
+/// Channel or video which can be reported, or which censorship can be appealed.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum CensoredContent<ChannelId, VideoId> {
+    /// Censored channel
+    Channel(ChannelId),
+    /// Censored video
+    Video(VideoId),
+}
+
+// Default trait implemented only because its used in a CensorshipAppeal which needs to implement a Default trait
+// since it is a StorageValue.
+impl<ChannelId: Default, VideoId> Default for CensoredContent<ChannelId, VideoId> {
+    fn default() -> Self {
+        CensoredContent::Channel(ChannelId::default())
+    }
+}
+
+/// Stage of a censorship appeal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum CensorshipAppealStatus {
+    /// Awaiting resolution
+    Pending,
+    /// Censorship was lifted, the stake was refunded
+    Accepted,
+    /// Censorship was upheld, the stake was slashed
+    Rejected,
+    /// Appeal was not resolved in time, censorship was lifted and the stake was refunded
+    Expired,
+    /// Content was uncensored or deleted before the appeal was resolved, the stake was refunded
+    Closed,
+}
+
+impl Default for CensorshipAppealStatus {
+    fn default() -> Self {
+        CensorshipAppealStatus::Pending
+    }
+}
+
+/// An appeal against censorship of a channel or video, filed by the channel owner.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct CensorshipAppealRecord<ChannelId, VideoId, AccountId, Balance, BlockNumber> {
+    /// The appealed channel or video
+    pub censored: CensoredContent<ChannelId, VideoId>,
+    /// Account the stake is reserved on
+    pub appellant: AccountId,
+    /// Stake reserved until the appeal is resolved
+    pub stake: Balance,
+    /// Block at which the appeal is granted if still pending
+    pub deadline: BlockNumber,
+    /// Current stage of the appeal
+    pub status: CensorshipAppealStatus,
+}
+
+// CensorshipAppeal type alias for simplification.
+pub type CensorshipAppeal<T> = CensorshipAppealRecord<
+    <T as StorageOwnership>::ChannelId,
+    <T as Trait>::VideoId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
 /// Information about channel being created.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
@@ -268,18 +500,16 @@ pub struct ChannelUpdateParameters<ContentParameters, AccountId> {
 }
 
 /// A category that videos can belong to.
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct VideoCategory {
-    // No runtime information is currently stored for a Category.
-}
+pub type VideoCategory<T> = CategoryRecord<<T as Trait>::VideoCategoryId>;
 
 /// Information about the video category being created.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct VideoCategoryCreationParameters {
+pub struct VideoCategoryCreationParameters<VideoCategoryId> {
     /// Metadata about the video category.
     meta: Vec<u8>,
+    /// If set, category the new category is nested in.
+    parent_category_id: Option<VideoCategoryId>,
 }
 
 /// Information about the video category being updated.
@@ -291,6 +521,24 @@ pub struct VideoCategoryUpdateParameters {
     new_meta: Vec<u8>,
 }
 
+/// Who can discover and watch a video.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VideoVisibility {
+    /// Only visible to the channel owner and collaborators
+    Draft,
+    /// Watchable by anyone who knows the video, but not listed
+    Unlisted,
+    /// Listed and watchable by anyone
+    Public,
+}
+
+impl Default for VideoVisibility {
+    fn default() -> Self {
+        VideoVisibility::Public
+    }
+}
+
 /// Information about the video being created.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
@@ -299,6 +547,8 @@ pub struct VideoCreationParameters<ContentParameters> {
     assets: Vec<NewAsset<ContentParameters>>,
     /// Metadata for the video.
     meta: Vec<u8>,
+    /// Initial visibility of the video.
+    visibility: VideoVisibility,
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -313,19 +563,43 @@ pub struct VideoUpdateParameters<ContentParameters> {
 /// A video which belongs to a channel. A video may be part of a series or playlist.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct Video<ChannelId, SeriesId> {
+pub struct Video<ChannelId, SeriesId, ContentId, VideoCategoryId, BlockNumber> {
     pub in_channel: ChannelId,
     // keep track of which season the video is in if it is an 'episode'
     // - prevent removing a video if it is in a season (because order is important)
     pub in_series: Option<SeriesId>,
     /// Whether the curators have censored the video or not.
     pub is_censored: bool,
+    /// Content ids of the video assets uploaded to the storage system
+    pub assets: Vec<ContentId>,
+    /// Category the video is assigned to
+    pub category: Option<VideoCategoryId>,
+    /// Who can discover and watch the video
+    pub visibility: VideoVisibility,
+    /// Block at which the video is scheduled to become public
+    pub publish_at: Option<BlockNumber>,
+}
+
+/// A comment in the thread of a video. Comment text is only included in the events.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct Comment<MemberId, CommentId> {
+    /// Member who posted the comment
+    pub author: MemberId,
+    /// Comment this comment replies to, if any
+    pub parent_comment_id: Option<CommentId>,
+    /// Whether the comment was hidden by the channel owner
+    pub is_moderated: bool,
 }
 
 /// Information about the plyalist being created.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct PlaylistCreationParameters {
+pub struct PlaylistCreationParameters<VideoId> {
+    /// Ordered videos of the playlist.
+    videos: Vec<VideoId>,
+    /// Whether videos from other channels may be added to the playlist.
+    cross_channel: bool,
     /// Metadata about the playlist.
     meta: Vec<u8>,
 }
@@ -333,18 +607,23 @@ pub struct PlaylistCreationParameters {
 /// Information about the playlist being updated.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct PlaylistUpdateParameters {
-    // It is the only field so its not an Option
-    /// Metadata update for the playlist.
-    new_meta: Vec<u8>,
+pub struct PlaylistUpdateParameters<VideoId> {
+    /// If set, replaces the ordered videos of the playlist.
+    videos: Option<Vec<VideoId>>,
+    /// If set, metadata update for the playlist.
+    new_meta: Option<Vec<u8>>,
 }
 
 /// A playlist is an ordered collection of videos.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct Playlist<ChannelId> {
+pub struct Playlist<ChannelId, VideoId> {
     /// The channel the playlist belongs to.
     in_channel: ChannelId,
+    /// The videos in the playlist, in order.
+    videos: Vec<VideoId>,
+    /// Whether the playlist may contain videos of other channels.
+    cross_channel: bool,
 }
 
 /// Information about the episode being created or updated.
@@ -397,9 +676,11 @@ pub struct Season<VideoId> {
 /// A series is an ordered list of seasons that belongs to a channel.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct Series<ChannelId, VideoId> {
+pub struct Series<ChannelId, VideoId, ContentId> {
     in_channel: ChannelId,
     seasons: Vec<Season<VideoId>>,
+    /// Content ids of the series and season assets uploaded to the storage system
+    assets: Vec<ContentId>,
 }
 
 // The actor the caller/origin is trying to act as for Person creation and update and delete calls.
@@ -451,26 +732,44 @@ pub struct PersonUpdateParameters<ContentParameters> {
 /// A Person represents a real person that may be associated with a video.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct Person<MemberId> {
+pub struct Person<MemberId, ContentId> {
     /// Who can update or delete this person.
     controlled_by: PersonController<MemberId>,
+    /// Content ids of the person assets uploaded to the storage system
+    assets: Vec<ContentId>,
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as Content {
-        pub ChannelById get(fn channel_by_id): map hasher(blake2_128_concat) T::ChannelId => Channel<T>;
+        pub ChannelById get(fn channel_by_id) config(): map hasher(blake2_128_concat) T::ChannelId => Channel<T>;
+
+        pub ChannelCategoryById get(fn channel_category_by_id) config(): map hasher(blake2_128_concat) T::ChannelCategoryId => ChannelCategory<T>;
 
-        pub ChannelCategoryById get(fn channel_category_by_id): map hasher(blake2_128_concat) T::ChannelCategoryId => ChannelCategory;
+        pub VideoById get(fn video_by_id) config(): map hasher(blake2_128_concat) T::VideoId => Video<T::ChannelId, T::SeriesId, ContentId<T>, T::VideoCategoryId, T::BlockNumber>;
 
-        pub VideoById get(fn video_by_id): map hasher(blake2_128_concat) T::VideoId => Video<T::ChannelId, T::SeriesId>;
+        pub VideoCategoryById get(fn video_category_by_id) config(): map hasher(blake2_128_concat) T::VideoCategoryId => VideoCategory<T>;
 
-        pub VideoCategoryById get(fn video_category_by_id): map hasher(blake2_128_concat) T::VideoCategoryId => VideoCategory;
+        pub PlaylistById get(fn playlist_by_id): map hasher(blake2_128_concat) T::PlaylistId => Playlist<T::ChannelId, T::VideoId>;
 
-        pub PlaylistById get(fn playlist_by_id): map hasher(blake2_128_concat) T::PlaylistId => Playlist<T::ChannelId>;
+        /// Double map, representing the playlists each video is included in
+        pub VideoInPlaylist get(fn video_in_playlist):
+            double_map hasher(blake2_128_concat) T::VideoId, hasher(blake2_128_concat) T::PlaylistId => ();
 
-        pub SeriesById get(fn series_by_id): map hasher(blake2_128_concat) T::SeriesId => Series<T::ChannelId, T::VideoId>;
+        pub SeriesById get(fn series_by_id): map hasher(blake2_128_concat) T::SeriesId => Series<T::ChannelId, T::VideoId, ContentId<T>>;
 
-        pub PersonById get(fn person_by_id): map hasher(blake2_128_concat) T::PersonId => Person<T::MemberId>;
+        pub PersonById get(fn person_by_id): map hasher(blake2_128_concat) T::PersonId => Person<T::MemberId, ContentId<T>>;
+
+        /// Double map, representing the persons credited in each video
+        pub PersonInVideo get(fn person_in_video):
+            double_map hasher(blake2_128_concat) T::VideoId, hasher(blake2_128_concat) T::PersonId => ();
+
+        /// Double map, representing the videos each person is credited in
+        pub VideoOfPerson get(fn video_of_person):
+            double_map hasher(blake2_128_concat) T::PersonId, hasher(blake2_128_concat) T::VideoId => ();
+
+        /// Double map, representing permissions granted to channel collaborators
+        pub ChannelCollaborators get(fn channel_collaborators):
+            double_map hasher(blake2_128_concat) T::ChannelId, hasher(blake2_128_concat) T::MemberId => BTreeSet<ChannelCollaboratorPermission>;
 
         pub ChannelOwnershipTransferRequestById get(fn channel_ownership_transfer_request_by_id):
             map hasher(blake2_128_concat) T::ChannelOwnershipTransferRequestId => ChannelOwnershipTransferRequest<T>;
@@ -493,8 +792,126 @@ decl_storage! {
 
         pub NextCuratorGroupId get(fn next_curator_group_id) config(): T::CuratorGroupId;
 
+        pub CensorshipAppealById get(fn censorship_appeal_by_id):
+            map hasher(blake2_128_concat) T::CensorshipAppealId => CensorshipAppeal<T>;
+
+        /// Map, representing the appeal filed against the current censorship of a channel or video
+        pub CensorshipAppealByContent get(fn censorship_appeal_by_content):
+            map hasher(blake2_128_concat) CensoredContent<T::ChannelId, T::VideoId> => Option<T::CensorshipAppealId>;
+
+        /// Double map, representing all appeals ever filed against censorship of a channel or video
+        pub CensorshipAppealHistory get(fn censorship_appeal_history):
+            double_map hasher(blake2_128_concat) CensoredContent<T::ChannelId, T::VideoId>,
+            hasher(blake2_128_concat) T::CensorshipAppealId => ();
+
+        /// Double map, representing the pending appeals to be granted at a given block
+        pub CensorshipAppealsByDeadline get(fn censorship_appeals_by_deadline):
+            double_map hasher(blake2_128_concat) T::BlockNumber, hasher(blake2_128_concat) T::CensorshipAppealId => ();
+
+        pub NextCensorshipAppealId get(fn next_censorship_appeal_id) config(): T::CensorshipAppealId;
+
+        /// Map, representing reports filed against channels and videos
+        pub ContentReportById get(fn content_report_by_id):
+            map hasher(blake2_128_concat) T::ContentReportId => ContentReport<T>;
+
+        /// Double map, representing the queue of pending reports of a channel or video
+        pub PendingContentReports get(fn pending_content_reports):
+            double_map hasher(blake2_128_concat) CensoredContent<T::ChannelId, T::VideoId>,
+            hasher(blake2_128_concat) T::ContentReportId => ();
+
+        pub NextContentReportId get(fn next_content_report_id) config(): T::ContentReportId;
+
+        /// Double map, representing the videos to be published at a given block
+        pub VideosByPublishBlock get(fn videos_by_publish_block):
+            double_map hasher(blake2_128_concat) T::BlockNumber, hasher(blake2_128_concat) T::VideoId => ();
+
+        /// Double map, representing the members who liked each video
+        pub VideoLikedBy get(fn video_liked_by):
+            double_map hasher(blake2_128_concat) T::VideoId, hasher(blake2_128_concat) T::MemberId => ();
+
+        pub LikesCountByVideo get(fn likes_count_by_video): map hasher(blake2_128_concat) T::VideoId => u64;
+
+        /// Double map, representing the comment thread of each video
+        pub CommentById get(fn comment_by_id):
+            double_map hasher(blake2_128_concat) T::VideoId, hasher(blake2_128_concat) T::CommentId => Comment<T::MemberId, T::CommentId>;
+
+        /// Videos for which the owner disabled posting comments
+        pub VideoCommentsDisabled get(fn video_comments_disabled): map hasher(blake2_128_concat) T::VideoId => bool;
+
+        pub NextCommentId get(fn next_comment_id) config(): T::CommentId;
+
+        /// Map, representing the featured videos of the home page (`None`) and of each video category
+        pub FeaturedVideos get(fn featured_videos):
+            map hasher(blake2_128_concat) Option<T::VideoCategoryId> => Vec<T::VideoId>;
+
+        /// Double map, representing the featured video lists each video is included in
+        pub FeaturedVideoLists get(fn featured_video_lists):
+            double_map hasher(blake2_128_concat) T::VideoId, hasher(blake2_128_concat) Option<T::VideoCategoryId> => ();
+
+        /// The mint funding channel rewards, created at genesis or on the runtime upgrade
+        pub ContentMint get(fn content_mint): Option<<T as minting::Trait>::MintId>;
+
+        /// Merkle root of the cumulative earnings of all channels, posted by the lead
+        pub CommitmentOfRewards get(fn commitment_of_rewards): T::Hash;
+
+        /// Map, representing the cumulative rewards claimed by each channel
+        pub ClaimedRewardsByChannel get(fn claimed_rewards_by_channel):
+            map hasher(blake2_128_concat) T::ChannelId => minting::BalanceOf<T>;
+
+        /// Map, representing the NFTs issued for videos
+        pub NftByVideo get(fn nft_by_video): map hasher(blake2_128_concat) T::VideoId => OwnedNft<T>;
+
+        /// Double map, representing the video NFT auctions to be settled at a given block
+        pub AuctionsByEndBlock get(fn auctions_by_end_block):
+            double_map hasher(blake2_128_concat) T::BlockNumber, hasher(blake2_128_concat) T::VideoId => ();
+
+        /// Double map, representing payments out of a module account (the first key) which could
+        /// not be transferred to the payee (the second key) and are waiting to be claimed
+        pub UnclaimedPayments get(fn unclaimed_payment):
+            double_map hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
+
+        /// Double map, representing the paid subscription tiers of each channel
+        pub SubscriptionTierById get(fn subscription_tier_by_id):
+            double_map hasher(blake2_128_concat) T::ChannelId,
+            hasher(blake2_128_concat) T::SubscriptionTierId => SubscriptionTier<T>;
+
+        pub NextSubscriptionTierId get(fn next_subscription_tier_id) config(): T::SubscriptionTierId;
+
+        /// Double map, representing the subscriptions of members to channels
+        pub ChannelSubscriptions get(fn channel_subscription):
+            double_map hasher(blake2_128_concat) T::ChannelId, hasher(blake2_128_concat) T::MemberId => Subscription<T>;
+
+        /// Double map, representing the channel subscriptions to be renewed at a given block
+        pub SubscriptionsByRenewalBlock get(fn subscriptions_by_renewal_block):
+            double_map hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) (T::ChannelId, T::MemberId) => ();
+
+        /// Earliest renewal block with channel subscriptions which may not have been processed yet
+        pub NextSubscriptionRenewalBlock get(fn next_subscription_renewal_block): Option<T::BlockNumber>;
+
+        pub CommentTextConstraint get(fn comment_text_constraint) config(): InputValidationLengthConstraint;
+
+        pub CommentModerationRationaleConstraint get(fn comment_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
+
         /// Map, representing  CuratorGroupId -> CuratorGroup relation
-        pub CuratorGroupById get(fn curator_group_by_id): map hasher(blake2_128_concat) T::CuratorGroupId => CuratorGroup<T>;
+        pub CuratorGroupById get(fn curator_group_by_id) config(): map hasher(blake2_128_concat) T::CuratorGroupId => CuratorGroup<T>;
+
+        /// Version of the storage layout, chains started before the current content directory are at version zero.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>| migration::STORAGE_VERSION): u32;
+    }
+    add_extra_genesis {
+        config(content_mint_capacity): minting::BalanceOf<T>;
+        build(|config: &GenesisConfig<T>| {
+            Module::<T>::initialize_content_mint(config.content_mint_capacity);
+
+            Module::<T>::initialize_imported_content(
+                &config.channel_category_by_id,
+                &config.channel_by_id,
+                &config.video_category_by_id,
+                &config.video_by_id,
+            );
+        });
     }
 }
 
@@ -509,6 +926,61 @@ decl_module! {
         /// Exports const -  max number of curators per group
         const MaxNumberOfCuratorsPerGroup: MaxNumber = T::MaxNumberOfCuratorsPerGroup::get();
 
+        /// Exports const -  max number of videos per playlist
+        const MaxNumberOfVideosPerPlaylist: MaxNumber = T::MaxNumberOfVideosPerPlaylist::get();
+
+        /// Exports const -  max number of featured videos per list
+        const MaxNumberOfFeaturedVideos: MaxNumber = T::MaxNumberOfFeaturedVideos::get();
+
+        /// Exports const -  max number of videos per batch
+        const MaxNumberOfVideosPerBatch: MaxNumber = T::MaxNumberOfVideosPerBatch::get();
+
+        /// Exports const -  max depth of channel and video subcategories
+        const MaxCategoryDepth: MaxNumber = T::MaxCategoryDepth::get();
+
+        /// Exports const -  stake required to file a censorship appeal
+        const CensorshipAppealStake: BalanceOf<T> = T::CensorshipAppealStake::get();
+
+        /// Exports const -  number of blocks to resolve a censorship appeal
+        const CensorshipAppealPeriod: T::BlockNumber = T::CensorshipAppealPeriod::get();
+
+        /// Exports const -  stake required to report a channel or video
+        const ContentReportStake: BalanceOf<T> = T::ContentReportStake::get();
+
+        /// Exports const -  reward for a report which led to censorship
+        const ContentReportReward: BalanceOf<T> = T::ContentReportReward::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            // Grant pending censorship appeals which were not resolved in time
+            let expired_appeals: Vec<T::CensorshipAppealId> =
+                CensorshipAppealsByDeadline::<T>::iter_prefix(now).map(|(appeal_id, _)| appeal_id).collect();
+
+            for appeal_id in expired_appeals {
+                Self::settle_censorship_appeal(appeal_id, CensorshipAppealStatus::Expired, vec![]);
+            }
+
+            // Publish videos scheduled for this block
+            let scheduled_videos: Vec<T::VideoId> =
+                VideosByPublishBlock::<T>::iter_prefix(now).map(|(video_id, _)| video_id).collect();
+
+            for video_id in scheduled_videos {
+                Self::publish_scheduled_video(now, video_id);
+            }
+
+            // Settle video NFT auctions ending at this block
+            let ended_auctions: Vec<T::VideoId> =
+                AuctionsByEndBlock::<T>::iter_prefix(now).map(|(video_id, _)| video_id).collect();
+
+            for video_id in ended_auctions {
+                Self::settle_english_auction(now, video_id);
+            }
+
+            // Renew or expire channel subscriptions at the end of their paid period
+            Self::process_subscription_renewals(now);
+
+            10_000_000 // TODO: adjust weight
+        }
+
         // ======
         // Next set of extrinsics can only be invoked by lead.
         // ======
@@ -565,6 +1037,33 @@ decl_module! {
             Self::deposit_event(RawEvent::CuratorGroupStatusSet(curator_group_id, is_active));
         }
 
+        /// Set permissions granted to curators of curator group under given `curator_group_id`
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_curator_group_permissions(
+            origin,
+            curator_group_id: T::CuratorGroupId,
+            permissions: BTreeSet<CuratorGroupPermission>,
+        ) {
+
+            // Ensure given origin is lead
+            ensure_is_lead::<T>(origin)?;
+
+            // Ensure curator group under provided curator_group_id already exist
+            Self::ensure_curator_group_under_given_id_exists(&curator_group_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Set permissions for curator group under given `curator_group_id`
+            <CuratorGroupById<T>>::mutate(curator_group_id, |curator_group| {
+                curator_group.set_permissions(permissions.clone())
+            });
+
+            // Trigger event
+            Self::deposit_event(RawEvent::CuratorGroupPermissionsSet(curator_group_id, permissions));
+        }
+
         /// Add curator to curator group under given `curator_group_id`
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn add_curator_to_group(
@@ -635,7 +1134,7 @@ decl_module! {
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn create_channel(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
             params: ChannelCreationParameters<ContentParameters<T>, T::AccountId>,
         ) {
             ensure_actor_authorized_to_create_channel::<T>(
@@ -653,6 +1152,8 @@ decl_module! {
 
             let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
 
+            let assets = Self::content_ids(&content_parameters);
+
             //
             // == MUTATION SAFE ==
             //
@@ -674,6 +1175,8 @@ decl_module! {
                 series: vec![],
                 is_censored: false,
                 reward_account: params.reward_account.clone(),
+                assets,
+                category: None,
             };
             ChannelById::<T>::insert(channel_id, channel.clone());
 
@@ -684,18 +1187,29 @@ decl_module! {
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_channel(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
             channel_id: T::ChannelId,
             params: ChannelUpdateParameters<ContentParameters<T>, T::AccountId>,
         ) {
             // check that channel exists
             let channel = Self::ensure_channel_exists(&channel_id)?;
 
-            ensure_actor_authorized_to_update_channel::<T>(
-                origin,
-                &actor,
-                &channel.owner,
-            )?;
+            if params.reward_account.is_some() {
+                // Only channel owner can update the reward account
+                ensure_actor_authorized_to_update_channel::<T>(
+                    origin,
+                    &actor,
+                    &channel.owner,
+                )?;
+            } else {
+                ensure_actor_authorized_to_update_channel_content::<T>(
+                    origin,
+                    &actor,
+                    &channel_id,
+                    &channel.owner,
+                    ChannelCollaboratorPermission::EditMetadata,
+                )?;
+            }
 
             // Pick out the assets to be uploaded to storage frame_system
             let new_assets = if let Some(assets) = &params.assets {
@@ -726,6 +1240,11 @@ decl_module! {
                 channel.reward_account = reward_account.clone();
             }
 
+            // Keep track of newly uploaded assets
+            if let Some((upload_parameters, _)) = &new_assets {
+                channel.assets.extend(Self::content_ids(upload_parameters));
+            }
+
             // Update the channel
             ChannelById::<T>::insert(channel_id, channel.clone());
 
@@ -745,616 +1264,3876 @@ decl_module! {
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn remove_channel_assets(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
             channel_id: T::ChannelId,
             assets: Vec<ContentId<T>>,
         ) {
             // check that channel exists
             let channel = Self::ensure_channel_exists(&channel_id)?;
 
-            ensure_actor_authorized_to_update_channel::<T>(
+            ensure_actor_authorized_to_update_channel_content::<T>(
                 origin,
                 &actor,
+                &channel_id,
                 &channel.owner,
+                ChannelCollaboratorPermission::EditMetadata,
             )?;
 
+            Self::ensure_assets_are_tracked(&channel.assets, &assets, Error::<T>::ChannelAssetNotFound)?;
+
             let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
 
             //
             // == MUTATION SAFE ==
             //
 
+            // This should be first mutation
             T::StorageSystem::atomically_remove_content(&object_owner, &assets)?;
 
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                channel.assets.retain(|content_id| !assets.contains(content_id));
+            });
+
             Self::deposit_event(RawEvent::ChannelAssetsRemoved(actor, channel_id, assets));
         }
 
+        /// Delete the channel together with its videos, playlists and series.
+        /// `assets` must list all the content owned by the channel in the storage system.
+        /// Video NFTs held by members must be transferred back to the channel owner first.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_channel_censorship_status(
+        pub fn delete_channel(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
             channel_id: T::ChannelId,
-            is_censored: bool,
-            rationale: Vec<u8>,
+            assets: Vec<ContentId<T>>,
         ) {
             // check that channel exists
             let channel = Self::ensure_channel_exists(&channel_id)?;
 
-            if channel.is_censored == is_censored {
-                return Ok(())
-            }
-
-            ensure_actor_authorized_to_censor::<T>(
+            ensure_actor_authorized_to_update_channel::<T>(
                 origin,
                 &actor,
                 &channel.owner,
             )?;
 
+            for video_id in channel.videos.iter() {
+                Self::ensure_video_nft_can_be_burned(video_id)?;
+            }
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
             //
             // == MUTATION SAFE ==
             //
 
-            let mut channel = channel;
+            // This should be first mutation
+            // Remove all channel assets and release the channel voucher
+            T::StorageSystem::atomically_remove_all_content(&object_owner, &assets)?;
 
-            channel.is_censored = is_censored;
+            for series_id in channel.series.iter() {
+                SeriesById::<T>::remove(series_id);
+            }
 
-            // TODO: unset the reward account ? so no revenue can be earned for censored channels?
+            for playlist_id in channel.playlists.iter() {
+                for video_id in Self::playlist_by_id(playlist_id).videos.iter() {
+                    VideoInPlaylist::<T>::remove(video_id, playlist_id);
+                }
+                PlaylistById::<T>::remove(playlist_id);
+            }
 
-            // Update the channel
-            ChannelById::<T>::insert(channel_id, channel);
+            for video_id in channel.videos.iter() {
+                Self::remove_video(video_id, &Self::video_by_id(video_id));
+            }
 
-            Self::deposit_event(RawEvent::ChannelCensorshipStatusUpdated(actor, channel_id, is_censored, rationale));
+            ChannelById::<T>::remove(channel_id);
+
+            ChannelCollaborators::<T>::remove_prefix(channel_id);
+
+            ClaimedRewardsByChannel::<T>::remove(channel_id);
+
+            // Drop subscriptions and subscription tiers of the channel
+            for (member_id, subscription) in ChannelSubscriptions::<T>::iter_prefix(channel_id) {
+                SubscriptionsByRenewalBlock::<T>::remove(subscription.paid_until, (channel_id, member_id));
+            }
+            ChannelSubscriptions::<T>::remove_prefix(channel_id);
+            SubscriptionTierById::<T>::remove_prefix(channel_id);
+
+            // Unassign channel from its category
+            Self::move_channel_between_categories(&channel.category, &None);
+
+            // Refund pending censorship appeal of the channel
+            Self::close_censorship_appeal(&CensoredContent::Channel(channel_id));
+
+            // Refund pending reports of the channel
+            Self::settle_content_reports(&CensoredContent::Channel(channel_id), ContentReportStatus::Closed);
+
+            Self::deposit_event(RawEvent::ChannelDeleted(actor, channel_id));
         }
 
+        /// Add collaborator to the channel or update permissions of existing one
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn create_channel_category(
+        pub fn set_channel_collaborator(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            params: ChannelCategoryCreationParameters,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            member_id: T::MemberId,
+            permissions: BTreeSet<ChannelCollaboratorPermission>,
         ) {
-            ensure_actor_authorized_to_manage_categories::<T>(
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            // Only channel owner can manage collaborators
+            ensure_actor_authorized_to_update_channel::<T>(
                 origin,
-                &actor
+                &actor,
+                &channel.owner,
             )?;
 
+            ensure!(!permissions.is_empty(), Error::<T>::NoCollaboratorPermissions);
+
+            ensure!(
+                channel.owner != ChannelOwner::Member(member_id),
+                Error::<T>::ActorIsAlreadyChannelOwner
+            );
+
             //
             // == MUTATION SAFE ==
             //
 
-            let category_id = Self::next_channel_category_id();
-            NextChannelCategoryId::<T>::mutate(|id| *id += T::ChannelCategoryId::one());
-
-            let category = ChannelCategory {};
-            ChannelCategoryById::<T>::insert(category_id, category.clone());
+            ChannelCollaborators::<T>::insert(channel_id, member_id, permissions.clone());
 
-            Self::deposit_event(RawEvent::ChannelCategoryCreated(category_id, category, params));
+            Self::deposit_event(RawEvent::ChannelCollaboratorSet(actor, channel_id, member_id, permissions));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_channel_category(
+        pub fn remove_channel_collaborator(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            category_id: T::ChannelCategoryId,
-            params: ChannelCategoryUpdateParameters,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            member_id: T::MemberId,
         ) {
-            ensure_actor_authorized_to_manage_categories::<T>(
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            // Only channel owner can manage collaborators
+            ensure_actor_authorized_to_update_channel::<T>(
                 origin,
-                &actor
+                &actor,
+                &channel.owner,
             )?;
 
-            Self::ensure_channel_category_exists(&category_id)?;
+            ensure!(
+                ChannelCollaborators::<T>::contains_key(channel_id, member_id),
+                Error::<T>::CollaboratorDoesNotExist
+            );
 
-            Self::deposit_event(RawEvent::ChannelCategoryUpdated(actor, category_id, params));
-        }
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ChannelCollaborators::<T>::remove(channel_id, member_id);
+
+            Self::deposit_event(RawEvent::ChannelCollaboratorRemoved(actor, channel_id, member_id));
+        }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn delete_channel_category(
+        pub fn update_channel_censorship_status(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            category_id: T::ChannelCategoryId,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            is_censored: bool,
+            rationale: Vec<u8>,
         ) {
-            ensure_actor_authorized_to_manage_categories::<T>(
-                origin,
-                &actor
-            )?;
-
-            Self::ensure_channel_category_exists(&category_id)?;
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
 
-            ChannelCategoryById::<T>::remove(&category_id);
+            if channel.is_censored == is_censored {
+                return Ok(())
+            }
 
-            Self::deposit_event(RawEvent::ChannelCategoryDeleted(actor, category_id));
-        }
+            ensure_actor_authorized_to_censor::<T>(
+                origin,
+                &actor,
+                &channel.owner,
+            )?;
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn request_channel_transfer(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _request: ChannelOwnershipTransferRequest<T>,
-        ) {
-            // requester must be new_owner
-            Self::not_implemented()?;
-        }
+            //
+            // == MUTATION SAFE ==
+            //
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn cancel_channel_transfer_request(
-            _origin,
-            _request_id: T::ChannelOwnershipTransferRequestId,
-        ) {
-            // origin must be original requester (ie. proposed new channel owner)
-            Self::not_implemented()?;
-        }
+            Self::set_channel_censorship_status(channel_id, channel, is_censored);
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn accept_channel_transfer(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _request_id: T::ChannelOwnershipTransferRequestId,
-        ) {
-            // only current owner of channel can approve
-            Self::not_implemented()?;
+            Self::deposit_event(RawEvent::ChannelCensorshipStatusUpdated(actor, channel_id, is_censored, rationale));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn create_video(
+        pub fn appeal_censorship(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            channel_id: T::ChannelId,
-            params: VideoCreationParameters<ContentParameters<T>>,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            censored: CensoredContent<T::ChannelId, T::VideoId>,
         ) {
-            // check that channel exists
-            let channel = Self::ensure_channel_exists(&channel_id)?;
+            let sender = ensure_signed(origin.clone())?;
 
+            let channel_id = Self::ensure_content_is_censored(&censored)?;
+
+            // Only channel owner can appeal censorship of the channel and its videos
             ensure_actor_authorized_to_update_channel::<T>(
                 origin,
                 &actor,
-                &channel.owner,
+                &Self::channel_by_id(channel_id).owner,
             )?;
 
-            // Pick out the assets to be uploaded to storage frame_system
-            let content_parameters: Vec<ContentParameters<T>> = Self::pick_content_parameters_from_assets(&params.assets);
+            // Each censorship can be appealed only once
+            ensure!(
+                !CensorshipAppealByContent::<T>::contains_key(&censored),
+                Error::<T>::CensorshipAlreadyAppealed
+            );
 
-            let video_id = NextVideoId::<T>::get();
+            let stake = T::CensorshipAppealStake::get();
 
-            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+            ensure!(
+                T::Currency::can_reserve(&sender, stake),
+                Error::<T>::InsufficientBalanceForAppealStake
+            );
 
-            // This should be first mutation
-            // Try add assets to storage
-            T::StorageSystem::atomically_add_content(
-                object_owner,
-                content_parameters,
-            )?;
+            let appeal_id = Self::next_censorship_appeal_id();
+
+            let deadline = <frame_system::Module<T>>::block_number() + T::CensorshipAppealPeriod::get();
 
             //
             // == MUTATION SAFE ==
             //
 
-            let video: Video<T::ChannelId, T::SeriesId> = Video {
-                in_channel: channel_id,
-                // keep track of which season the video is in if it is an 'episode'
-                // - prevent removing a video if it is in a season (because order is important)
-                in_series: None,
-                /// Whether the curators have censored the video or not.
-                is_censored: false,
+            // This should be first mutation
+            T::Currency::reserve(&sender, stake)?;
+
+            NextCensorshipAppealId::<T>::mutate(|id| *id += T::CensorshipAppealId::one());
+
+            let appeal = CensorshipAppealRecord {
+                censored: censored.clone(),
+                appellant: sender,
+                stake,
+                deadline,
+                status: CensorshipAppealStatus::Pending,
             };
 
-            VideoById::<T>::insert(video_id, video);
+            CensorshipAppealById::<T>::insert(appeal_id, appeal.clone());
+            CensorshipAppealByContent::<T>::insert(&censored, appeal_id);
+            CensorshipAppealHistory::<T>::insert(&censored, appeal_id, ());
+            CensorshipAppealsByDeadline::<T>::insert(deadline, appeal_id, ());
 
-            // Only increment next video id if adding content was successful
-            NextVideoId::<T>::mutate(|id| *id += T::VideoId::one());
+            Self::deposit_event(RawEvent::CensorshipAppealFiled(actor, appeal_id, appeal));
+        }
 
-            // Add recently added video id to the channel
-            ChannelById::<T>::mutate(channel_id, |channel| {
-                channel.videos.push(video_id);
-            });
+        /// Resolve pending censorship appeal, can be invoked by lead or by an approved proposal
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn resolve_censorship_appeal(
+            origin,
+            appeal_id: T::CensorshipAppealId,
+            accept: bool,
+            rationale: Vec<u8>,
+        ) {
+            ensure_is_lead_or_root::<T>(origin)?;
 
-            Self::deposit_event(RawEvent::VideoCreated(actor, channel_id, video_id, params));
+            Self::ensure_censorship_appeal_is_pending(&appeal_id)?;
 
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let status = if accept {
+                CensorshipAppealStatus::Accepted
+            } else {
+                CensorshipAppealStatus::Rejected
+            };
+
+            Self::settle_censorship_appeal(appeal_id, status, rationale);
         }
 
+        /// Report a video, the report stake is reserved until curators triage the report
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_video(
+        pub fn report_video(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            member_id: T::MemberId,
             video_id: T::VideoId,
-            params: VideoUpdateParameters<ContentParameters<T>>,
+            reason: ReportReason,
         ) {
-            // check that video exists, retrieve corresponding channel id.
-            let channel_id = Self::ensure_video_exists(&video_id)?.in_channel;
+            Self::file_content_report(origin, member_id, CensoredContent::Video(video_id), reason)?;
+        }
 
-            ensure_actor_authorized_to_update_channel::<T>(
+        /// Report a channel, the report stake is reserved until curators triage the report
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn report_channel(
+            origin,
+            member_id: T::MemberId,
+            channel_id: T::ChannelId,
+            reason: ReportReason,
+        ) {
+            Self::file_content_report(origin, member_id, CensoredContent::Channel(channel_id), reason)?;
+        }
+
+        /// Resolve all pending reports of a channel or video, either censoring the content and
+        /// rewarding the reporters, or dismissing the reports and slashing their stakes
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn resolve_content_reports(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            content: CensoredContent<T::ChannelId, T::VideoId>,
+            censor: bool,
+            rationale: Vec<u8>,
+        ) {
+            let (channel_id, _) = Self::ensure_content_exists(&content)?;
+
+            ensure!(
+                PendingContentReports::<T>::iter_prefix(&content).next().is_some(),
+                Error::<T>::NoPendingContentReports
+            );
+
+            ensure_actor_authorized_to_censor::<T>(
                 origin,
                 &actor,
                 &Self::channel_by_id(channel_id).owner,
             )?;
 
-            // Pick out the assets to be uploaded to storage frame_system
-            let new_assets = if let Some(assets) = &params.assets {
-                let upload_parameters: Vec<ContentParameters<T>> = Self::pick_content_parameters_from_assets(assets);
+            //
+            // == MUTATION SAFE ==
+            //
 
-                let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+            // Content with pending reports is never censored, censoring it upholds the reports
+            match content {
+                CensoredContent::Channel(channel_id) if censor => {
+                    Self::set_channel_censorship_status(channel_id, Self::channel_by_id(channel_id), true);
 
-                // check assets can be uploaded to storage.
-                // update can_add_content() to only take &refrences
-                T::StorageSystem::can_add_content(
-                    object_owner.clone(),
-                    upload_parameters.clone(),
-                )?;
+                    Self::deposit_event(RawEvent::ChannelCensorshipStatusUpdated(actor, channel_id, true, rationale));
+                }
+                CensoredContent::Video(video_id) if censor => {
+                    Self::set_video_censorship_status(video_id, Self::video_by_id(video_id), true);
 
-                Some((upload_parameters, object_owner))
-            } else {
-                None
+                    Self::deposit_event(RawEvent::VideoCensorshipStatusUpdated(actor, video_id, true, rationale));
+                }
+                _ => {
+                    Self::settle_content_reports(&content, ContentReportStatus::Dismissed);
+
+                    Self::deposit_event(RawEvent::ContentReportsDismissed(actor, content, rationale));
+                }
+            }
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_channel_category(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            params: ChannelCategoryCreationParameters<T::ChannelCategoryId>,
+        ) {
+            ensure_actor_authorized_to_manage_categories::<T>(
+                origin,
+                &actor
+            )?;
+
+            let depth = match params.parent_category_id {
+                Some(parent_category_id) => Self::ensure_channel_category_exists(&parent_category_id)?.depth + 1,
+                None => 0,
             };
 
+            ensure!(depth <= T::MaxCategoryDepth::get(), Error::<T>::CategoryDepthLimitReached);
+
             //
             // == MUTATION SAFE ==
             //
 
-            // add assets to storage
-            // This should not fail because of prior can_add_content() check!
-            if let Some((upload_parameters, object_owner)) = new_assets {
-                T::StorageSystem::atomically_add_content(
-                    object_owner,
-                    upload_parameters,
-                )?;
+            let category_id = Self::next_channel_category_id();
+            NextChannelCategoryId::<T>::mutate(|id| *id += T::ChannelCategoryId::one());
+
+            if let Some(parent_category_id) = params.parent_category_id {
+                ChannelCategoryById::<T>::mutate(parent_category_id, |parent| parent.subcategories_count += 1);
             }
 
-            Self::deposit_event(RawEvent::VideoUpdated(actor, video_id, params));
+            let category = CategoryRecord {
+                parent_category_id: params.parent_category_id,
+                depth,
+                subcategories_count: 0,
+                items_count: 0,
+            };
+            ChannelCategoryById::<T>::insert(category_id, category.clone());
+
+            Self::deposit_event(RawEvent::ChannelCategoryCreated(category_id, category, params));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn delete_video(
+        pub fn update_channel_category(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            video_id: T::VideoId,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            category_id: T::ChannelCategoryId,
+            params: ChannelCategoryUpdateParameters,
         ) {
+            ensure_actor_authorized_to_manage_categories::<T>(
+                origin,
+                &actor
+            )?;
 
-            // check that video exists
-            let video = Self::ensure_video_exists(&video_id)?;
+            Self::ensure_channel_category_exists(&category_id)?;
 
-            let channel_id = video.in_channel;
+            Self::deposit_event(RawEvent::ChannelCategoryUpdated(actor, category_id, params));
+        }
 
-            ensure_actor_authorized_to_update_channel::<T>(
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_channel_category(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            category_id: T::ChannelCategoryId,
+        ) {
+            ensure_actor_authorized_to_manage_categories::<T>(
                 origin,
-                &actor,
-                // The channel owner will be..
-                &Self::channel_by_id(channel_id).owner,
+                &actor
             )?;
 
-            Self::ensure_video_can_be_removed(video)?;
+            let category = Self::ensure_channel_category_exists(&category_id)?;
+
+            category.ensure_is_unreferenced::<T>()?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            // Remove video
-            VideoById::<T>::remove(video_id);
+            if let Some(parent_category_id) = category.parent_category_id {
+                ChannelCategoryById::<T>::mutate(parent_category_id, |parent| {
+                    parent.subcategories_count = parent.subcategories_count.saturating_sub(1)
+                });
+            }
 
-            // Update corresponding channel
-            // Remove recently deleted video from the channel
-            ChannelById::<T>::mutate(channel_id, |channel| {
-                if let Some(index) = channel.videos.iter().position(|x| *x == video_id) {
-                    channel.videos.remove(index);
-                }
-            });
+            ChannelCategoryById::<T>::remove(&category_id);
 
-            Self::deposit_event(RawEvent::VideoDeleted(actor, video_id));
+            Self::deposit_event(RawEvent::ChannelCategoryDeleted(actor, category_id));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn create_playlist(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _channel_id: T::ChannelId,
-            _params: PlaylistCreationParameters,
+        pub fn set_channel_category(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            category_id: Option<T::ChannelCategoryId>,
         ) {
-            Self::not_implemented()?;
-        }
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_playlist(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _playlist: T::PlaylistId,
-            _params: PlaylistUpdateParameters,
-        ) {
-            Self::not_implemented()?;
-        }
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &channel.owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn delete_playlist(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _channel_id: T::ChannelId,
-            _playlist: T::PlaylistId,
-        ) {
-            Self::not_implemented()?;
+            if let Some(category_id) = category_id {
+                Self::ensure_channel_category_exists(&category_id)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::move_channel_between_categories(&channel.category, &category_id);
+
+            let mut channel = channel;
+
+            channel.category = category_id;
+
+            ChannelById::<T>::insert(channel_id, channel);
+
+            Self::deposit_event(RawEvent::ChannelCategorySet(actor, channel_id, category_id));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn set_featured_videos(
+        pub fn request_channel_transfer(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            list: Vec<T::VideoId>
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            params: ChannelOwnershipTransferRequestParameters<T>,
         ) {
-            // can only be set by lead
-            ensure_actor_authorized_to_set_featured_videos::<T>(
-                origin,
-                &actor,
-            )?;
+            let sender = ensure_signed(origin.clone())?;
+
+            // requester must be new_owner
+            Self::ensure_actor_is_requested_channel_owner(origin, &actor, &params.new_owner)?;
+
+            // payment is taken from the account signing the request
+            let request = ChannelOwnershipTransferRequestRecord {
+                channel_id: params.channel_id,
+                new_owner: params.new_owner,
+                payment: params.payment,
+                payer: sender.clone(),
+                new_reward_account: params.new_reward_account,
+            };
+
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&request.channel_id)?;
+
+            ensure!(channel.owner != request.new_owner, Error::<T>::ActorIsAlreadyChannelOwner);
+
+            let request_id = Self::next_channel_transfer_request_id();
 
             //
             // == MUTATION SAFE ==
             //
 
-            Self::deposit_event(RawEvent::FeaturedVideosSet(actor, list));
+            // This should be first mutation
+            // Try move the payment into escrow
+            if !request.payment.is_zero() {
+                T::Currency::transfer(
+                    &sender,
+                    &Self::channel_ownership_payment_escrow_account(),
+                    request.payment,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+
+            // Only increment next request id if payment was successfully escrowed
+            NextChannelOwnershipTransferRequestId::<T>::mutate(|id| *id += T::ChannelOwnershipTransferRequestId::one());
+
+            ChannelOwnershipTransferRequestById::<T>::insert(request_id, request.clone());
+
+            Self::deposit_event(RawEvent::ChannelOwnershipTransferRequested(actor, request_id, request));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn create_video_category(
+        pub fn cancel_channel_transfer_request(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            params: VideoCategoryCreationParameters,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            request_id: T::ChannelOwnershipTransferRequestId,
         ) {
-            ensure_actor_authorized_to_manage_categories::<T>(
-                origin,
-                &actor
-            )?;
+            let request = Self::ensure_channel_transfer_request_exists(&request_id)?;
+
+            // origin must be original requester (ie. proposed new channel owner)
+            Self::ensure_actor_is_requested_channel_owner(origin, &actor, &request.new_owner)?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            let category_id = Self::next_video_category_id();
-            NextVideoCategoryId::<T>::mutate(|id| *id += T::VideoCategoryId::one());
+            // Refund the escrowed payment
+            if !request.payment.is_zero() {
+                T::Currency::transfer(
+                    &Self::channel_ownership_payment_escrow_account(),
+                    &request.payer,
+                    request.payment,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
 
-            let category = VideoCategory {};
-            VideoCategoryById::<T>::insert(category_id, category);
+            ChannelOwnershipTransferRequestById::<T>::remove(request_id);
+
+            Self::deposit_event(RawEvent::ChannelOwnershipTransferRequestWithdrawn(actor, request_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn accept_channel_transfer(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            request_id: T::ChannelOwnershipTransferRequestId,
+        ) {
+            let sender = ensure_signed(origin.clone())?;
+
+            let request = Self::ensure_channel_transfer_request_exists(&request_id)?;
+
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&request.channel_id)?;
+
+            // only current owner of channel can approve
+            ensure_actor_authorized_to_update_channel::<T>(
+                origin,
+                &actor,
+                &channel.owner,
+            )?;
+
+            // Channel may have changed hands since the request was made
+            ensure!(channel.owner != request.new_owner, Error::<T>::ActorIsAlreadyChannelOwner);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Release the escrowed payment to the current owner's reward account,
+            // falling back to the account accepting the transfer.
+            if !request.payment.is_zero() {
+                let payee = channel.reward_account.clone().unwrap_or(sender);
+
+                T::Currency::transfer(
+                    &Self::channel_ownership_payment_escrow_account(),
+                    &payee,
+                    request.payment,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+
+            let mut channel = channel;
+
+            // Ownership and reward account move together
+            channel.owner = request.new_owner;
+            channel.reward_account = request.new_reward_account;
+
+            ChannelById::<T>::insert(request.channel_id, channel);
+
+            // Collaborators of the previous owner are not carried over
+            ChannelCollaborators::<T>::remove_prefix(request.channel_id);
+
+            ChannelOwnershipTransferRequestById::<T>::remove(request_id);
+
+            Self::deposit_event(RawEvent::ChannelOwnershipTransferred(actor, request_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_video(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            params: VideoCreationParameters<ContentParameters<T>>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &channel.owner,
+                ChannelCollaboratorPermission::UploadVideos,
+            )?;
+
+            // Pick out the assets to be uploaded to storage frame_system
+            let content_parameters: Vec<ContentParameters<T>> = Self::pick_content_parameters_from_assets(&params.assets);
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            // This should be first mutation
+            // Try add assets to storage
+            T::StorageSystem::atomically_add_content(
+                object_owner,
+                content_parameters,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::insert_new_video(actor, channel_id, params);
+        }
+
+        /// Create multiple videos in the channel, either all of them are created or none
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_videos(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            params: Vec<VideoCreationParameters<ContentParameters<T>>>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &channel.owner,
+                ChannelCollaboratorPermission::UploadVideos,
+            )?;
+
+            Self::ensure_video_batch_size_is_valid(params.len())?;
+
+            // Pick out the assets of all videos to be uploaded to storage frame_system
+            let content_parameters: Vec<ContentParameters<T>> = params
+                .iter()
+                .flat_map(|video_params| Self::pick_content_parameters_from_assets(&video_params.assets))
+                .collect();
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            // check the aggregate upload fits the channel voucher
+            T::StorageSystem::can_add_content(
+                object_owner.clone(),
+                content_parameters.clone(),
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // This should not fail because of prior can_add_content() check!
+            T::StorageSystem::atomically_add_content(
+                object_owner,
+                content_parameters,
+            )?;
+
+            for video_params in params {
+                Self::insert_new_video(actor, channel_id, video_params);
+            }
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_video(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            params: VideoUpdateParameters<ContentParameters<T>>,
+        ) {
+            // check that video exists, retrieve corresponding channel id.
+            let channel_id = Self::ensure_video_exists(&video_id)?.in_channel;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            // Pick out the assets to be uploaded to storage frame_system
+            let new_assets = if let Some(assets) = &params.assets {
+                let upload_parameters: Vec<ContentParameters<T>> = Self::pick_content_parameters_from_assets(assets);
+
+                let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+                // check assets can be uploaded to storage.
+                // update can_add_content() to only take &refrences
+                T::StorageSystem::can_add_content(
+                    object_owner.clone(),
+                    upload_parameters.clone(),
+                )?;
+
+                Some((upload_parameters, object_owner))
+            } else {
+                None
+            };
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // add assets to storage
+            // This should not fail because of prior can_add_content() check!
+            if let Some((upload_parameters, object_owner)) = new_assets {
+                let content_ids = Self::content_ids(&upload_parameters);
+
+                T::StorageSystem::atomically_add_content(
+                    object_owner,
+                    upload_parameters,
+                )?;
+
+                // Keep track of newly uploaded assets
+                VideoById::<T>::mutate(video_id, |video| video.assets.extend(content_ids));
+            }
+
+            Self::deposit_event(RawEvent::VideoUpdated(actor, video_id, params));
+        }
+
+        /// Update multiple videos of the channel, either all of them are updated or none
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_videos(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            updates: Vec<(T::VideoId, VideoUpdateParameters<ContentParameters<T>>)>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &channel.owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            Self::ensure_video_batch_size_is_valid(updates.len())?;
+
+            let video_ids: Vec<T::VideoId> = updates.iter().map(|(video_id, _)| *video_id).collect();
+            Self::ensure_videos_are_in_channel(&channel_id, &video_ids)?;
+
+            // Pick out the assets of all videos to be uploaded to storage frame_system
+            let upload_parameters: Vec<(T::VideoId, Vec<ContentParameters<T>>)> = updates
+                .iter()
+                .filter_map(|(video_id, params)| {
+                    params.assets.as_ref().map(|assets| (*video_id, Self::pick_content_parameters_from_assets(assets)))
+                })
+                .collect();
+
+            let content_parameters: Vec<ContentParameters<T>> = upload_parameters
+                .iter()
+                .flat_map(|(_, parameters)| parameters.iter().cloned())
+                .collect();
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            // check the aggregate upload fits the channel voucher
+            T::StorageSystem::can_add_content(
+                object_owner.clone(),
+                content_parameters.clone(),
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // This should not fail because of prior can_add_content() check!
+            T::StorageSystem::atomically_add_content(
+                object_owner,
+                content_parameters,
+            )?;
+
+            // Keep track of newly uploaded assets
+            for (video_id, parameters) in upload_parameters {
+                let content_ids = Self::content_ids(&parameters);
+                VideoById::<T>::mutate(video_id, |video| video.assets.extend(content_ids));
+            }
+
+            for (video_id, params) in updates {
+                Self::deposit_event(RawEvent::VideoUpdated(actor, video_id, params));
+            }
+        }
+
+        /// Remove assets of a video from storage
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_video_assets(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            assets: Vec<ContentId<T>>,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            let channel_id = video.in_channel;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            Self::ensure_assets_are_tracked(&video.assets, &assets, Error::<T>::VideoAssetNotFound)?;
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            T::StorageSystem::atomically_remove_content(&object_owner, &assets)?;
+
+            VideoById::<T>::mutate(video_id, |video| {
+                video.assets.retain(|content_id| !assets.contains(content_id));
+            });
+
+            Self::deposit_event(RawEvent::VideoAssetsRemoved(actor, video_id, assets));
+        }
+
+        /// Delete the video. A video NFT held by a member is the member's property and is never
+        /// burned without their consent, so it must be transferred back to the channel owner first.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_video(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+        ) {
+
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            let channel_id = video.in_channel;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                // The channel owner will be..
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::UploadVideos,
+            )?;
+
+            Self::ensure_video_can_be_removed(&video)?;
+
+            Self::ensure_video_nft_can_be_burned(&video_id)?;
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Free video assets in storage
+            T::StorageSystem::atomically_remove_content(&object_owner, &video.assets)?;
+
+            // Remove video
+            Self::remove_video(&video_id, &video);
+
+            // Update corresponding channel
+            // Remove recently deleted video from the channel
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                if let Some(index) = channel.videos.iter().position(|x| *x == video_id) {
+                    channel.videos.remove(index);
+                }
+            });
+
+            Self::deposit_event(RawEvent::VideoDeleted(actor, video_id));
+        }
+
+        /// Delete multiple videos of the channel, either all of them are deleted or none
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_videos(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            video_ids: Vec<T::VideoId>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &channel.owner,
+                ChannelCollaboratorPermission::UploadVideos,
+            )?;
+
+            Self::ensure_video_batch_size_is_valid(video_ids.len())?;
+
+            Self::ensure_videos_are_in_channel(&channel_id, &video_ids)?;
+
+            let mut videos = Vec::with_capacity(video_ids.len());
+            for video_id in video_ids.iter() {
+                let video = Self::video_by_id(video_id);
+
+                Self::ensure_video_can_be_removed(&video)?;
+
+                Self::ensure_video_nft_can_be_burned(video_id)?;
+
+                videos.push(video);
+            }
+
+            let assets: Vec<ContentId<T>> = videos
+                .iter()
+                .flat_map(|video| video.assets.iter().cloned())
+                .collect();
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            // check the assets of all videos can be freed in storage
+            T::StorageSystem::can_remove_content(&object_owner, &assets)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Free assets of all videos in storage
+            T::StorageSystem::atomically_remove_content(&object_owner, &assets)?;
+
+            for (video_id, video) in video_ids.iter().zip(videos.iter()) {
+                Self::remove_video(video_id, video);
+            }
+
+            // Remove deleted videos from the channel
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                channel.videos.retain(|video_id| !video_ids.contains(video_id));
+            });
+
+            for video_id in video_ids {
+                Self::deposit_event(RawEvent::VideoDeleted(actor, video_id));
+            }
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_playlist(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            params: PlaylistCreationParameters<T::VideoId>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &channel.owner,
+                ChannelCollaboratorPermission::ManagePlaylists,
+            )?;
+
+            Self::ensure_playlist_videos_are_valid(&channel_id, params.cross_channel, &params.videos)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let playlist_id = Self::next_playlist_id();
+            NextPlaylistId::<T>::mutate(|id| *id += T::PlaylistId::one());
+
+            let playlist: Playlist<T::ChannelId, T::VideoId> = Playlist {
+                in_channel: channel_id,
+                videos: params.videos.clone(),
+                cross_channel: params.cross_channel,
+            };
+
+            for video_id in playlist.videos.iter() {
+                VideoInPlaylist::<T>::insert(video_id, playlist_id, ());
+            }
+
+            PlaylistById::<T>::insert(playlist_id, playlist);
+
+            // Add recently added playlist id to the channel
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                channel.playlists.push(playlist_id);
+            });
+
+            Self::deposit_event(RawEvent::PlaylistCreated(actor, playlist_id, params));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_playlist(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            playlist_id: T::PlaylistId,
+            params: PlaylistUpdateParameters<T::VideoId>,
+        ) {
+            // check that playlist exists
+            let playlist = Self::ensure_playlist_exists(&playlist_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &playlist.in_channel,
+                &Self::channel_by_id(playlist.in_channel).owner,
+                ChannelCollaboratorPermission::ManagePlaylists,
+            )?;
+
+            if let Some(videos) = &params.videos {
+                Self::ensure_playlist_videos_are_valid(&playlist.in_channel, playlist.cross_channel, videos)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(videos) = &params.videos {
+                let mut playlist = playlist;
+
+                for video_id in playlist.videos.iter() {
+                    VideoInPlaylist::<T>::remove(video_id, playlist_id);
+                }
+
+                for video_id in videos.iter() {
+                    VideoInPlaylist::<T>::insert(video_id, playlist_id, ());
+                }
+
+                playlist.videos = videos.clone();
+
+                PlaylistById::<T>::insert(playlist_id, playlist);
+            }
+
+            Self::deposit_event(RawEvent::PlaylistUpdated(actor, playlist_id, params));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_playlist(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            playlist_id: T::PlaylistId,
+        ) {
+            // check that playlist exists
+            let playlist = Self::ensure_playlist_exists(&playlist_id)?;
+
+            ensure!(playlist.in_channel == channel_id, Error::<T>::PlaylistNotInChannel);
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::ManagePlaylists,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for video_id in playlist.videos.iter() {
+                VideoInPlaylist::<T>::remove(video_id, playlist_id);
+            }
+
+            PlaylistById::<T>::remove(playlist_id);
+
+            // Remove recently deleted playlist from the channel
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                if let Some(index) = channel.playlists.iter().position(|x| *x == playlist_id) {
+                    channel.playlists.remove(index);
+                }
+            });
+
+            Self::deposit_event(RawEvent::PlaylistDeleted(actor, playlist_id));
+        }
+
+        /// Set featured videos of the home page, or of the video category if given
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_featured_videos(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            category_id: Option<T::VideoCategoryId>,
+            list: Vec<T::VideoId>
+        ) {
+            // can only be set by lead
+            ensure_actor_authorized_to_set_featured_videos::<T>(
+                origin,
+                &actor,
+            )?;
+
+            if let Some(category_id) = category_id {
+                Self::ensure_video_category_exists(&category_id)?;
+            }
+
+            ensure!(
+                list.len() <= T::MaxNumberOfFeaturedVideos::get() as usize,
+                Error::<T>::FeaturedVideosLimitExceeded
+            );
+
+            for video_id in list.iter() {
+                Self::ensure_video_can_be_featured(video_id)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for video_id in Self::featured_videos(category_id).iter() {
+                FeaturedVideoLists::<T>::remove(video_id, category_id);
+            }
+
+            for video_id in list.iter() {
+                FeaturedVideoLists::<T>::insert(video_id, category_id, ());
+            }
+
+            FeaturedVideos::<T>::insert(category_id, list.clone());
+
+            Self::deposit_event(RawEvent::FeaturedVideosSet(actor, category_id, list));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_video_category(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            params: VideoCategoryCreationParameters<T::VideoCategoryId>,
+        ) {
+            ensure_actor_authorized_to_manage_categories::<T>(
+                origin,
+                &actor
+            )?;
+
+            let depth = match params.parent_category_id {
+                Some(parent_category_id) => Self::ensure_video_category_exists(&parent_category_id)?.depth + 1,
+                None => 0,
+            };
+
+            ensure!(depth <= T::MaxCategoryDepth::get(), Error::<T>::CategoryDepthLimitReached);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let category_id = Self::next_video_category_id();
+            NextVideoCategoryId::<T>::mutate(|id| *id += T::VideoCategoryId::one());
+
+            if let Some(parent_category_id) = params.parent_category_id {
+                VideoCategoryById::<T>::mutate(parent_category_id, |parent| parent.subcategories_count += 1);
+            }
+
+            let category = CategoryRecord {
+                parent_category_id: params.parent_category_id,
+                depth,
+                subcategories_count: 0,
+                items_count: 0,
+            };
+            VideoCategoryById::<T>::insert(category_id, category);
+
+            Self::deposit_event(RawEvent::VideoCategoryCreated(actor, category_id, params));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_video_category(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            category_id: T::VideoCategoryId,
+            params: VideoCategoryUpdateParameters,
+        ) {
+            ensure_actor_authorized_to_manage_categories::<T>(
+                origin,
+                &actor
+            )?;
+
+            Self::ensure_video_category_exists(&category_id)?;
+
+            Self::deposit_event(RawEvent::VideoCategoryUpdated(actor, category_id, params));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_video_category(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            category_id: T::VideoCategoryId,
+        ) {
+            ensure_actor_authorized_to_manage_categories::<T>(
+                origin,
+                &actor
+            )?;
+
+            let category = Self::ensure_video_category_exists(&category_id)?;
+
+            category.ensure_is_unreferenced::<T>()?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(parent_category_id) = category.parent_category_id {
+                VideoCategoryById::<T>::mutate(parent_category_id, |parent| {
+                    parent.subcategories_count = parent.subcategories_count.saturating_sub(1)
+                });
+            }
+
+            VideoCategoryById::<T>::remove(&category_id);
+
+            // Remove featured videos of the category
+            for video_id in FeaturedVideos::<T>::take(Some(category_id)).iter() {
+                FeaturedVideoLists::<T>::remove(video_id, Some(category_id));
+            }
+
+            Self::deposit_event(RawEvent::VideoCategoryDeleted(actor, category_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_video_category(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            category_id: Option<T::VideoCategoryId>,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &video.in_channel,
+                // The channel owner will be..
+                &Self::channel_by_id(video.in_channel).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            if let Some(category_id) = category_id {
+                Self::ensure_video_category_exists(&category_id)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::move_video_between_categories(&video.category, &category_id);
+
+            let mut video = video;
+
+            video.category = category_id;
+
+            VideoById::<T>::insert(video_id, video);
+
+            Self::deposit_event(RawEvent::VideoCategorySet(actor, video_id, category_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_person(
+            origin,
+            actor: PersonActor<T::MemberId, T::CuratorId>,
+            params: PersonCreationParameters<ContentParameters<T>>,
+        ) {
+            ensure_actor_authorized_to_create_person::<T>(
+                origin,
+                &actor,
+            )?;
+
+            // The person controller will be..
+            let controlled_by = Self::actor_to_person_controller(&actor);
+
+            // Pick out the assets to be uploaded to storage frame_system
+            let content_parameters: Vec<ContentParameters<T>> = Self::pick_content_parameters_from_assets(&params.assets);
+
+            let assets = Self::content_ids(&content_parameters);
+
+            let object_owner = Self::person_controller_to_storage_object_owner(&controlled_by);
+
+            let person_id = Self::next_person_id();
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Try add assets to storage
+            T::StorageSystem::atomically_add_content(
+                object_owner,
+                content_parameters,
+            )?;
+
+            // Only increment next person id if adding content was successful
+            NextPersonId::<T>::mutate(|id| *id += T::PersonId::one());
+
+            PersonById::<T>::insert(person_id, Person { controlled_by, assets });
+
+            Self::deposit_event(RawEvent::PersonCreated(actor, person_id, params.assets.clone(), params));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_person(
+            origin,
+            actor: PersonActor<T::MemberId, T::CuratorId>,
+            person_id: T::PersonId,
+            params: PersonUpdateParameters<ContentParameters<T>>,
+        ) {
+            // check that person exists
+            let person = Self::ensure_person_exists(&person_id)?;
+
+            ensure_actor_authorized_to_update_or_delete_person::<T>(
+                origin,
+                &actor,
+                &person.controlled_by,
+            )?;
+
+            // Pick out the assets to be uploaded to storage frame_system
+            let new_assets = if let Some(assets) = &params.assets {
+                let upload_parameters: Vec<ContentParameters<T>> = Self::pick_content_parameters_from_assets(assets);
+
+                let object_owner = Self::person_controller_to_storage_object_owner(&person.controlled_by);
+
+                // check assets can be uploaded to storage.
+                // update can_add_content() to only take &refrences
+                T::StorageSystem::can_add_content(
+                    object_owner.clone(),
+                    upload_parameters.clone(),
+                )?;
+
+                Some((upload_parameters, object_owner))
+            } else {
+                None
+            };
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // add assets to storage
+            // This should not fail because of prior can_add_content() check!
+            if let Some((upload_parameters, object_owner)) = new_assets {
+                let content_ids = Self::content_ids(&upload_parameters);
+
+                T::StorageSystem::atomically_add_content(
+                    object_owner,
+                    upload_parameters,
+                )?;
+
+                // Keep track of newly uploaded assets
+                PersonById::<T>::mutate(person_id, |person| person.assets.extend(content_ids));
+            }
+
+            Self::deposit_event(RawEvent::PersonUpdated(
+                actor,
+                person_id,
+                params.assets.clone().unwrap_or_default(),
+                params,
+            ));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_person(
+            origin,
+            actor: PersonActor<T::MemberId, T::CuratorId>,
+            person_id: T::PersonId,
+        ) {
+            // check that person exists
+            let person = Self::ensure_person_exists(&person_id)?;
+
+            ensure_actor_authorized_to_update_or_delete_person::<T>(
+                origin,
+                &actor,
+                &person.controlled_by,
+            )?;
+
+            let object_owner = Self::person_controller_to_storage_object_owner(&person.controlled_by);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Free person assets in storage
+            T::StorageSystem::atomically_remove_content(&object_owner, &person.assets)?;
+
+            // Remove all video credits of the person
+            for (video_id, _) in VideoOfPerson::<T>::iter_prefix(&person_id) {
+                PersonInVideo::<T>::remove(video_id, person_id);
+            }
+            VideoOfPerson::<T>::remove_prefix(&person_id);
+
+            PersonById::<T>::remove(person_id);
+
+            Self::deposit_event(RawEvent::PersonDeleted(actor, person_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_person_to_video(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            person_id: T::PersonId,
+        ) {
+            // check that video exists, retrieve corresponding channel id.
+            let channel_id = Self::ensure_video_exists(&video_id)?.in_channel;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            // check that person exists
+            Self::ensure_person_exists(&person_id)?;
+
+            ensure!(
+                !PersonInVideo::<T>::contains_key(video_id, person_id),
+                Error::<T>::PersonAlreadyInVideo
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            PersonInVideo::<T>::insert(video_id, person_id, ());
+            VideoOfPerson::<T>::insert(person_id, video_id, ());
+
+            Self::deposit_event(RawEvent::PersonAddedToVideo(actor, video_id, person_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_person_from_video(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            person_id: T::PersonId,
+        ) {
+            // check that video exists, retrieve corresponding channel id.
+            let channel_id = Self::ensure_video_exists(&video_id)?.in_channel;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            ensure!(
+                PersonInVideo::<T>::contains_key(video_id, person_id),
+                Error::<T>::PersonNotInVideo
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            PersonInVideo::<T>::remove(video_id, person_id);
+            VideoOfPerson::<T>::remove(person_id, video_id);
+
+            Self::deposit_event(RawEvent::PersonRemovedFromVideo(actor, video_id, person_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_video_censorship_status(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            is_censored: bool,
+            rationale: Vec<u8>,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            if video.is_censored == is_censored {
+                return Ok(())
+            }
+
+            ensure_actor_authorized_to_censor::<T>(
+                origin,
+                &actor,
+                // The channel owner will be..
+                &Self::channel_by_id(video.in_channel).owner,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::set_video_censorship_status(video_id, video, is_censored);
+
+            Self::deposit_event(RawEvent::VideoCensorshipStatusUpdated(actor, video_id, is_censored, rationale));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_series(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            params: SeriesParameters<T::VideoId, ContentParameters<T>>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &channel.owner,
+                ChannelCollaboratorPermission::UploadVideos,
+            )?;
+
+            let series_id = Self::next_series_id();
+
+            // Resolve episodes of all seasons, every season and episode must be provided
+            let planned_seasons = Self::plan_series_seasons(&[], &params)?;
+
+            Self::ensure_series_episodes_are_valid(&channel_id, &series_id, &planned_seasons)?;
+
+            // Pick out the assets to be uploaded to storage frame_system
+            let content_parameters: Vec<ContentParameters<T>> =
+                Self::pick_content_parameters_from_series(&params, &planned_seasons);
+
+            // Episode assets are tracked by the episode videos
+            let assets = Self::content_ids(&Self::pick_series_content_parameters(&params));
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Try add assets to storage
+            T::StorageSystem::atomically_add_content(
+                object_owner,
+                content_parameters,
+            )?;
+
+            // Only increment next series id if adding content was successful
+            NextSeriesId::<T>::mutate(|id| *id += T::SeriesId::one());
+
+            let seasons = Self::apply_series_seasons(&actor, &channel_id, &series_id, planned_seasons);
+
+            let series: Series<T::ChannelId, T::VideoId, ContentId<T>> = Series {
+                in_channel: channel_id,
+                seasons,
+                assets,
+            };
+
+            SeriesById::<T>::insert(series_id, series.clone());
+
+            // Add recently added series id to the channel
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                channel.series.push(series_id);
+            });
+
+            Self::deposit_event(RawEvent::SeriesCreated(
+                actor,
+                series_id,
+                params.assets.clone().unwrap_or_default(),
+                params,
+                series,
+            ));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_series(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            series_id: T::SeriesId,
+            params: SeriesParameters<T::VideoId, ContentParameters<T>>,
+        ) {
+            // check that series exists, retrieve corresponding channel id.
+            let series = Self::ensure_series_exists(&series_id)?;
+
+            let channel_id = series.in_channel;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::UploadVideos,
+            )?;
+
+            // Resolve episodes of all seasons, keeping seasons and episodes which are not updated
+            let planned_seasons = Self::plan_series_seasons(&series.seasons, &params)?;
+
+            Self::ensure_series_episodes_are_valid(&channel_id, &series_id, &planned_seasons)?;
+
+            // Pick out the assets to be uploaded to storage frame_system
+            let content_parameters: Vec<ContentParameters<T>> =
+                Self::pick_content_parameters_from_series(&params, &planned_seasons);
+
+            // Episode assets are tracked by the episode videos
+            let new_assets = Self::content_ids(&Self::pick_series_content_parameters(&params));
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Try add assets to storage
+            T::StorageSystem::atomically_add_content(
+                object_owner,
+                content_parameters,
+            )?;
+
+            // Release videos which are no longer episodes of the series
+            let remaining_episodes: BTreeSet<T::VideoId> =
+                Self::existing_videos_in_seasons(&planned_seasons).into_iter().collect();
+
+            for video_id in series.seasons.iter().flat_map(|season| season.episodes.iter()) {
+                if !remaining_episodes.contains(video_id) {
+                    VideoById::<T>::mutate(video_id, |video| video.in_series = None);
+                }
+            }
+
+            let mut series = series;
+
+            series.seasons = Self::apply_series_seasons(&actor, &channel_id, &series_id, planned_seasons);
+
+            // Keep track of newly uploaded assets
+            series.assets.extend(new_assets);
+
+            SeriesById::<T>::insert(series_id, series.clone());
+
+            Self::deposit_event(RawEvent::SeriesUpdated(
+                actor,
+                series_id,
+                params.assets.clone().unwrap_or_default(),
+                params,
+                series,
+            ));
+        }
+
+        /// Delete a series and free the series and season assets.
+        /// Its episodes are kept as regular videos of the channel.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_series(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            series_id: T::SeriesId,
+        ) {
+            // check that series exists
+            let series = Self::ensure_series_exists(&series_id)?;
+
+            let channel_id = series.in_channel;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &channel_id,
+                &Self::channel_by_id(channel_id).owner,
+                ChannelCollaboratorPermission::UploadVideos,
+            )?;
+
+            let object_owner = StorageObjectOwner::<T>::Channel(channel_id);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Free series assets in storage
+            T::StorageSystem::atomically_remove_content(&object_owner, &series.assets)?;
+
+            // Release all episodes of the series
+            for video_id in series.seasons.iter().flat_map(|season| season.episodes.iter()) {
+                VideoById::<T>::mutate(video_id, |video| video.in_series = None);
+            }
+
+            SeriesById::<T>::remove(series_id);
+
+            // Remove recently deleted series from the channel
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                if let Some(index) = channel.series.iter().position(|x| *x == series_id) {
+                    channel.series.remove(index);
+                }
+            });
+
+            Self::deposit_event(RawEvent::SeriesDeleted(actor, series_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn like_video(
+            origin,
+            member_id: T::MemberId,
+            video_id: T::VideoId,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+            Self::ensure_video_exists(&video_id)?;
+
+            ensure!(
+                !VideoLikedBy::<T>::contains_key(video_id, member_id),
+                Error::<T>::VideoAlreadyLiked
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            VideoLikedBy::<T>::insert(video_id, member_id, ());
+            LikesCountByVideo::<T>::mutate(video_id, |count| *count += 1);
+
+            Self::deposit_event(RawEvent::VideoLiked(member_id, video_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unlike_video(
+            origin,
+            member_id: T::MemberId,
+            video_id: T::VideoId,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+            ensure!(
+                VideoLikedBy::<T>::contains_key(video_id, member_id),
+                Error::<T>::VideoNotLiked
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            VideoLikedBy::<T>::remove(video_id, member_id);
+            LikesCountByVideo::<T>::mutate(video_id, |count| *count -= 1);
+
+            Self::deposit_event(RawEvent::VideoUnliked(member_id, video_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn post_comment(
+            origin,
+            member_id: T::MemberId,
+            video_id: T::VideoId,
+            parent_comment_id: Option<T::CommentId>,
+            text: Vec<u8>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+            Self::ensure_video_exists(&video_id)?;
+
+            ensure!(!Self::video_comments_disabled(video_id), Error::<T>::VideoCommentsDisabled);
+
+            // Replies must stay within the thread of the video
+            if let Some(parent_comment_id) = parent_comment_id {
+                Self::ensure_comment_exists(&video_id, &parent_comment_id)?;
+            }
+
+            Self::ensure_comment_text_is_valid(&text)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let comment_id = Self::next_comment_id();
+            NextCommentId::<T>::mutate(|id| *id += T::CommentId::one());
+
+            let comment = Comment {
+                author: member_id,
+                parent_comment_id,
+                is_moderated: false,
+            };
+
+            CommentById::<T>::insert(video_id, comment_id, comment);
+
+            Self::deposit_event(RawEvent::CommentPosted(member_id, video_id, comment_id, parent_comment_id, text));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn moderate_comment(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            comment_id: T::CommentId,
+            rationale: Vec<u8>,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &video.in_channel,
+                // The channel owner will be..
+                &Self::channel_by_id(video.in_channel).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            let comment = Self::ensure_comment_exists(&video_id, &comment_id)?;
+
+            ensure!(!comment.is_moderated, Error::<T>::CommentAlreadyModerated);
+
+            Self::ensure_comment_moderation_rationale_is_valid(&rationale)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            CommentById::<T>::mutate(video_id, comment_id, |comment| comment.is_moderated = true);
+
+            Self::deposit_event(RawEvent::CommentModerated(actor, video_id, comment_id, rationale));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_video_comments_status(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            comments_enabled: bool,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &video.in_channel,
+                // The channel owner will be..
+                &Self::channel_by_id(video.in_channel).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if comments_enabled {
+                VideoCommentsDisabled::<T>::remove(video_id);
+            } else {
+                VideoCommentsDisabled::<T>::insert(video_id, true);
+            }
+
+            Self::deposit_event(RawEvent::VideoCommentsStatusSet(actor, video_id, comments_enabled));
+        }
+
+        /// Set the length constraints of the comment texts and comment moderation rationales.
+        /// Requires lead or root origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_comment_constraints(
+            origin,
+            text_constraint: InputValidationLengthConstraint,
+            moderation_rationale_constraint: InputValidationLengthConstraint,
+        ) {
+            ensure_is_lead_or_root::<T>(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            CommentTextConstraint::put(text_constraint);
+
+            CommentModerationRationaleConstraint::put(moderation_rationale_constraint);
+
+            Self::deposit_event(RawEvent::CommentConstraintsSet(text_constraint, moderation_rationale_constraint));
+        }
+
+        /// Set visibility of the video, optionally scheduling it to become public at `publish_at` block
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_video_visibility(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            visibility: VideoVisibility,
+            publish_at: Option<T::BlockNumber>,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            ensure_actor_authorized_to_update_channel_content::<T>(
+                origin,
+                &actor,
+                &video.in_channel,
+                // The channel owner will be..
+                &Self::channel_by_id(video.in_channel).owner,
+                ChannelCollaboratorPermission::EditMetadata,
+            )?;
+
+            if let Some(publish_at) = publish_at {
+                ensure!(
+                    visibility != VideoVisibility::Public,
+                    Error::<T>::VideoIsAlreadyPublic
+                );
+                ensure!(
+                    publish_at > <frame_system::Module<T>>::block_number(),
+                    Error::<T>::VideoPublishBlockNotInFuture
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::unschedule_video_publishing(&video, &video_id);
+
+            if let Some(publish_at) = publish_at {
+                VideosByPublishBlock::<T>::insert(publish_at, video_id, ());
+            }
+
+            let mut video = video;
+
+            video.visibility = visibility;
+            video.publish_at = publish_at;
+
+            VideoById::<T>::insert(video_id, video);
+
+            // Only public videos can be featured
+            if visibility != VideoVisibility::Public {
+                Self::remove_video_from_featured_lists(&video_id);
+            }
+
+            Self::deposit_event(RawEvent::VideoVisibilitySet(actor, video_id, visibility, publish_at));
+        }
+
+        /// Issue an NFT for the video, held by the channel owner unless given member
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn issue_nft(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            creator_royalty: Option<Perbill>,
+            to: Option<T::MemberId>,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            // Only channel owner can issue NFTs of its videos
+            ensure_actor_authorized_to_update_channel::<T>(
+                origin,
+                &actor,
+                &Self::channel_by_id(video.in_channel).owner,
+            )?;
+
+            ensure!(!NftByVideo::<T>::contains_key(video_id), Error::<T>::NftAlreadyIssued);
+
+            if let Some(creator_royalty) = creator_royalty {
+                ensure!(
+                    creator_royalty <= T::MaxCreatorRoyalty::get(),
+                    Error::<T>::CreatorRoyaltyTooHigh
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let nft = OwnedNft::<T> {
+                owner: to.map_or(NftOwner::ChannelOwner, NftOwner::Member),
+                creator_royalty,
+                transactional_status: NftTransactionalStatus::Idle,
+            };
+
+            NftByVideo::<T>::insert(video_id, nft.clone());
+
+            Self::deposit_event(RawEvent::NftIssued(actor, video_id, nft));
+        }
+
+        /// Transfer the video NFT which is not on sale
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn transfer_nft(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            to: NftOwner<T::MemberId>,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            let nft = Self::ensure_nft_exists(&video_id)?;
+
+            ensure_actor_authorized_to_manage_nft::<T>(
+                origin,
+                &actor,
+                &nft.owner,
+                &Self::channel_by_id(video.in_channel).owner,
+            )?;
+
+            nft.ensure_is_idle::<T>()?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let mut nft = nft;
+
+            nft.owner = to;
+
+            NftByVideo::<T>::insert(video_id, nft);
+
+            Self::deposit_event(RawEvent::NftTransferred(actor, video_id, to));
+        }
+
+        /// Offer the video NFT for a fixed price
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn sell_nft(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            price: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin.clone())?;
+
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            let nft = Self::ensure_nft_exists(&video_id)?;
+
+            ensure_actor_authorized_to_manage_nft::<T>(
+                origin,
+                &actor,
+                &nft.owner,
+                &Self::channel_by_id(video.in_channel).owner,
+            )?;
+
+            nft.ensure_is_idle::<T>()?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let seller_account = Self::nft_seller_account(&video.in_channel, &nft, sender);
+
+            let mut nft = nft;
+
+            nft.transactional_status = NftTransactionalStatus::BuyNow(FixedPriceSaleRecord {
+                price,
+                seller_account,
+            });
+
+            NftByVideo::<T>::insert(video_id, nft);
+
+            Self::deposit_event(RawEvent::NftSellOrderMade(actor, video_id, price));
+        }
+
+        /// Buy the video NFT offered for a fixed price
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn buy_nft(
+            origin,
+            member_id: T::MemberId,
+            video_id: T::VideoId,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            let nft = Self::ensure_nft_exists(&video_id)?;
+
+            let sale = if let NftTransactionalStatus::BuyNow(sale) = &nft.transactional_status {
+                sale.clone()
+            } else {
+                return Err(Error::<T>::NftIsNotOnSale.into());
+            };
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Try move the payment into escrow
+            T::Currency::transfer(
+                &sender,
+                &Self::nft_payment_escrow_account(),
+                sale.price,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            Self::release_nft_payment(&video.in_channel, &nft, &sale.seller_account, sale.price);
+
+            let mut nft = nft;
+
+            nft.owner = NftOwner::Member(member_id);
+            nft.transactional_status = NftTransactionalStatus::Idle;
+
+            NftByVideo::<T>::insert(video_id, nft);
+
+            Self::deposit_event(RawEvent::NftBought(member_id, video_id, sale.price));
+        }
+
+        /// Start english auction of the video NFT
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn start_english_auction(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+            params: AuctionParams<BalanceOf<T>, T::BlockNumber>,
+        ) {
+            let sender = ensure_signed(origin.clone())?;
+
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            let nft = Self::ensure_nft_exists(&video_id)?;
+
+            ensure_actor_authorized_to_manage_nft::<T>(
+                origin,
+                &actor,
+                &nft.owner,
+                &Self::channel_by_id(video.in_channel).owner,
+            )?;
+
+            nft.ensure_is_idle::<T>()?;
+
+            ensure!(
+                params.duration >= T::MinAuctionDuration::get(),
+                Error::<T>::AuctionDurationTooShort
+            );
+            ensure!(
+                params.duration <= T::MaxAuctionDuration::get(),
+                Error::<T>::AuctionDurationTooLong
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let auction = EnglishAuction::<T> {
+                starting_price: params.starting_price,
+                min_bid_step: params.min_bid_step,
+                end: <frame_system::Module<T>>::block_number() + params.duration,
+                seller_account: Self::nft_seller_account(&video.in_channel, &nft, sender),
+                top_bid: None,
+            };
+
+            AuctionsByEndBlock::<T>::insert(auction.end, video_id, ());
+
+            let mut nft = nft;
+
+            nft.transactional_status = NftTransactionalStatus::Auction(auction.clone());
+
+            NftByVideo::<T>::insert(video_id, nft);
+
+            Self::deposit_event(RawEvent::EnglishAuctionStarted(actor, video_id, auction));
+        }
+
+        /// Bid in english auction of the video NFT, the bid is held in escrow until outbid
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn make_bid(
+            origin,
+            member_id: T::MemberId,
+            video_id: T::VideoId,
+            amount: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+            let nft = Self::ensure_nft_exists(&video_id)?;
+
+            let auction = if let NftTransactionalStatus::Auction(auction) = &nft.transactional_status {
+                auction.clone()
+            } else {
+                return Err(Error::<T>::NftIsNotInAuction.into());
+            };
+
+            if let Some(top_bid) = &auction.top_bid {
+                ensure!(
+                    amount > top_bid.amount
+                        && amount >= top_bid.amount.saturating_add(auction.min_bid_step),
+                    Error::<T>::BidTooLow
+                );
+            } else {
+                ensure!(amount >= auction.starting_price, Error::<T>::BidTooLow);
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Try move the bid into escrow
+            T::Currency::transfer(
+                &sender,
+                &Self::nft_payment_escrow_account(),
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            // Refund the outbid bid
+            if let Some(top_bid) = &auction.top_bid {
+                Self::pay_or_defer(
+                    &Self::nft_payment_escrow_account(),
+                    &top_bid.bidder_account,
+                    top_bid.amount,
+                );
+            }
+
+            let mut auction = auction;
+
+            auction.top_bid = Some(BidRecord {
+                bidder: member_id,
+                bidder_account: sender,
+                amount,
+            });
+
+            let mut nft = nft;
+
+            nft.transactional_status = NftTransactionalStatus::Auction(auction);
+
+            NftByVideo::<T>::insert(video_id, nft);
+
+            Self::deposit_event(RawEvent::AuctionBidMade(member_id, video_id, amount));
+        }
+
+        /// Cancel fixed price sale, or english auction which received no bids, of the video NFT
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn cancel_nft_sale(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            video_id: T::VideoId,
+        ) {
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            let nft = Self::ensure_nft_exists(&video_id)?;
+
+            ensure_actor_authorized_to_manage_nft::<T>(
+                origin,
+                &actor,
+                &nft.owner,
+                &Self::channel_by_id(video.in_channel).owner,
+            )?;
+
+            match &nft.transactional_status {
+                NftTransactionalStatus::Idle => {
+                    return Err(Error::<T>::NftIsNotOnSale.into());
+                }
+                NftTransactionalStatus::BuyNow(_) => (),
+                NftTransactionalStatus::Auction(auction) => {
+                    ensure!(auction.top_bid.is_none(), Error::<T>::AuctionHasBids);
+                }
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let NftTransactionalStatus::Auction(auction) = &nft.transactional_status {
+                AuctionsByEndBlock::<T>::remove(auction.end, video_id);
+            }
+
+            let mut nft = nft;
+
+            nft.transactional_status = NftTransactionalStatus::Idle;
+
+            NftByVideo::<T>::insert(video_id, nft);
+
+            Self::deposit_event(RawEvent::NftSaleCanceled(actor, video_id));
+        }
+
+        /// Claim the payment out of the `payer` module account which could not be transferred
+        /// to the sender when due, such as an outbid refund or the proceeds of a video NFT sale
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn claim_payment(origin, payer: T::AccountId) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(
+                UnclaimedPayments::<T>::contains_key(&payer, &sender),
+                Error::<T>::NoUnclaimedPayment
+            );
+
+            let amount = Self::unclaimed_payment(&payer, &sender);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            T::Currency::transfer(&payer, &sender, amount, ExistenceRequirement::AllowDeath)?;
+
+            UnclaimedPayments::<T>::remove(&payer, &sender);
+
+            Self::deposit_event(RawEvent::PaymentClaimed(payer, sender, amount));
+        }
+
+        /// Sets the capacity of the mint funding channel rewards. Requires root origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_content_mint_capacity(
+            origin,
+            new_capacity: minting::BalanceOf<T>,
+        ) {
+            ensure_root(origin)?;
+
+            let mint_id = Self::ensure_content_mint_exists()?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <minting::Module<T>>::set_mint_capacity(mint_id, new_capacity)
+                .map_err(|_| Error::<T>::ContentMintNotFound)?;
+
+            Self::deposit_event(RawEvent::ContentMintCapacityChanged(mint_id, new_capacity));
+        }
+
+        /// Post merkle root of the cumulative earnings of all channels
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_rewards_commitment(
+            origin,
+            commitment: T::Hash,
+        ) {
+            ensure_is_lead::<T>(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            CommitmentOfRewards::<T>::put(commitment);
+
+            Self::deposit_event(RawEvent::RewardsCommitmentUpdated(commitment));
+        }
+
+        /// Claim channel earnings not claimed yet, proving them against the rewards commitment
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn claim_channel_reward(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            proof: Vec<ProofElement<T>>,
+            claim: ChannelRewardClaim<T>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&claim.channel_id)?;
+
+            ensure_actor_authorized_to_update_channel::<T>(
+                origin,
+                &actor,
+                &channel.owner,
+            )?;
+
+            let reward_account = channel.reward_account.ok_or(Error::<T>::ChannelRewardAccountNotSet)?;
+
+            ensure!(
+                claim.compute_merkle_root::<T>(&proof) == Self::commitment_of_rewards(),
+                Error::<T>::RewardClaimProofInvalid
+            );
+
+            let claimed = Self::claimed_rewards_by_channel(claim.channel_id);
+
+            // Earnings claimed already are not paid again
+            ensure!(claim.cumulative_earnings > claimed, Error::<T>::RewardAlreadyClaimed);
+
+            let amount = claim.cumulative_earnings - claimed;
+
+            let mint_id = Self::ensure_content_mint_exists()?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            // Try pay the reward from the content mint
+            <minting::Module<T>>::transfer_tokens(mint_id, amount, &reward_account)
+                .map_err(|err| match err {
+                    minting::TransferError::MintNotFound => Error::<T>::ContentMintNotFound,
+                    minting::TransferError::NotEnoughCapacity => Error::<T>::InsufficientContentMintCapacity,
+                })?;
+
+            ClaimedRewardsByChannel::<T>::insert(claim.channel_id, claim.cumulative_earnings);
+
+            Self::deposit_event(RawEvent::ChannelRewardClaimed(actor, claim.channel_id, amount));
+        }
+
+        /// Add paid subscription tier to the channel
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_subscription_tier(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            tier: SubscriptionTier<T>,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel::<T>(
+                origin,
+                &actor,
+                &channel.owner,
+            )?;
+
+            ensure!(!tier.price.is_zero(), Error::<T>::SubscriptionPriceIsZero);
+
+            ensure!(
+                tier.period >= T::MinSubscriptionPeriod::get(),
+                Error::<T>::SubscriptionPeriodTooShort
+            );
+
+            let tier_id = Self::next_subscription_tier_id();
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            NextSubscriptionTierId::<T>::mutate(|id| *id += T::SubscriptionTierId::one());
+
+            SubscriptionTierById::<T>::insert(channel_id, tier_id, tier.clone());
+
+            Self::deposit_event(RawEvent::SubscriptionTierCreated(actor, channel_id, tier_id, tier));
+        }
+
+        /// Remove subscription tier of the channel, existing subscriptions to it are not renewed
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_subscription_tier(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+            channel_id: T::ChannelId,
+            tier_id: T::SubscriptionTierId,
+        ) {
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_update_channel::<T>(
+                origin,
+                &actor,
+                &channel.owner,
+            )?;
+
+            Self::ensure_subscription_tier_exists(&channel_id, &tier_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            SubscriptionTierById::<T>::remove(channel_id, tier_id);
+
+            Self::deposit_event(RawEvent::SubscriptionTierRemoved(actor, channel_id, tier_id));
+        }
+
+        /// Subscribe to the channel, paying the first period of the tier upfront
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn subscribe_to_channel(
+            origin,
+            member_id: T::MemberId,
+            channel_id: T::ChannelId,
+            tier_id: T::SubscriptionTierId,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            let tier = Self::ensure_subscription_tier_exists(&channel_id, &tier_id)?;
+
+            ensure!(
+                !ChannelSubscriptions::<T>::contains_key(channel_id, member_id),
+                Error::<T>::AlreadySubscribedToChannel
+            );
+
+            ensure!(channel.reward_account.is_some(), Error::<T>::ChannelRewardAccountNotSet);
+
+            ensure!(
+                T::Currency::free_balance(&sender) >= tier.price,
+                Error::<T>::InsufficientBalanceForSubscription
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This should be first mutation
+            Self::pay_channel_subscription(&sender, &channel_id, tier.price)?;
+
+            let paid_until = <frame_system::Module<T>>::block_number() + tier.period;
+
+            let subscription = SubscriptionRecord {
+                tier_id,
+                payer: sender,
+                paid_until,
+                auto_renew: true,
+            };
+
+            ChannelSubscriptions::<T>::insert(channel_id, member_id, subscription.clone());
+            SubscriptionsByRenewalBlock::<T>::insert(paid_until, (channel_id, member_id), ());
+
+            Self::deposit_event(RawEvent::ChannelSubscribed(member_id, channel_id, subscription));
+        }
+
+        /// Stop renewing the subscription, it stays active until the end of the paid period
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn cancel_channel_subscription(
+            origin,
+            member_id: T::MemberId,
+            channel_id: T::ChannelId,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+            ensure!(
+                ChannelSubscriptions::<T>::contains_key(channel_id, member_id),
+                Error::<T>::NotSubscribedToChannel
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ChannelSubscriptions::<T>::mutate(channel_id, member_id, |subscription| {
+                subscription.auto_renew = false;
+            });
+
+            Self::deposit_event(RawEvent::ChannelSubscriptionCanceled(member_id, channel_id));
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Ensure `CuratorGroup` under given id exists
+    fn ensure_curator_group_under_given_id_exists(
+        curator_group_id: &T::CuratorGroupId,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            <CuratorGroupById<T>>::contains_key(curator_group_id),
+            Error::<T>::CuratorGroupDoesNotExist
+        );
+        Ok(())
+    }
+
+    /// Ensure `CuratorGroup` under given id exists, return corresponding one
+    fn ensure_curator_group_exists(
+        curator_group_id: &T::CuratorGroupId,
+    ) -> Result<CuratorGroup<T>, Error<T>> {
+        Self::ensure_curator_group_under_given_id_exists(curator_group_id)?;
+        Ok(Self::curator_group_by_id(curator_group_id))
+    }
+
+    fn ensure_content_mint_exists() -> Result<<T as minting::Trait>::MintId, Error<T>> {
+        let mint_id = Self::content_mint().ok_or(Error::<T>::ContentMintNotFound)?;
+        ensure!(
+            <minting::Mints<T>>::contains_key(mint_id),
+            Error::<T>::ContentMintNotFound
+        );
+        Ok(mint_id)
+    }
+
+    fn ensure_channel_exists(channel_id: &T::ChannelId) -> Result<Channel<T>, Error<T>> {
+        ensure!(
+            ChannelById::<T>::contains_key(channel_id),
+            Error::<T>::ChannelDoesNotExist
+        );
+        Ok(ChannelById::<T>::get(channel_id))
+    }
+
+    fn ensure_video_exists(
+        video_id: &T::VideoId,
+    ) -> Result<
+        Video<T::ChannelId, T::SeriesId, ContentId<T>, T::VideoCategoryId, T::BlockNumber>,
+        Error<T>,
+    > {
+        ensure!(
+            VideoById::<T>::contains_key(video_id),
+            Error::<T>::VideoDoesNotExist
+        );
+        Ok(VideoById::<T>::get(video_id))
+    }
+
+    // Ensure given video is not in season
+    fn ensure_video_can_be_removed(
+        video: &Video<T::ChannelId, T::SeriesId, ContentId<T>, T::VideoCategoryId, T::BlockNumber>,
+    ) -> DispatchResult {
+        ensure!(video.in_series.is_none(), Error::<T>::VideoInSeason);
+        Ok(())
+    }
+
+    fn ensure_playlist_exists(
+        playlist_id: &T::PlaylistId,
+    ) -> Result<Playlist<T::ChannelId, T::VideoId>, Error<T>> {
+        ensure!(
+            PlaylistById::<T>::contains_key(playlist_id),
+            Error::<T>::PlaylistDoesNotExist
+        );
+        Ok(PlaylistById::<T>::get(playlist_id))
+    }
+
+    // Ensure playlist videos exist, are unique, fit the playlist length limit
+    // and belong to the playlist channel unless the playlist is cross channel
+    fn ensure_playlist_videos_are_valid(
+        channel_id: &T::ChannelId,
+        cross_channel: bool,
+        videos: &[T::VideoId],
+    ) -> DispatchResult {
+        ensure!(
+            videos.len() <= T::MaxNumberOfVideosPerPlaylist::get() as usize,
+            Error::<T>::VideosPerPlaylistLimitReached
+        );
+
+        let unique_videos: BTreeSet<_> = videos.iter().collect();
+        ensure!(
+            unique_videos.len() == videos.len(),
+            Error::<T>::DuplicateVideoInPlaylist
+        );
+
+        for video_id in videos.iter() {
+            let video = Self::ensure_video_exists(video_id)?;
+            ensure!(
+                cross_channel || video.in_channel == *channel_id,
+                Error::<T>::VideoNotInPlaylistChannel
+            );
+        }
+        Ok(())
+    }
+
+    // Remove all references to the video from playlists
+    fn remove_video_from_playlists(video_id: &T::VideoId) {
+        for (playlist_id, _) in VideoInPlaylist::<T>::iter_prefix(video_id) {
+            PlaylistById::<T>::mutate(playlist_id, |playlist| {
+                playlist.videos.retain(|x| x != video_id);
+            });
+        }
+        VideoInPlaylist::<T>::remove_prefix(video_id);
+    }
+
+    fn ensure_series_exists(
+        series_id: &T::SeriesId,
+    ) -> Result<Series<T::ChannelId, T::VideoId, ContentId<T>>, Error<T>> {
+        ensure!(
+            SeriesById::<T>::contains_key(series_id),
+            Error::<T>::SeriesDoesNotExist
+        );
+        Ok(SeriesById::<T>::get(series_id))
+    }
+
+    // Resolve the episodes of every season once series parameters are applied.
+    // Seasons and episodes set to `None` keep their current value, while seasons
+    // and episodes extending the series must all be provided.
+    fn plan_series_seasons(
+        current_seasons: &[Season<T::VideoId>],
+        params: &SeriesParameters<T::VideoId, ContentParameters<T>>,
+    ) -> Result<Vec<Vec<EpisodeParameters<T::VideoId, ContentParameters<T>>>>, Error<T>> {
+        let seasons = match &params.seasons {
+            Some(seasons) => seasons,
+            None => {
+                return Ok(current_seasons
+                    .iter()
+                    .map(|season| Self::existing_episodes(&season.episodes))
+                    .collect())
+            }
+        };
+
+        seasons
+            .iter()
+            .enumerate()
+            .map(|(index, season)| {
+                let current_episodes = current_seasons
+                    .get(index)
+                    .map(|season| season.episodes.as_slice());
+
+                match (season, current_episodes) {
+                    (Some(season), current_episodes) => Self::plan_season_episodes(
+                        current_episodes.unwrap_or_default(),
+                        &season.episodes,
+                    ),
+                    (None, Some(current_episodes)) => Ok(Self::existing_episodes(current_episodes)),
+                    (None, None) => Err(Error::<T>::SeasonParametersMissing),
+                }
+            })
+            .collect()
+    }
+
+    // Resolve the episodes of a season once episode parameters are applied.
+    fn plan_season_episodes(
+        current_episodes: &[T::VideoId],
+        episodes: &Option<Vec<Option<EpisodeParameters<T::VideoId, ContentParameters<T>>>>>,
+    ) -> Result<Vec<EpisodeParameters<T::VideoId, ContentParameters<T>>>, Error<T>> {
+        let episodes = match episodes {
+            Some(episodes) => episodes,
+            None => return Ok(Self::existing_episodes(current_episodes)),
+        };
+
+        episodes
+            .iter()
+            .enumerate()
+            .map(
+                |(index, episode)| match (episode, current_episodes.get(index)) {
+                    (Some(episode), _) => Ok(episode.clone()),
+                    (None, Some(video_id)) => Ok(EpisodeParameters::ExistingVideo(*video_id)),
+                    (None, None) => Err(Error::<T>::EpisodeParametersMissing),
+                },
+            )
+            .collect()
+    }
+
+    fn existing_episodes(
+        episodes: &[T::VideoId],
+    ) -> Vec<EpisodeParameters<T::VideoId, ContentParameters<T>>> {
+        episodes
+            .iter()
+            .map(|video_id| EpisodeParameters::ExistingVideo(*video_id))
+            .collect()
+    }
+
+    fn existing_videos_in_seasons(
+        seasons: &[Vec<EpisodeParameters<T::VideoId, ContentParameters<T>>>],
+    ) -> Vec<T::VideoId> {
+        seasons
+            .iter()
+            .flatten()
+            .filter_map(|episode| match episode {
+                EpisodeParameters::ExistingVideo(video_id) => Some(*video_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Ensure existing videos used as episodes belong to the series channel,
+    // are not episodes of another series and are used only once
+    fn ensure_series_episodes_are_valid(
+        channel_id: &T::ChannelId,
+        series_id: &T::SeriesId,
+        seasons: &[Vec<EpisodeParameters<T::VideoId, ContentParameters<T>>>],
+    ) -> DispatchResult {
+        let mut episodes = BTreeSet::new();
+
+        for video_id in Self::existing_videos_in_seasons(seasons) {
+            ensure!(
+                episodes.insert(video_id),
+                Error::<T>::DuplicateEpisodeInSeries
+            );
+
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            ensure!(
+                video.in_channel == *channel_id,
+                Error::<T>::VideoNotInSeriesChannel
+            );
+
+            ensure!(
+                video.in_series.is_none() || video.in_series == Some(*series_id),
+                Error::<T>::VideoAlreadyInSeries
+            );
+        }
+        Ok(())
+    }
+
+    // Assets of the series, updated seasons and episodes created as new videos
+    fn pick_content_parameters_from_series(
+        params: &SeriesParameters<T::VideoId, ContentParameters<T>>,
+        seasons: &[Vec<EpisodeParameters<T::VideoId, ContentParameters<T>>>],
+    ) -> Vec<ContentParameters<T>> {
+        let mut content_parameters = Self::pick_series_content_parameters(params);
+
+        for episode in seasons.iter().flatten() {
+            if let EpisodeParameters::NewVideo(video_params) = episode {
+                content_parameters.extend(Self::pick_content_parameters_from_assets(
+                    &video_params.assets,
+                ));
+            }
+        }
+
+        content_parameters
+    }
+
+    // Assets of the series and its updated seasons
+    fn pick_series_content_parameters(
+        params: &SeriesParameters<T::VideoId, ContentParameters<T>>,
+    ) -> Vec<ContentParameters<T>> {
+        let mut content_parameters = params
+            .assets
+            .as_ref()
+            .map(|assets| Self::pick_content_parameters_from_assets(assets))
+            .unwrap_or_default();
+
+        if let Some(season_params) = &params.seasons {
+            for assets in season_params
+                .iter()
+                .flatten()
+                .filter_map(|season| season.assets.as_ref())
+            {
+                content_parameters.extend(Self::pick_content_parameters_from_assets(assets));
+            }
+        }
+
+        content_parameters
+    }
+
+    // Make episodes of the series out of existing videos and create new episode videos
+    fn apply_series_seasons(
+        actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+        channel_id: &T::ChannelId,
+        series_id: &T::SeriesId,
+        seasons: Vec<Vec<EpisodeParameters<T::VideoId, ContentParameters<T>>>>,
+    ) -> Vec<Season<T::VideoId>> {
+        seasons
+            .into_iter()
+            .map(|episodes| Season {
+                episodes: episodes
+                    .into_iter()
+                    .map(|episode| match episode {
+                        EpisodeParameters::ExistingVideo(video_id) => {
+                            VideoById::<T>::mutate(video_id, |video| {
+                                video.in_series = Some(*series_id)
+                            });
+                            video_id
+                        }
+                        EpisodeParameters::NewVideo(params) => {
+                            Self::create_episode_video(actor, channel_id, series_id, params)
+                        }
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn create_episode_video(
+        actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+        channel_id: &T::ChannelId,
+        series_id: &T::SeriesId,
+        params: VideoCreationParameters<ContentParameters<T>>,
+    ) -> T::VideoId {
+        let video_id = NextVideoId::<T>::get();
+        NextVideoId::<T>::mutate(|id| *id += T::VideoId::one());
+
+        let video: Video<
+            T::ChannelId,
+            T::SeriesId,
+            ContentId<T>,
+            T::VideoCategoryId,
+            T::BlockNumber,
+        > = Video {
+            in_channel: *channel_id,
+            in_series: Some(*series_id),
+            is_censored: false,
+            assets: Self::content_ids(&Self::pick_content_parameters_from_assets(&params.assets)),
+            category: None,
+            visibility: params.visibility,
+            publish_at: None,
+        };
+
+        VideoById::<T>::insert(video_id, video);
+
+        // Add recently added video id to the channel
+        ChannelById::<T>::mutate(channel_id, |channel| {
+            channel.videos.push(video_id);
+        });
+
+        Self::deposit_event(RawEvent::VideoCreated(
+            *actor,
+            *channel_id,
+            video_id,
+            params,
+        ));
+
+        video_id
+    }
+
+    fn ensure_person_exists(
+        person_id: &T::PersonId,
+    ) -> Result<Person<T::MemberId, ContentId<T>>, Error<T>> {
+        ensure!(
+            PersonById::<T>::contains_key(person_id),
+            Error::<T>::PersonDoesNotExist
+        );
+        Ok(PersonById::<T>::get(person_id))
+    }
+
+    // Remove all person credits of the video
+    fn remove_persons_from_video(video_id: &T::VideoId) {
+        for (person_id, _) in PersonInVideo::<T>::iter_prefix(video_id) {
+            VideoOfPerson::<T>::remove(person_id, video_id);
+        }
+        PersonInVideo::<T>::remove_prefix(video_id);
+    }
+
+    fn ensure_channel_category_exists(
+        channel_category_id: &T::ChannelCategoryId,
+    ) -> Result<ChannelCategory<T>, Error<T>> {
+        ensure!(
+            ChannelCategoryById::<T>::contains_key(channel_category_id),
+            Error::<T>::CategoryDoesNotExist
+        );
+        Ok(ChannelCategoryById::<T>::get(channel_category_id))
+    }
+
+    fn ensure_video_category_exists(
+        video_category_id: &T::VideoCategoryId,
+    ) -> Result<VideoCategory<T>, Error<T>> {
+        ensure!(
+            VideoCategoryById::<T>::contains_key(video_category_id),
+            Error::<T>::CategoryDoesNotExist
+        );
+        Ok(VideoCategoryById::<T>::get(video_category_id))
+    }
+
+    fn ensure_channel_transfer_request_exists(
+        request_id: &T::ChannelOwnershipTransferRequestId,
+    ) -> Result<ChannelOwnershipTransferRequest<T>, Error<T>> {
+        ensure!(
+            ChannelOwnershipTransferRequestById::<T>::contains_key(request_id),
+            Error::<T>::ChannelOwnershipTransferRequestDoesNotExist
+        );
+        Ok(ChannelOwnershipTransferRequestById::<T>::get(request_id))
+    }
+
+    // Ensure actor is authorized to act as the requested new channel owner
+    fn ensure_actor_is_requested_channel_owner(
+        origin: T::Origin,
+        actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+        new_owner: &ChannelOwner<T::MemberId, T::CuratorGroupId, T::DAOId>,
+    ) -> DispatchResult {
+        ensure_actor_authorized_to_create_channel::<T>(origin, actor)?;
+
+        ensure!(
+            Self::actor_to_channel_owner(actor)? == *new_owner,
+            Error::<T>::ActorNotAuthorized
+        );
+        Ok(())
+    }
+
+    /// The account holding payments of pending channel ownership transfer requests
+    pub fn channel_ownership_payment_escrow_account() -> T::AccountId {
+        ModuleId(T::ChannelOwnershipPaymentEscrowId::get()).into_account()
+    }
+
+    /// Initialize the mint funding channel rewards
+    pub fn initialize_content_mint(content_mint_capacity: minting::BalanceOf<T>) {
+        if let Ok(mint_id) = <minting::Module<T>>::add_mint(content_mint_capacity, None) {
+            ContentMint::<T>::put(mint_id);
+        } else {
+            panic!("Failed to create a mint for the content rewards");
+        }
+    }
+
+    /// Drop references of channels and videos imported at genesis to playlists and series,
+    /// which are not imported, recount the imported categories and publish scheduled videos
+    pub fn initialize_imported_content(
+        channel_categories: &[(T::ChannelCategoryId, ChannelCategory<T>)],
+        channels: &[(T::ChannelId, Channel<T>)],
+        video_categories: &[(T::VideoCategoryId, VideoCategory<T>)],
+        videos: &[(
+            T::VideoId,
+            Video<T::ChannelId, T::SeriesId, ContentId<T>, T::VideoCategoryId, T::BlockNumber>,
+        )],
+    ) {
+        // Counters are recomputed from the imported records rather than trusted
+        for (category_id, _) in channel_categories {
+            ChannelCategoryById::<T>::mutate(category_id, |category| {
+                category.subcategories_count = 0;
+                category.items_count = 0;
+            });
+        }
+
+        for (category_id, category) in channel_categories {
+            if let Some(parent_category_id) = category.parent_category_id {
+                if ChannelCategoryById::<T>::contains_key(parent_category_id) {
+                    ChannelCategoryById::<T>::mutate(parent_category_id, |parent| {
+                        parent.subcategories_count = parent.subcategories_count.saturating_add(1)
+                    });
+                } else {
+                    ChannelCategoryById::<T>::mutate(category_id, |category| {
+                        category.parent_category_id = None;
+                        category.depth = 0;
+                    });
+                }
+            }
+        }
+
+        for (category_id, _) in video_categories {
+            VideoCategoryById::<T>::mutate(category_id, |category| {
+                category.subcategories_count = 0;
+                category.items_count = 0;
+            });
+        }
+
+        for (category_id, category) in video_categories {
+            if let Some(parent_category_id) = category.parent_category_id {
+                if VideoCategoryById::<T>::contains_key(parent_category_id) {
+                    VideoCategoryById::<T>::mutate(parent_category_id, |parent| {
+                        parent.subcategories_count = parent.subcategories_count.saturating_add(1)
+                    });
+                } else {
+                    VideoCategoryById::<T>::mutate(category_id, |category| {
+                        category.parent_category_id = None;
+                        category.depth = 0;
+                    });
+                }
+            }
+        }
+
+        for (channel_id, _) in channels {
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                channel.playlists.clear();
+                channel.series.clear();
+
+                // Drop assignments to categories which were not imported
+                if let Some(category_id) = channel.category {
+                    if !ChannelCategoryById::<T>::contains_key(category_id) {
+                        channel.category = None;
+                    }
+                }
+
+                Self::move_channel_between_categories(&None, &channel.category);
+            });
+        }
+
+        // Publishing blocks of the previous chain are meaningless here, so scheduled
+        // videos are published right away
+        for (video_id, _) in videos {
+            VideoById::<T>::mutate(video_id, |video| {
+                video.in_series = None;
+
+                if video.publish_at.take().is_some() {
+                    video.visibility = VideoVisibility::Public;
+                }
+
+                // Drop assignments to categories which were not imported
+                if let Some(category_id) = video.category {
+                    if !VideoCategoryById::<T>::contains_key(category_id) {
+                        video.category = None;
+                    }
+                }
+
+                Self::move_video_between_categories(&None, &video.category);
+            });
+        }
+    }
+
+    /// The account holding payments and bids of video NFT sales
+    pub fn nft_payment_escrow_account() -> T::AccountId {
+        ModuleId(T::NftPaymentEscrowId::get()).into_account()
+    }
+
+    fn ensure_nft_exists(video_id: &T::VideoId) -> Result<OwnedNft<T>, Error<T>> {
+        ensure!(
+            NftByVideo::<T>::contains_key(video_id),
+            Error::<T>::NftDoesNotExist
+        );
+        Ok(NftByVideo::<T>::get(video_id))
+    }
+
+    // Video NFT can only be burned with the video while held by the channel owner and not on sale,
+    // members holding the NFT keep the video from being deleted until they transfer it back
+    fn ensure_video_nft_can_be_burned(video_id: &T::VideoId) -> DispatchResult {
+        if NftByVideo::<T>::contains_key(video_id) {
+            let nft = NftByVideo::<T>::get(video_id);
+
+            ensure!(
+                nft.owner == NftOwner::ChannelOwner,
+                Error::<T>::VideoNftIsNotRemovable
+            );
+            nft.ensure_is_idle::<T>()?;
+        }
+        Ok(())
+    }
+
+    // Account receiving the payment of the video NFT sale started by `sender`,
+    // the channel reward account is used when the NFT is held by the channel owner
+    fn nft_seller_account(
+        channel_id: &T::ChannelId,
+        nft: &OwnedNft<T>,
+        sender: T::AccountId,
+    ) -> T::AccountId {
+        match nft.owner {
+            NftOwner::ChannelOwner => Self::channel_by_id(channel_id)
+                .reward_account
+                .unwrap_or(sender),
+            NftOwner::Member(_) => sender,
+        }
+    }
+
+    // Release the escrowed payment of the video NFT sale, paying the creator royalty
+    // of secondary sales to the channel reward account
+    fn release_nft_payment(
+        channel_id: &T::ChannelId,
+        nft: &OwnedNft<T>,
+        seller_account: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) {
+        let escrow_account = Self::nft_payment_escrow_account();
+
+        let mut seller_payment = amount;
+
+        if let (NftOwner::Member(_), Some(creator_royalty), Some(reward_account)) = (
+            nft.owner,
+            nft.creator_royalty,
+            Self::channel_by_id(channel_id).reward_account,
+        ) {
+            let royalty_payment = creator_royalty * amount;
+
+            if T::Currency::transfer(
+                &escrow_account,
+                &reward_account,
+                royalty_payment,
+                ExistenceRequirement::AllowDeath,
+            )
+            .is_ok()
+            {
+                seller_payment -= royalty_payment;
+            }
+        }
+
+        Self::pay_or_defer(&escrow_account, seller_account, seller_payment);
+    }
+
+    // Pay out of the module account, keeping the payment claimable by the payee
+    // through `claim_payment` if it cannot be transferred now
+    fn pay_or_defer(payer: &T::AccountId, payee: &T::AccountId, amount: BalanceOf<T>) {
+        if T::Currency::transfer(payer, payee, amount, ExistenceRequirement::AllowDeath).is_err() {
+            UnclaimedPayments::<T>::mutate(payer, payee, |unclaimed| {
+                *unclaimed = unclaimed.saturating_add(amount)
+            });
+
+            Self::deposit_event(RawEvent::PaymentDeferred(
+                payer.clone(),
+                payee.clone(),
+                amount,
+            ));
+        }
+    }
+
+    // Transfer the video NFT to the top bidder, if any, and release the winning bid
+    fn settle_english_auction(now: T::BlockNumber, video_id: T::VideoId) {
+        AuctionsByEndBlock::<T>::remove(now, video_id);
+
+        let mut nft = Self::nft_by_video(video_id);
+
+        let winner = if let NftTransactionalStatus::Auction(auction) = &nft.transactional_status {
+            auction.top_bid.as_ref().map(|top_bid| {
+                Self::release_nft_payment(
+                    &Self::video_by_id(video_id).in_channel,
+                    &nft,
+                    &auction.seller_account,
+                    top_bid.amount,
+                );
+                top_bid.bidder
+            })
+        } else {
+            None
+        };
+
+        if let Some(winner) = winner {
+            nft.owner = NftOwner::Member(winner);
+        }
+        nft.transactional_status = NftTransactionalStatus::Idle;
+
+        NftByVideo::<T>::insert(video_id, nft);
+
+        Self::deposit_event(RawEvent::EnglishAuctionSettled(video_id, winner));
+    }
+
+    fn pick_content_parameters_from_assets(
+        assets: &[NewAsset<ContentParameters<T>>],
+    ) -> Vec<ContentParameters<T>> {
+        assets
+            .iter()
+            .filter_map(|asset| match asset {
+                NewAsset::Upload(content_parameters) => Some(content_parameters.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn content_ids(content_parameters: &[ContentParameters<T>]) -> Vec<ContentId<T>> {
+        content_parameters
+            .iter()
+            .map(|content_parameters| content_parameters.content_id)
+            .collect()
+    }
+
+    // Ensure assets to be removed are tracked as uploaded assets of a channel or video
+    fn ensure_assets_are_tracked(
+        tracked_assets: &[ContentId<T>],
+        assets: &[ContentId<T>],
+        error: Error<T>,
+    ) -> DispatchResult {
+        ensure!(
+            assets
+                .iter()
+                .all(|content_id| tracked_assets.contains(content_id)),
+            error
+        );
+        Ok(())
+    }
+
+    fn actor_to_channel_owner(
+        actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+    ) -> ActorToChannelOwnerResult<T> {
+        match actor {
+            // Lead should use their member or curator role to create channels
+            ContentActor::Lead => Err(Error::<T>::ActorCannotOwnChannel),
+            ContentActor::Curator(curator_group_id, _curator_id) => {
+                Ok(ChannelOwner::CuratorGroup(*curator_group_id))
+            }
+            ContentActor::Member(member_id) => Ok(ChannelOwner::Member(*member_id)),
+            ContentActor::Dao(dao_id) => Ok(ChannelOwner::Dao(*dao_id)),
+        }
+    }
+
+    fn actor_to_person_controller(
+        actor: &PersonActor<T::MemberId, T::CuratorId>,
+    ) -> PersonController<T::MemberId> {
+        match actor {
+            PersonActor::Member(member_id) => PersonController::Member(*member_id),
+            PersonActor::Curator(_curator_id) => PersonController::Curators,
+        }
+    }
+
+    // Assets of curator controlled persons are owned by the content working group
+    fn person_controller_to_storage_object_owner(
+        controller: &PersonController<T::MemberId>,
+    ) -> StorageObjectOwner<T> {
+        match controller {
+            PersonController::Member(member_id) => StorageObjectOwner::<T>::Member(*member_id),
+            PersonController::Curators => {
+                StorageObjectOwner::<T>::WorkingGroup(WorkingGroup::Content)
+            }
+        }
+    }
+
+    fn ensure_comment_exists(
+        video_id: &T::VideoId,
+        comment_id: &T::CommentId,
+    ) -> Result<Comment<T::MemberId, T::CommentId>, Error<T>> {
+        ensure!(
+            CommentById::<T>::contains_key(video_id, comment_id),
+            Error::<T>::CommentDoesNotExist
+        );
+        Ok(CommentById::<T>::get(video_id, comment_id))
+    }
+
+    fn ensure_comment_text_is_valid(text: &[u8]) -> Result<(), Error<T>> {
+        let constraint = Self::comment_text_constraint();
+
+        ensure!(
+            text.len() >= constraint.min as usize,
+            Error::<T>::CommentTextTooShort
+        );
+        ensure!(
+            text.len() <= constraint.max() as usize,
+            Error::<T>::CommentTextTooLong
+        );
+        Ok(())
+    }
+
+    fn ensure_comment_moderation_rationale_is_valid(rationale: &[u8]) -> Result<(), Error<T>> {
+        let constraint = Self::comment_moderation_rationale_constraint();
+
+        ensure!(
+            rationale.len() >= constraint.min as usize,
+            Error::<T>::CommentModerationRationaleTooShort
+        );
+        ensure!(
+            rationale.len() <= constraint.max() as usize,
+            Error::<T>::CommentModerationRationaleTooLong
+        );
+        Ok(())
+    }
+
+    // Only existing public videos which are not censored, in channels which are not censored, can be featured
+    fn ensure_video_can_be_featured(video_id: &T::VideoId) -> DispatchResult {
+        let video = Self::ensure_video_exists(video_id)?;
+
+        ensure!(
+            !video.is_censored && !Self::channel_by_id(video.in_channel).is_censored,
+            Error::<T>::CensoredVideoCannotBeFeatured
+        );
+        ensure!(
+            video.visibility == VideoVisibility::Public,
+            Error::<T>::NonPublicVideoCannotBeFeatured
+        );
+        Ok(())
+    }
+
+    // Insert a new video into the channel, its assets must already be added to storage
+    fn insert_new_video(
+        actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+        channel_id: T::ChannelId,
+        params: VideoCreationParameters<ContentParameters<T>>,
+    ) {
+        let video_id = NextVideoId::<T>::get();
+
+        let assets = Self::content_ids(&Self::pick_content_parameters_from_assets(&params.assets));
+
+        let video: Video<
+            T::ChannelId,
+            T::SeriesId,
+            ContentId<T>,
+            T::VideoCategoryId,
+            T::BlockNumber,
+        > = Video {
+            in_channel: channel_id,
+            // keep track of which season the video is in if it is an 'episode'
+            // - prevent removing a video if it is in a season (because order is important)
+            in_series: None,
+            /// Whether the curators have censored the video or not.
+            is_censored: false,
+            assets,
+            category: None,
+            visibility: params.visibility,
+            publish_at: None,
+        };
+
+        VideoById::<T>::insert(video_id, video);
+
+        // Only increment next video id if adding content was successful
+        NextVideoId::<T>::mutate(|id| *id += T::VideoId::one());
+
+        // Add recently added video id to the channel
+        ChannelById::<T>::mutate(channel_id, |channel| {
+            channel.videos.push(video_id);
+        });
+
+        Self::deposit_event(RawEvent::VideoCreated(actor, channel_id, video_id, params));
+    }
+
+    // Remove the video and everything attached to it, except its assets and the channel video list
+    fn remove_video(
+        video_id: &T::VideoId,
+        video: &Video<T::ChannelId, T::SeriesId, ContentId<T>, T::VideoCategoryId, T::BlockNumber>,
+    ) {
+        VideoById::<T>::remove(video_id);
+
+        // Unassign video from its category
+        Self::move_video_between_categories(&video.category, &None);
+
+        // Cancel scheduled publishing of the video
+        Self::unschedule_video_publishing(video, video_id);
+
+        // Burn the video NFT held by the channel owner
+        NftByVideo::<T>::remove(video_id);
+
+        // Refund pending censorship appeal of the video
+        Self::close_censorship_appeal(&CensoredContent::Video(*video_id));
+
+        // Refund pending reports of the video
+        Self::settle_content_reports(
+            &CensoredContent::Video(*video_id),
+            ContentReportStatus::Closed,
+        );
+
+        // Remove video from the playlists it is included in
+        Self::remove_video_from_playlists(video_id);
+
+        // Remove all person credits of the video
+        Self::remove_persons_from_video(video_id);
+
+        // Remove likes and comments of the video
+        Self::remove_video_interactions(video_id);
+
+        // Remove video from the featured video lists it is included in
+        Self::remove_video_from_featured_lists(video_id);
+    }
+
+    // Ensure the batch is not empty and does not exceed the maximum batch size
+    fn ensure_video_batch_size_is_valid(size: usize) -> DispatchResult {
+        ensure!(
+            size > 0 && size <= T::MaxNumberOfVideosPerBatch::get() as usize,
+            Error::<T>::InvalidVideoBatchSize
+        );
+        Ok(())
+    }
 
-            Self::deposit_event(RawEvent::VideoCategoryCreated(actor, category_id, params));
+    // Ensure all videos exist, belong to the channel and none of them is repeated
+    fn ensure_videos_are_in_channel(
+        channel_id: &T::ChannelId,
+        video_ids: &[T::VideoId],
+    ) -> DispatchResult {
+        let mut seen = BTreeSet::new();
+        for video_id in video_ids {
+            ensure!(seen.insert(*video_id), Error::<T>::DuplicateVideoInBatch);
+            ensure!(
+                Self::ensure_video_exists(video_id)?.in_channel == *channel_id,
+                Error::<T>::VideoNotInChannel
+            );
         }
+        Ok(())
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_video_category(
-            origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            category_id: T::VideoCategoryId,
-            params: VideoCategoryUpdateParameters,
-        ) {
-            ensure_actor_authorized_to_manage_categories::<T>(
-                origin,
-                &actor
-            )?;
+    // Remove the video from all featured video lists
+    fn remove_video_from_featured_lists(video_id: &T::VideoId) {
+        for (category_id, _) in FeaturedVideoLists::<T>::iter_prefix(video_id) {
+            FeaturedVideos::<T>::mutate(category_id, |list| list.retain(|id| id != video_id));
+        }
+        FeaturedVideoLists::<T>::remove_prefix(video_id);
+    }
 
-            Self::ensure_video_category_exists(&category_id)?;
+    // Remove likes, comments and comment settings of the video
+    fn remove_video_interactions(video_id: &T::VideoId) {
+        VideoLikedBy::<T>::remove_prefix(video_id);
+        LikesCountByVideo::<T>::remove(video_id);
+        CommentById::<T>::remove_prefix(video_id);
+        VideoCommentsDisabled::<T>::remove(video_id);
+    }
 
-            Self::deposit_event(RawEvent::VideoCategoryUpdated(actor, category_id, params));
+    // Remove the video from the publishing schedule
+    fn unschedule_video_publishing(
+        video: &Video<T::ChannelId, T::SeriesId, ContentId<T>, T::VideoCategoryId, T::BlockNumber>,
+        video_id: &T::VideoId,
+    ) {
+        if let Some(publish_at) = video.publish_at {
+            VideosByPublishBlock::<T>::remove(publish_at, video_id);
         }
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn delete_video_category(
-            origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            category_id: T::VideoCategoryId,
-        ) {
-            ensure_actor_authorized_to_manage_categories::<T>(
-                origin,
-                &actor
-            )?;
+    // Make a scheduled video public
+    fn publish_scheduled_video(now: T::BlockNumber, video_id: T::VideoId) {
+        VideosByPublishBlock::<T>::remove(now, video_id);
 
-            Self::ensure_video_category_exists(&category_id)?;
+        VideoById::<T>::mutate(video_id, |video| {
+            video.visibility = VideoVisibility::Public;
+            video.publish_at = None;
+        });
 
-            VideoCategoryById::<T>::remove(&category_id);
+        Self::deposit_event(RawEvent::VideoPublished(video_id));
+    }
 
-            Self::deposit_event(RawEvent::VideoCategoryDeleted(actor, category_id));
+    // Update item counts of the categories a channel is moved between
+    fn move_channel_between_categories(
+        from: &Option<T::ChannelCategoryId>,
+        to: &Option<T::ChannelCategoryId>,
+    ) {
+        if let Some(category_id) = from {
+            ChannelCategoryById::<T>::mutate(category_id, |category| {
+                category.items_count = category.items_count.saturating_sub(1)
+            });
+        }
+        if let Some(category_id) = to {
+            ChannelCategoryById::<T>::mutate(category_id, |category| category.items_count += 1);
         }
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn create_person(
-            _origin,
-            _actor: PersonActor<T::MemberId, T::CuratorId>,
-            _params: PersonCreationParameters<ContentParameters<T>>,
-        ) {
-            Self::not_implemented()?;
+    // Update item counts of the categories a video is moved between
+    fn move_video_between_categories(
+        from: &Option<T::VideoCategoryId>,
+        to: &Option<T::VideoCategoryId>,
+    ) {
+        if let Some(category_id) = from {
+            VideoCategoryById::<T>::mutate(category_id, |category| {
+                category.items_count = category.items_count.saturating_sub(1)
+            });
+        }
+        if let Some(category_id) = to {
+            VideoCategoryById::<T>::mutate(category_id, |category| category.items_count += 1);
         }
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_person(
-            _origin,
-            _actor: PersonActor<T::MemberId, T::CuratorId>,
-            _person: T::PersonId,
-            _params: PersonUpdateParameters<ContentParameters<T>>,
-        ) {
-            Self::not_implemented()?;
+    // Ensure the channel or video exists, returning the channel it belongs to and its censorship status
+    fn ensure_content_exists(
+        content: &CensoredContent<T::ChannelId, T::VideoId>,
+    ) -> Result<(T::ChannelId, bool), Error<T>> {
+        match content {
+            CensoredContent::Channel(channel_id) => {
+                let channel = Self::ensure_channel_exists(channel_id)?;
+                Ok((*channel_id, channel.is_censored))
+            }
+            CensoredContent::Video(video_id) => {
+                let video = Self::ensure_video_exists(video_id)?;
+                Ok((video.in_channel, video.is_censored))
+            }
         }
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn delete_person(
-            _origin,
-            _actor: PersonActor<T::MemberId, T::CuratorId>,
-            _person: T::PersonId,
-        ) {
-            Self::not_implemented()?;
+    // Ensure channel or video exists and is censored, returns the channel it belongs to
+    fn ensure_content_is_censored(
+        censored: &CensoredContent<T::ChannelId, T::VideoId>,
+    ) -> Result<T::ChannelId, Error<T>> {
+        let (channel_id, is_censored) = Self::ensure_content_exists(censored)?;
+
+        ensure!(is_censored, Error::<T>::ContentIsNotCensored);
+
+        Ok(channel_id)
+    }
+
+    fn ensure_censorship_appeal_is_pending(
+        appeal_id: &T::CensorshipAppealId,
+    ) -> Result<CensorshipAppeal<T>, Error<T>> {
+        ensure!(
+            CensorshipAppealById::<T>::contains_key(appeal_id),
+            Error::<T>::CensorshipAppealDoesNotExist
+        );
+
+        let appeal = CensorshipAppealById::<T>::get(appeal_id);
+
+        ensure!(
+            appeal.status == CensorshipAppealStatus::Pending,
+            Error::<T>::CensorshipAppealIsNotPending
+        );
+
+        Ok(appeal)
+    }
+
+    // Refund pending appeal filed against the current censorship of the content, if any
+    fn close_censorship_appeal(censored: &CensoredContent<T::ChannelId, T::VideoId>) {
+        if let Some(appeal_id) = CensorshipAppealByContent::<T>::take(censored) {
+            if Self::censorship_appeal_by_id(appeal_id).status == CensorshipAppealStatus::Pending {
+                Self::settle_censorship_appeal(appeal_id, CensorshipAppealStatus::Closed, vec![]);
+            }
         }
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn add_person_to_video(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _video_id: T::VideoId,
-            _person: T::PersonId
-        ) {
-            Self::not_implemented()?;
+    // Move pending appeal into its final status, lifting censorship and releasing
+    // the stake to the appellant unless the appeal was rejected
+    fn settle_censorship_appeal(
+        appeal_id: T::CensorshipAppealId,
+        status: CensorshipAppealStatus,
+        rationale: Vec<u8>,
+    ) {
+        let mut appeal = Self::censorship_appeal_by_id(appeal_id);
+
+        match status {
+            CensorshipAppealStatus::Rejected => {
+                // Censorship is upheld, burn the stake
+                let _ = T::Currency::slash_reserved(&appeal.appellant, appeal.stake);
+            }
+            _ => {
+                T::Currency::unreserve(&appeal.appellant, appeal.stake);
+                CensorshipAppealByContent::<T>::remove(&appeal.censored);
+            }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn remove_person_from_video(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _video_id: T::VideoId
-        ) {
-            Self::not_implemented()?;
+        if status == CensorshipAppealStatus::Accepted || status == CensorshipAppealStatus::Expired {
+            Self::lift_censorship(&appeal.censored);
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_video_censorship_status(
-            origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            video_id: T::VideoId,
-            is_censored: bool,
-            rationale: Vec<u8>,
-        ) {
-            // check that video exists
-            let video = Self::ensure_video_exists(&video_id)?;
+        CensorshipAppealsByDeadline::<T>::remove(appeal.deadline, appeal_id);
 
-            if video.is_censored == is_censored {
-                return Ok(())
-            }
+        appeal.status = status.clone();
+        CensorshipAppealById::<T>::insert(appeal_id, appeal);
 
-            ensure_actor_authorized_to_censor::<T>(
-                origin,
-                &actor,
-                // The channel owner will be..
-                &Self::channel_by_id(video.in_channel).owner,
-            )?;
+        Self::deposit_event(RawEvent::CensorshipAppealResolved(
+            appeal_id, status, rationale,
+        ));
+    }
 
-            //
-            // == MUTATION SAFE ==
-            //
+    // Update censorship status of the channel, settling the appeals and reports it affects
+    fn set_channel_censorship_status(
+        channel_id: T::ChannelId,
+        mut channel: Channel<T>,
+        is_censored: bool,
+    ) {
+        channel.is_censored = is_censored;
 
-            let mut video = video;
+        // TODO: unset the reward account ? so no revenue can be earned for censored channels?
 
-            video.is_censored = is_censored;
+        // Censored channel videos are no longer featured
+        if is_censored {
+            for video_id in channel.videos.iter() {
+                Self::remove_video_from_featured_lists(video_id);
+            }
+        }
 
-            // Update the video
-            VideoById::<T>::insert(video_id, video);
+        // Update the channel
+        ChannelById::<T>::insert(channel_id, channel);
 
-            Self::deposit_event(RawEvent::VideoCensorshipStatusUpdated(actor, video_id, is_censored, rationale));
+        // Appeals only apply to the censorship they were filed against
+        Self::close_censorship_appeal(&CensoredContent::Channel(channel_id));
+
+        // Censorship upholds the pending reports of the channel
+        if is_censored {
+            Self::settle_content_reports(
+                &CensoredContent::Channel(channel_id),
+                ContentReportStatus::Upheld,
+            );
         }
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn create_series(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _channel_id: T::ChannelId,
-            _params: SeriesParameters<T::VideoId, ContentParameters<T>>,
-        ) {
-            Self::not_implemented()?;
+    // Update censorship status of the video, settling the appeals and reports it affects
+    fn set_video_censorship_status(
+        video_id: T::VideoId,
+        mut video: Video<
+            T::ChannelId,
+            T::SeriesId,
+            ContentId<T>,
+            T::VideoCategoryId,
+            T::BlockNumber,
+        >,
+        is_censored: bool,
+    ) {
+        video.is_censored = is_censored;
+
+        // Update the video
+        VideoById::<T>::insert(video_id, video);
+
+        // Censored videos are no longer featured
+        if is_censored {
+            Self::remove_video_from_featured_lists(&video_id);
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn update_series(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _channel_id: T::ChannelId,
-            _params: SeriesParameters<T::VideoId, ContentParameters<T>>,
-        ) {
-            Self::not_implemented()?;
+        // Appeals only apply to the censorship they were filed against
+        Self::close_censorship_appeal(&CensoredContent::Video(video_id));
+
+        // Censorship upholds the pending reports of the video
+        if is_censored {
+            Self::settle_content_reports(
+                &CensoredContent::Video(video_id),
+                ContentReportStatus::Upheld,
+            );
         }
+    }
 
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn delete_series(
-            _origin,
-            _actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            _series: T::SeriesId,
-        ) {
-            Self::not_implemented()?;
+    /// Whether the member has a paid subscription to the channel which has not expired yet.
+    /// Auto renewed subscriptions whose renewal is postponed by the per block renewals limit
+    /// stay active until it is processed, the renewed period is paid from the renewal block.
+    pub fn has_active_subscription(member_id: &T::MemberId, channel_id: &T::ChannelId) -> bool {
+        if !ChannelSubscriptions::<T>::contains_key(channel_id, member_id) {
+            return false;
         }
+
+        let subscription = Self::channel_subscription(channel_id, member_id);
+
+        subscription.paid_until > <frame_system::Module<T>>::block_number()
+            || (subscription.auto_renew
+                && SubscriptionsByRenewalBlock::<T>::contains_key(
+                    subscription.paid_until,
+                    (*channel_id, *member_id),
+                ))
     }
-}
 
-impl<T: Trait> Module<T> {
-    /// Ensure `CuratorGroup` under given id exists
-    fn ensure_curator_group_under_given_id_exists(
-        curator_group_id: &T::CuratorGroupId,
-    ) -> Result<(), Error<T>> {
+    fn ensure_subscription_tier_exists(
+        channel_id: &T::ChannelId,
+        tier_id: &T::SubscriptionTierId,
+    ) -> Result<SubscriptionTier<T>, Error<T>> {
         ensure!(
-            <CuratorGroupById<T>>::contains_key(curator_group_id),
-            Error::<T>::CuratorGroupDoesNotExist
+            SubscriptionTierById::<T>::contains_key(channel_id, tier_id),
+            Error::<T>::SubscriptionTierDoesNotExist
         );
-        Ok(())
+        Ok(SubscriptionTierById::<T>::get(channel_id, tier_id))
     }
 
-    /// Ensure `CuratorGroup` under given id exists, return corresponding one
-    fn ensure_curator_group_exists(
-        curator_group_id: &T::CuratorGroupId,
-    ) -> Result<CuratorGroup<T>, Error<T>> {
-        Self::ensure_curator_group_under_given_id_exists(curator_group_id)?;
-        Ok(Self::curator_group_by_id(curator_group_id))
+    // Pay subscription period to the channel reward account
+    fn pay_channel_subscription(
+        payer: &T::AccountId,
+        channel_id: &T::ChannelId,
+        price: BalanceOf<T>,
+    ) -> DispatchResult {
+        let reward_account = Self::channel_by_id(channel_id)
+            .reward_account
+            .ok_or(Error::<T>::ChannelRewardAccountNotSet)?;
+
+        T::Currency::transfer(
+            payer,
+            &reward_account,
+            price,
+            ExistenceRequirement::AllowDeath,
+        )
     }
 
-    fn ensure_channel_exists(channel_id: &T::ChannelId) -> Result<Channel<T>, Error<T>> {
-        ensure!(
-            ChannelById::<T>::contains_key(channel_id),
-            Error::<T>::ChannelDoesNotExist
-        );
-        Ok(ChannelById::<T>::get(channel_id))
+    // Renew or expire the subscriptions scheduled up to the current block, in the order of their
+    // renewal blocks. Subscriptions beyond the per block limit are left for the following blocks.
+    fn process_subscription_renewals(now: T::BlockNumber) {
+        let mut renewals_left = T::MaxSubscriptionRenewalsPerBlock::get() as usize;
+
+        let mut renewal_block = Self::next_subscription_renewal_block().unwrap_or(now);
+
+        while renewal_block <= now {
+            let ending_subscriptions: Vec<(T::ChannelId, T::MemberId)> =
+                SubscriptionsByRenewalBlock::<T>::iter_prefix(renewal_block)
+                    .map(|(key, _)| key)
+                    .take(renewals_left)
+                    .collect();
+
+            renewals_left -= ending_subscriptions.len();
+
+            for (channel_id, member_id) in ending_subscriptions {
+                Self::renew_channel_subscription(renewal_block, channel_id, member_id);
+            }
+
+            if renewals_left == 0 {
+                break;
+            }
+
+            renewal_block += One::one();
+        }
+
+        NextSubscriptionRenewalBlock::<T>::put(renewal_block);
     }
 
-    fn ensure_video_exists(
-        video_id: &T::VideoId,
-    ) -> Result<Video<T::ChannelId, T::SeriesId>, Error<T>> {
-        ensure!(
-            VideoById::<T>::contains_key(video_id),
-            Error::<T>::VideoDoesNotExist
-        );
-        Ok(VideoById::<T>::get(video_id))
+    // Charge the next period of the subscription, or let it expire if it is canceled,
+    // its tier was removed or the payment fails
+    // The next period is paid from the renewal block even when the renewal was postponed,
+    // so a postponed renewal never extends the subscription for free
+    fn renew_channel_subscription(
+        renewal_block: T::BlockNumber,
+        channel_id: T::ChannelId,
+        member_id: T::MemberId,
+    ) {
+        SubscriptionsByRenewalBlock::<T>::remove(renewal_block, (channel_id, member_id));
+
+        let mut subscription = Self::channel_subscription(channel_id, member_id);
+
+        let tier = Self::ensure_subscription_tier_exists(&channel_id, &subscription.tier_id);
+
+        let renewed = match tier {
+            Ok(tier) if subscription.auto_renew => {
+                Self::pay_channel_subscription(&subscription.payer, &channel_id, tier.price)
+                    .map(|_| renewal_block + tier.period)
+                    .ok()
+            }
+            _ => None,
+        };
+
+        if let Some(paid_until) = renewed {
+            subscription.paid_until = paid_until;
+
+            ChannelSubscriptions::<T>::insert(channel_id, member_id, subscription);
+            SubscriptionsByRenewalBlock::<T>::insert(paid_until, (channel_id, member_id), ());
+
+            Self::deposit_event(RawEvent::ChannelSubscriptionRenewed(
+                member_id, channel_id, paid_until,
+            ));
+        } else {
+            ChannelSubscriptions::<T>::remove(channel_id, member_id);
+
+            Self::deposit_event(RawEvent::ChannelSubscriptionExpired(member_id, channel_id));
+        }
     }
 
-    // Ensure given video is not in season
-    fn ensure_video_can_be_removed(video: Video<T::ChannelId, T::SeriesId>) -> DispatchResult {
-        ensure!(video.in_series.is_none(), Error::<T>::VideoInSeason);
-        Ok(())
+    pub fn content_report_pool_account() -> T::AccountId {
+        ModuleId(T::ContentReportPoolId::get()).into_account()
     }
 
-    fn ensure_channel_category_exists(
-        channel_category_id: &T::ChannelCategoryId,
-    ) -> Result<ChannelCategory, Error<T>> {
+    // Reserve the report stake and put the report into the pending queue of the content
+    fn file_content_report(
+        origin: T::Origin,
+        member_id: T::MemberId,
+        content: CensoredContent<T::ChannelId, T::VideoId>,
+        reason: ReportReason,
+    ) -> DispatchResult {
+        let sender = ensure_signed(origin)?;
+
+        ensure_member_auth_success::<T>(&member_id, &sender)?;
+
+        let (_, is_censored) = Self::ensure_content_exists(&content)?;
+
+        ensure!(!is_censored, Error::<T>::ContentIsAlreadyCensored);
+
+        // Each member can have only one pending report of the content
         ensure!(
-            ChannelCategoryById::<T>::contains_key(channel_category_id),
-            Error::<T>::CategoryDoesNotExist
+            !PendingContentReports::<T>::iter_prefix(&content)
+                .any(|(report_id, _)| Self::content_report_by_id(report_id).reporter == member_id),
+            Error::<T>::ContentAlreadyReported
         );
-        Ok(ChannelCategoryById::<T>::get(channel_category_id))
-    }
 
-    fn ensure_video_category_exists(
-        video_category_id: &T::VideoCategoryId,
-    ) -> Result<VideoCategory, Error<T>> {
+        let stake = T::ContentReportStake::get();
+
         ensure!(
-            VideoCategoryById::<T>::contains_key(video_category_id),
-            Error::<T>::CategoryDoesNotExist
+            T::Currency::can_reserve(&sender, stake),
+            Error::<T>::InsufficientBalanceForReportStake
         );
-        Ok(VideoCategoryById::<T>::get(video_category_id))
+
+        let report_id = Self::next_content_report_id();
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // This should be first mutation
+        T::Currency::reserve(&sender, stake)?;
+
+        NextContentReportId::<T>::mutate(|id| *id += T::ContentReportId::one());
+
+        let report = ContentReportRecord {
+            content: content.clone(),
+            reporter: member_id,
+            reporter_account: sender,
+            stake,
+            reason,
+            status: ContentReportStatus::Pending,
+        };
+
+        ContentReportById::<T>::insert(report_id, report.clone());
+        PendingContentReports::<T>::insert(&content, report_id, ());
+
+        Self::deposit_event(RawEvent::ContentReported(member_id, report_id, report));
+
+        Ok(())
     }
 
-    fn pick_content_parameters_from_assets(
-        assets: &[NewAsset<ContentParameters<T>>],
-    ) -> Vec<ContentParameters<T>> {
-        assets
-            .iter()
-            .filter_map(|asset| match asset {
-                NewAsset::Upload(content_parameters) => Some(content_parameters.clone()),
-                _ => None,
-            })
-            .collect()
+    // Move all pending reports of the content into their final status. Stakes of dismissed
+    // reports are slashed into the report stake pool, the others are refunded, and reporters
+    // of upheld reports are rewarded from the pool as far as its funds allow
+    fn settle_content_reports(
+        content: &CensoredContent<T::ChannelId, T::VideoId>,
+        status: ContentReportStatus,
+    ) {
+        let report_ids: Vec<T::ContentReportId> = PendingContentReports::<T>::iter_prefix(content)
+            .map(|(report_id, _)| report_id)
+            .collect();
+
+        PendingContentReports::<T>::remove_prefix(content);
+
+        let pool_account = Self::content_report_pool_account();
+
+        for report_id in report_ids {
+            let mut report = Self::content_report_by_id(report_id);
+
+            if status == ContentReportStatus::Dismissed {
+                let _ = T::Currency::repatriate_reserved(
+                    &report.reporter_account,
+                    &pool_account,
+                    report.stake,
+                    BalanceStatus::Free,
+                );
+            } else {
+                T::Currency::unreserve(&report.reporter_account, report.stake);
+            }
+
+            if status == ContentReportStatus::Upheld {
+                let reward =
+                    T::ContentReportReward::get().min(T::Currency::free_balance(&pool_account));
+
+                Self::pay_or_defer(&pool_account, &report.reporter_account, reward);
+            }
+
+            report.status = status;
+            ContentReportById::<T>::insert(report_id, report);
+
+            Self::deposit_event(RawEvent::ContentReportResolved(report_id, status));
+        }
     }
 
-    fn actor_to_channel_owner(
-        actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-    ) -> ActorToChannelOwnerResult<T> {
-        match actor {
-            // Lead should use their member or curator role to create channels
-            ContentActor::Lead => Err(Error::<T>::ActorCannotOwnChannel),
-            ContentActor::Curator(
-                curator_group_id,
-                _curator_id
-            ) => {
-                Ok(ChannelOwner::CuratorGroup(*curator_group_id))
+    fn lift_censorship(censored: &CensoredContent<T::ChannelId, T::VideoId>) {
+        match censored {
+            CensoredContent::Channel(channel_id) => {
+                if ChannelById::<T>::contains_key(channel_id) {
+                    ChannelById::<T>::mutate(channel_id, |channel| channel.is_censored = false)
+                }
             }
-            ContentActor::Member(member_id) => {
-                Ok(ChannelOwner::Member(*member_id))
+            CensoredContent::Video(video_id) => {
+                if VideoById::<T>::contains_key(video_id) {
+                    VideoById::<T>::mutate(video_id, |video| video.is_censored = false)
+                }
             }
-            // TODO:
-            // ContentActor::Dao(id) => Ok(ChannelOwner::Dao(id)),
         }
     }
 
@@ -1370,29 +5149,52 @@ decl_event!(
             <T as ContentActorAuthenticator>::CuratorGroupId,
             <T as ContentActorAuthenticator>::CuratorId,
             <T as MembershipTypes>::MemberId,
+            <T as StorageOwnership>::DAOId,
         >,
         CuratorGroupId = <T as ContentActorAuthenticator>::CuratorGroupId,
         CuratorId = <T as ContentActorAuthenticator>::CuratorId,
+        MemberId = <T as MembershipTypes>::MemberId,
+        PersonActor = PersonActor<
+            <T as MembershipTypes>::MemberId,
+            <T as ContentActorAuthenticator>::CuratorId,
+        >,
         VideoId = <T as Trait>::VideoId,
         VideoCategoryId = <T as Trait>::VideoCategoryId,
         ChannelId = <T as StorageOwnership>::ChannelId,
         NewAsset = NewAsset<ContentParameters<T>>,
         ChannelCategoryId = <T as Trait>::ChannelCategoryId,
         ChannelOwnershipTransferRequestId = <T as Trait>::ChannelOwnershipTransferRequestId,
+        ChannelCategory = ChannelCategory<T>,
         PlaylistId = <T as Trait>::PlaylistId,
         SeriesId = <T as Trait>::SeriesId,
         PersonId = <T as Trait>::PersonId,
         ChannelOwnershipTransferRequest = ChannelOwnershipTransferRequest<T>,
-        Series = Series<<T as StorageOwnership>::ChannelId, <T as Trait>::VideoId>,
+        Series = Series<<T as StorageOwnership>::ChannelId, <T as Trait>::VideoId, ContentId<T>>,
         Channel = Channel<T>,
         ContentParameters = ContentParameters<T>,
         AccountId = <T as frame_system::Trait>::AccountId,
         ContentId = ContentId<T>,
         IsCensored = bool,
+        CensorshipAppealId = <T as Trait>::CensorshipAppealId,
+        CensorshipAppeal = CensorshipAppeal<T>,
+        CommentId = <T as Trait>::CommentId,
+        ContentReportId = <T as Trait>::ContentReportId,
+        ContentReport = ContentReport<T>,
+        SubscriptionTierId = <T as Trait>::SubscriptionTierId,
+        SubscriptionTier = SubscriptionTier<T>,
+        Subscription = Subscription<T>,
+        BlockNumber = <T as frame_system::Trait>::BlockNumber,
+        Balance = BalanceOf<T>,
+        OwnedNft = OwnedNft<T>,
+        EnglishAuction = EnglishAuction<T>,
+        MintId = <T as minting::Trait>::MintId,
+        MintBalance = minting::BalanceOf<T>,
+        Hash = <T as frame_system::Trait>::Hash,
     {
         // Curators
         CuratorGroupCreated(CuratorGroupId),
         CuratorGroupStatusSet(CuratorGroupId, bool /* active status */),
+        CuratorGroupPermissionsSet(CuratorGroupId, BTreeSet<CuratorGroupPermission>),
         CuratorAdded(CuratorGroupId, CuratorId),
         CuratorRemoved(CuratorGroupId, CuratorId),
 
@@ -1410,6 +5212,14 @@ decl_event!(
             ChannelUpdateParameters<ContentParameters, AccountId>,
         ),
         ChannelAssetsRemoved(ContentActor, ChannelId, Vec<ContentId>),
+        ChannelDeleted(ContentActor, ChannelId),
+        ChannelCollaboratorSet(
+            ContentActor,
+            ChannelId,
+            MemberId,
+            BTreeSet<ChannelCollaboratorPermission>,
+        ),
+        ChannelCollaboratorRemoved(ContentActor, ChannelId, MemberId),
 
         ChannelCensorshipStatusUpdated(
             ContentActor,
@@ -1431,7 +5241,7 @@ decl_event!(
         ChannelCategoryCreated(
             ChannelCategoryId,
             ChannelCategory,
-            ChannelCategoryCreationParameters,
+            ChannelCategoryCreationParameters<ChannelCategoryId>,
         ),
         ChannelCategoryUpdated(
             ContentActor,
@@ -1439,15 +5249,17 @@ decl_event!(
             ChannelCategoryUpdateParameters,
         ),
         ChannelCategoryDeleted(ContentActor, ChannelCategoryId),
+        ChannelCategorySet(ContentActor, ChannelId, Option<ChannelCategoryId>),
 
         // Videos
         VideoCategoryCreated(
             ContentActor,
             VideoCategoryId,
-            VideoCategoryCreationParameters,
+            VideoCategoryCreationParameters<VideoCategoryId>,
         ),
         VideoCategoryUpdated(ContentActor, VideoCategoryId, VideoCategoryUpdateParameters),
         VideoCategoryDeleted(ContentActor, VideoCategoryId),
+        VideoCategorySet(ContentActor, VideoId, Option<VideoCategoryId>),
 
         VideoCreated(
             ContentActor,
@@ -1460,6 +5272,7 @@ decl_event!(
             VideoId,
             VideoUpdateParameters<ContentParameters>,
         ),
+        VideoAssetsRemoved(ContentActor, VideoId, Vec<ContentId>),
         VideoDeleted(ContentActor, VideoId),
 
         VideoCensorshipStatusUpdated(
@@ -1469,12 +5282,101 @@ decl_event!(
             Vec<u8>, /* rationale */
         ),
 
+        VideoVisibilitySet(
+            ContentActor,
+            VideoId,
+            VideoVisibility,
+            Option<BlockNumber>, /* publish at */
+        ),
+        VideoPublished(VideoId),
+
+        // Video NFTs
+        NftIssued(ContentActor, VideoId, OwnedNft),
+        NftTransferred(ContentActor, VideoId, NftOwner<MemberId>),
+        NftSellOrderMade(ContentActor, VideoId, Balance),
+        NftBought(MemberId, VideoId, Balance),
+        EnglishAuctionStarted(ContentActor, VideoId, EnglishAuction),
+        AuctionBidMade(MemberId, VideoId, Balance),
+        EnglishAuctionSettled(VideoId, Option<MemberId> /* winner */),
+        NftSaleCanceled(ContentActor, VideoId),
+        PaymentDeferred(
+            AccountId, /* payer */
+            AccountId, /* payee */
+            Balance,
+        ),
+        PaymentClaimed(
+            AccountId, /* payer */
+            AccountId, /* payee */
+            Balance,
+        ),
+
+        // Channel Rewards
+        ContentMintCapacityChanged(MintId, MintBalance),
+        RewardsCommitmentUpdated(Hash),
+        ChannelRewardClaimed(ContentActor, ChannelId, MintBalance),
+
+        // Channel Subscriptions
+        SubscriptionTierCreated(
+            ContentActor,
+            ChannelId,
+            SubscriptionTierId,
+            SubscriptionTier,
+        ),
+        SubscriptionTierRemoved(ContentActor, ChannelId, SubscriptionTierId),
+        ChannelSubscribed(MemberId, ChannelId, Subscription),
+        ChannelSubscriptionCanceled(MemberId, ChannelId),
+        ChannelSubscriptionRenewed(MemberId, ChannelId, BlockNumber /* paid until */),
+        ChannelSubscriptionExpired(MemberId, ChannelId),
+
+        // Reactions and Comments
+        VideoLiked(MemberId, VideoId),
+        VideoUnliked(MemberId, VideoId),
+        CommentPosted(
+            MemberId,
+            VideoId,
+            CommentId,
+            Option<CommentId>, /* parent comment */
+            Vec<u8>,           /* text */
+        ),
+        CommentModerated(
+            ContentActor,
+            VideoId,
+            CommentId,
+            Vec<u8>, /* rationale */
+        ),
+        VideoCommentsStatusSet(ContentActor, VideoId, bool /* comments enabled */),
+        CommentConstraintsSet(
+            InputValidationLengthConstraint, /* text */
+            InputValidationLengthConstraint, /* moderation rationale */
+        ),
+
+        // Censorship Appeals
+        CensorshipAppealFiled(ContentActor, CensorshipAppealId, CensorshipAppeal),
+        CensorshipAppealResolved(
+            CensorshipAppealId,
+            CensorshipAppealStatus,
+            Vec<u8>, /* rationale */
+        ),
+
+        // Content Reports
+        ContentReported(MemberId, ContentReportId, ContentReport),
+        ContentReportResolved(ContentReportId, ContentReportStatus),
+        ContentReportsDismissed(
+            ContentActor,
+            CensoredContent<ChannelId, VideoId>,
+            Vec<u8>, /* rationale */
+        ),
+
         // Featured Videos
-        FeaturedVideosSet(ContentActor, Vec<VideoId>),
+        FeaturedVideosSet(ContentActor, Option<VideoCategoryId>, Vec<VideoId>),
 
         // Video Playlists
-        PlaylistCreated(ContentActor, PlaylistId, PlaylistCreationParameters),
-        PlaylistUpdated(ContentActor, PlaylistId, PlaylistUpdateParameters),
+        PlaylistCreated(
+            ContentActor,
+            PlaylistId,
+            PlaylistCreationParameters<VideoId>,
+        ),
+        PlaylistUpdated(ContentActor, PlaylistId, PlaylistUpdateParameters<VideoId>),
         PlaylistDeleted(ContentActor, PlaylistId),
 
         // Series
@@ -1496,17 +5398,21 @@ decl_event!(
 
         // Persons
         PersonCreated(
-            ContentActor,
+            PersonActor,
             PersonId,
             Vec<NewAsset>,
             PersonCreationParameters<ContentParameters>,
         ),
         PersonUpdated(
-            ContentActor,
+            PersonActor,
             PersonId,
             Vec<NewAsset>,
             PersonUpdateParameters<ContentParameters>,
         ),
-        PersonDeleted(ContentActor, PersonId),
+        PersonDeleted(PersonActor, PersonId),
+
+        // Video Credits
+        PersonAddedToVideo(ContentActor, VideoId, PersonId),
+        PersonRemovedFromVideo(ContentActor, VideoId, PersonId),
     }
 );
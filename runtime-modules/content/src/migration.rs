@@ -0,0 +1,282 @@
+use crate::*;
+
+use frame_support::storage::{IterableStorageMap, StorageValue};
+use sp_std::collections::btree_map::BTreeMap;
+
+/// Version of the module storage layout, the runtime upgrade migrates older layouts
+pub(crate) const STORAGE_VERSION: u32 = 1;
+
+/// Length constraint of the comment texts and moderation rationales, comments did not exist
+/// in the previous layout
+fn default_comment_constraint() -> InputValidationLengthConstraint {
+    InputValidationLengthConstraint::new(1, 1024)
+}
+
+/// Channel layout before channel assets and categories were tracked.
+#[derive(Decode)]
+struct OldChannelRecord<MemberId, CuratorGroupId, DAOId, AccountId, VideoId, PlaylistId, SeriesId> {
+    owner: ChannelOwner<MemberId, CuratorGroupId, DAOId>,
+    videos: Vec<VideoId>,
+    playlists: Vec<PlaylistId>,
+    series: Vec<SeriesId>,
+    is_censored: bool,
+    reward_account: Option<AccountId>,
+}
+
+type OldChannel<T> = OldChannelRecord<
+    <T as MembershipTypes>::MemberId,
+    <T as ContentActorAuthenticator>::CuratorGroupId,
+    <T as StorageOwnership>::DAOId,
+    <T as frame_system::Trait>::AccountId,
+    <T as Trait>::VideoId,
+    <T as Trait>::PlaylistId,
+    <T as Trait>::SeriesId,
+>;
+
+impl<MemberId, CuratorGroupId, DAOId, AccountId, VideoId, PlaylistId, SeriesId>
+    OldChannelRecord<MemberId, CuratorGroupId, DAOId, AccountId, VideoId, PlaylistId, SeriesId>
+{
+    /// Channel layout before channel categories, tracking the given assets.
+    fn migrate<ContentId>(
+        self,
+        assets: Vec<ContentId>,
+    ) -> ChannelBeforeCategory<
+        MemberId,
+        CuratorGroupId,
+        DAOId,
+        AccountId,
+        VideoId,
+        PlaylistId,
+        SeriesId,
+        ContentId,
+    > {
+        ChannelBeforeCategory {
+            owner: self.owner,
+            videos: self.videos,
+            playlists: self.playlists,
+            series: self.series,
+            is_censored: self.is_censored,
+            reward_account: self.reward_account,
+            assets,
+        }
+    }
+}
+
+/// Channel layout before channel categories.
+struct ChannelBeforeCategory<
+    MemberId,
+    CuratorGroupId,
+    DAOId,
+    AccountId,
+    VideoId,
+    PlaylistId,
+    SeriesId,
+    ContentId,
+> {
+    owner: ChannelOwner<MemberId, CuratorGroupId, DAOId>,
+    videos: Vec<VideoId>,
+    playlists: Vec<PlaylistId>,
+    series: Vec<SeriesId>,
+    is_censored: bool,
+    reward_account: Option<AccountId>,
+    assets: Vec<ContentId>,
+}
+
+impl<MemberId, CuratorGroupId, DAOId, AccountId, VideoId, PlaylistId, SeriesId, ContentId>
+    ChannelBeforeCategory<
+        MemberId,
+        CuratorGroupId,
+        DAOId,
+        AccountId,
+        VideoId,
+        PlaylistId,
+        SeriesId,
+        ContentId,
+    >
+{
+    /// Current channel layout, the channel is not assigned to any category.
+    fn migrate<ChannelCategoryId>(
+        self,
+    ) -> ChannelRecord<
+        MemberId,
+        CuratorGroupId,
+        DAOId,
+        AccountId,
+        VideoId,
+        PlaylistId,
+        SeriesId,
+        ContentId,
+        ChannelCategoryId,
+    > {
+        ChannelRecord {
+            owner: self.owner,
+            videos: self.videos,
+            playlists: self.playlists,
+            series: self.series,
+            is_censored: self.is_censored,
+            reward_account: self.reward_account,
+            assets: self.assets,
+            category: None,
+        }
+    }
+}
+
+/// Video layout before video assets, categories, visibility and scheduled publishing.
+#[derive(Decode)]
+struct OldVideo<ChannelId, SeriesId> {
+    in_channel: ChannelId,
+    in_series: Option<SeriesId>,
+    is_censored: bool,
+}
+
+impl<ChannelId, SeriesId> OldVideo<ChannelId, SeriesId> {
+    /// Video layout before video categories. Video assets were uploaded with the channel
+    /// as the owner, they are tracked by the channel.
+    fn migrate<ContentId>(self) -> VideoBeforeCategory<ChannelId, SeriesId, ContentId> {
+        VideoBeforeCategory {
+            in_channel: self.in_channel,
+            in_series: self.in_series,
+            is_censored: self.is_censored,
+            assets: Vec::new(),
+        }
+    }
+}
+
+/// Video layout before video categories.
+struct VideoBeforeCategory<ChannelId, SeriesId, ContentId> {
+    in_channel: ChannelId,
+    in_series: Option<SeriesId>,
+    is_censored: bool,
+    assets: Vec<ContentId>,
+}
+
+impl<ChannelId, SeriesId, ContentId> VideoBeforeCategory<ChannelId, SeriesId, ContentId> {
+    /// Video layout before video visibility, the video is not assigned to any category.
+    fn migrate<VideoCategoryId>(
+        self,
+    ) -> VideoBeforeVisibility<ChannelId, SeriesId, ContentId, VideoCategoryId> {
+        VideoBeforeVisibility {
+            in_channel: self.in_channel,
+            in_series: self.in_series,
+            is_censored: self.is_censored,
+            assets: self.assets,
+            category: None,
+        }
+    }
+}
+
+/// Video layout before video visibility and scheduled publishing.
+struct VideoBeforeVisibility<ChannelId, SeriesId, ContentId, VideoCategoryId> {
+    in_channel: ChannelId,
+    in_series: Option<SeriesId>,
+    is_censored: bool,
+    assets: Vec<ContentId>,
+    category: Option<VideoCategoryId>,
+}
+
+impl<ChannelId, SeriesId, ContentId, VideoCategoryId>
+    VideoBeforeVisibility<ChannelId, SeriesId, ContentId, VideoCategoryId>
+{
+    /// Current video layout. Videos were public before they had a visibility,
+    /// and none is scheduled for publishing.
+    fn migrate<BlockNumber>(
+        self,
+    ) -> Video<ChannelId, SeriesId, ContentId, VideoCategoryId, BlockNumber> {
+        Video {
+            in_channel: self.in_channel,
+            in_series: self.in_series,
+            is_censored: self.is_censored,
+            assets: self.assets,
+            category: self.category,
+            visibility: VideoVisibility::Public,
+            publish_at: None,
+        }
+    }
+}
+
+/// Category layout before category nesting and counters.
+#[derive(Decode)]
+struct OldCategory {}
+
+/// Curator group layout before curator group permissions.
+#[derive(Decode)]
+struct OldCuratorGroup<CuratorId: Ord> {
+    curators: BTreeSet<CuratorId>,
+    active: bool,
+}
+
+impl<T: Trait> Module<T> {
+    /// Translate channels, videos, categories and curator groups stored by the previous runtime
+    /// into the current layout, once per storage version. Channels get the assets they own in
+    /// the storage system, other new fields are filled with their defaults: no video assets,
+    /// no category, public visibility and the default curator group permissions. Comment
+    /// constraints are initialized and the content mint is created.
+    pub fn migrate_content_storage() -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: u64 = 0;
+
+        // Channels track all the content they own in the storage system, so it is freed
+        // together with the channel
+        let content_owners = T::StorageSystem::content_owners();
+        let stored_content = content_owners.len() as u64;
+
+        let mut channel_assets = BTreeMap::<T::ChannelId, Vec<ContentId<T>>>::new();
+        for (content_id, owner) in content_owners {
+            if let StorageObjectOwner::<T>::Channel(channel_id) = owner {
+                channel_assets
+                    .entry(channel_id)
+                    .or_insert_with(Vec::new)
+                    .push(content_id);
+            }
+        }
+
+        ChannelById::<T>::translate(|channel_id, old: OldChannel<T>| {
+            translated += 1;
+            let assets = channel_assets.remove(&channel_id).unwrap_or_default();
+
+            Some(old.migrate(assets).migrate())
+        });
+
+        VideoById::<T>::translate(|_, old: OldVideo<T::ChannelId, T::SeriesId>| {
+            translated += 1;
+            Some(old.migrate().migrate().migrate())
+        });
+
+        // Migrated channels and videos are not assigned to any category, and previous categories
+        // could not be nested, so every category starts out empty and at the root.
+        ChannelCategoryById::<T>::translate(|_, _: OldCategory| {
+            translated += 1;
+            Some(ChannelCategory::<T>::default())
+        });
+
+        VideoCategoryById::<T>::translate(|_, _: OldCategory| {
+            translated += 1;
+            Some(VideoCategory::<T>::default())
+        });
+
+        CuratorGroupById::<T>::translate(|_, old: OldCuratorGroup<T::CuratorId>| {
+            translated += 1;
+            Some(CuratorGroup::<T>::with_default_permissions(
+                old.curators,
+                old.active,
+            ))
+        });
+
+        CommentTextConstraint::put(default_comment_constraint());
+        CommentModerationRationaleConstraint::put(default_comment_constraint());
+
+        // Channel rewards were not paid before, their mint starts without capacity
+        if let Ok(mint_id) = <minting::Module<T>>::add_mint(Zero::zero(), None) {
+            ContentMint::<T>::put(mint_id);
+        }
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        T::DbWeight::get()
+            .reads_writes(translated + 1, translated + 5)
+            .saturating_add(T::DbWeight::get().reads(stored_content))
+    }
+}
@@ -0,0 +1,128 @@
+use super::*;
+
+/// Owner of a video NFT
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NftOwner<MemberId> {
+    /// NFT is held by the owner of the channel the video belongs to
+    ChannelOwner,
+    /// NFT is held by a member
+    Member(MemberId),
+}
+
+impl<MemberId> Default for NftOwner<MemberId> {
+    fn default() -> Self {
+        NftOwner::ChannelOwner
+    }
+}
+
+/// Fixed price sale of a video NFT
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct FixedPriceSaleRecord<Balance, AccountId> {
+    /// Price the NFT can be bought for
+    pub price: Balance,
+    /// Account the payment is released to
+    pub seller_account: AccountId,
+}
+
+/// Highest bid placed in an english auction, the amount is held in escrow
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct BidRecord<MemberId, Balance, AccountId> {
+    /// Member the NFT goes to if the bid wins
+    pub bidder: MemberId,
+    /// Account the bid was taken from, and refunded to if outbid
+    pub bidder_account: AccountId,
+    /// Amount of the bid
+    pub amount: Balance,
+}
+
+/// English auction of a video NFT
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct EnglishAuctionRecord<MemberId, Balance, BlockNumber, AccountId> {
+    /// Minimal amount of the first bid
+    pub starting_price: Balance,
+    /// Minimal amount a bid must exceed the top bid by
+    pub min_bid_step: Balance,
+    /// Block at which the auction is settled
+    pub end: BlockNumber,
+    /// Account the winning bid is released to
+    pub seller_account: AccountId,
+    /// Current highest bid, if any
+    pub top_bid: Option<BidRecord<MemberId, Balance, AccountId>>,
+}
+
+/// Parameters of the english auction being started
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct AuctionParams<Balance, BlockNumber> {
+    /// Minimal amount of the first bid
+    pub starting_price: Balance,
+    /// Minimal amount a bid must exceed the top bid by
+    pub min_bid_step: Balance,
+    /// Number of blocks the auction accepts bids for
+    pub duration: BlockNumber,
+}
+
+/// Whether a video NFT is on sale
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum NftTransactionalStatus<MemberId, Balance, BlockNumber, AccountId> {
+    /// Not on sale
+    Idle,
+    /// Can be bought for a fixed price
+    BuyNow(FixedPriceSaleRecord<Balance, AccountId>),
+    /// Sold in an english auction
+    Auction(EnglishAuctionRecord<MemberId, Balance, BlockNumber, AccountId>),
+}
+
+impl<MemberId, Balance, BlockNumber, AccountId> Default
+    for NftTransactionalStatus<MemberId, Balance, BlockNumber, AccountId>
+{
+    fn default() -> Self {
+        NftTransactionalStatus::Idle
+    }
+}
+
+/// Non-fungible token issued for a video
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct OwnedNftRecord<MemberId, Balance, BlockNumber, AccountId> {
+    /// Current owner of the NFT
+    pub owner: NftOwner<MemberId>,
+    /// Share of secondary sales paid to the channel reward account
+    pub creator_royalty: Option<Perbill>,
+    /// Whether the NFT is on sale
+    pub transactional_status: NftTransactionalStatus<MemberId, Balance, BlockNumber, AccountId>,
+}
+
+impl<MemberId, Balance, BlockNumber, AccountId>
+    OwnedNftRecord<MemberId, Balance, BlockNumber, AccountId>
+{
+    /// Ensure the NFT is not on sale
+    pub fn ensure_is_idle<T: Trait>(&self) -> DispatchResult {
+        ensure!(
+            matches!(self.transactional_status, NftTransactionalStatus::Idle),
+            Error::<T>::NftIsOnSale
+        );
+        Ok(())
+    }
+}
+
+// OwnedNft type alias for simplification.
+pub type OwnedNft<T> = OwnedNftRecord<
+    <T as MembershipTypes>::MemberId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+    <T as frame_system::Trait>::AccountId,
+>;
+
+// EnglishAuction type alias for simplification.
+pub type EnglishAuction<T> = EnglishAuctionRecord<
+    <T as MembershipTypes>::MemberId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+    <T as frame_system::Trait>::AccountId,
+>;
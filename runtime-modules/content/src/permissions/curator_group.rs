@@ -1,5 +1,40 @@
 use super::*;
 
+/// Actions curators can perform on behalf of their curator group, when permitted by the lead
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum CuratorGroupPermission {
+    /// Create channels owned by the curator group
+    CreateChannels,
+
+    /// Update and delete curator group owned channels and their content
+    ManageChannels,
+
+    /// Censor channels and videos
+    CensorContent,
+
+    /// Create, update and delete channel and video categories
+    ManageCategories,
+
+    /// Set featured videos
+    SetFeaturedVideos,
+}
+
+impl CuratorGroupPermission {
+    /// Permissions granted to newly created curator groups
+    pub fn default_permissions() -> BTreeSet<Self> {
+        [
+            Self::CreateChannels,
+            Self::ManageChannels,
+            Self::CensorContent,
+            Self::ManageCategories,
+        ]
+        .iter()
+        .copied()
+        .collect()
+    }
+}
+
 /// A group, that consists of `curators` set
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
@@ -9,6 +44,9 @@ pub struct CuratorGroup<T: Trait> {
 
     /// When `false`, curator in a given group is forbidden to act
     active: bool,
+
+    /// Actions curators in a given group are allowed to perform
+    permissions: BTreeSet<CuratorGroupPermission>,
 }
 
 impl<T: Trait> Default for CuratorGroup<T> {
@@ -17,11 +55,21 @@ impl<T: Trait> Default for CuratorGroup<T> {
             curators: BTreeSet::new(),
             // default curator group status right after creation
             active: false,
+            permissions: CuratorGroupPermission::default_permissions(),
         }
     }
 }
 
 impl<T: Trait> CuratorGroup<T> {
+    /// Create `CuratorGroup` with provided curators and status, granted the default permissions
+    pub(crate) fn with_default_permissions(curators: BTreeSet<T::CuratorId>, active: bool) -> Self {
+        Self {
+            curators,
+            active,
+            permissions: CuratorGroupPermission::default_permissions(),
+        }
+    }
+
     /// Check if `CuratorGroup` contains curator under given `curator_id`
     pub fn has_curator(&self, curator_id: &T::CuratorId) -> bool {
         self.curators.contains(curator_id)
@@ -37,6 +85,21 @@ impl<T: Trait> CuratorGroup<T> {
         self.active = is_active
     }
 
+    /// Check if `CuratorGroup` is allowed to perform actions under given `permission`
+    pub fn has_permission(&self, permission: CuratorGroupPermission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    /// Retrieve set of all permissions granted to `CuratorGroup` by reference
+    pub fn get_permissions(&self) -> &BTreeSet<CuratorGroupPermission> {
+        &self.permissions
+    }
+
+    /// Set `CuratorGroup` permissions as provided
+    pub fn set_permissions(&mut self, permissions: BTreeSet<CuratorGroupPermission>) {
+        self.permissions = permissions
+    }
+
     /// Retrieve set of all curator_ids related to `CuratorGroup` by reference
     pub fn get_curators(&self) -> &BTreeSet<T::CuratorId> {
         &self.curators
@@ -99,4 +162,22 @@ impl<T: Trait> CuratorGroup<T> {
         Self::ensure_curator_in_group_exists(&curator_group, curator_id)?;
         Ok(())
     }
+
+    /// Authorize curator, performing all checks to ensure curator can act
+    /// and the curator group is allowed to perform actions under given `permission`
+    pub fn perform_curator_in_group_auth_with_permission(
+        curator_id: &T::CuratorId,
+        curator_group_id: &T::CuratorGroupId,
+        account_id: &T::AccountId,
+        permission: CuratorGroupPermission,
+    ) -> DispatchResult {
+        Self::perform_curator_in_group_auth(curator_id, curator_group_id, account_id)?;
+
+        // Ensure curator group is allowed to perform the action
+        ensure!(
+            Module::<T>::curator_group_by_id(curator_group_id).has_permission(permission),
+            Error::<T>::CuratorGroupLacksPermission
+        );
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ pub use curator_group::*;
 pub use crate::errors::*;
 use crate::*;
 pub use codec::{Codec, Decode, Encode};
-pub use common::MembershipTypes;
+pub use common::{MembershipTypes, StorageOwnership};
 use core::fmt::Debug;
 use frame_support::{ensure, Parameter};
 #[cfg(feature = "std")]
@@ -15,7 +15,9 @@ use sp_runtime::traits::{MaybeSerializeDeserialize, Member};
 // use frame_system::ensure_root;
 
 /// Model of authentication manager.
-pub trait ContentActorAuthenticator: frame_system::Trait + MembershipTypes {
+pub trait ContentActorAuthenticator:
+    frame_system::Trait + MembershipTypes + StorageOwnership
+{
     /// Curator identifier
     type CuratorId: Parameter
         + Member
@@ -53,6 +55,9 @@ pub trait ContentActorAuthenticator: frame_system::Trait + MembershipTypes {
 
     /// Authorize actor as member
     fn is_member(member_id: &Self::MemberId, account_id: &Self::AccountId) -> bool;
+
+    /// Authorize actor as DAO
+    fn is_dao_account(dao_id: &Self::DAOId, account_id: &Self::AccountId) -> bool;
 }
 
 pub fn ensure_is_valid_curator_id<T: Trait>(curator_id: &T::CuratorId) -> DispatchResult {
@@ -87,6 +92,18 @@ pub fn ensure_member_auth_success<T: Trait>(
     Ok(())
 }
 
+/// Ensure DAO authorization performed succesfully
+pub fn ensure_dao_auth_success<T: Trait>(
+    dao_id: &T::DAOId,
+    account_id: &T::AccountId,
+) -> DispatchResult {
+    ensure!(
+        T::is_dao_account(dao_id, account_id),
+        Error::<T>::DaoAuthFailed
+    );
+    Ok(())
+}
+
 /// Ensure lead authorization performed succesfully
 pub fn ensure_lead_auth_success<T: Trait>(account_id: &T::AccountId) -> DispatchResult {
     ensure!(T::is_lead(account_id), Error::<T>::LeadAuthFailed);
@@ -99,9 +116,18 @@ pub fn ensure_is_lead<T: Trait>(origin: T::Origin) -> DispatchResult {
     ensure_lead_auth_success::<T>(&account_id)
 }
 
+/// Ensure given `Origin` is lead or root, e.g. an approved proposal
+pub fn ensure_is_lead_or_root<T: Trait>(origin: T::Origin) -> DispatchResult {
+    if ensure_root(origin.clone()).is_ok() {
+        Ok(())
+    } else {
+        ensure_is_lead::<T>(origin)
+    }
+}
+
 pub fn ensure_actor_authorized_to_create_channel<T: Trait>(
     origin: T::Origin,
-    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
 ) -> DispatchResult {
     match actor {
         // Lead should use their member or curator role to create or update channel assets.
@@ -112,10 +138,11 @@ pub fn ensure_actor_authorized_to_create_channel<T: Trait>(
             let sender = ensure_signed(origin)?;
 
             // Authorize curator, performing all checks to ensure curator can act
-            CuratorGroup::<T>::perform_curator_in_group_auth(
+            CuratorGroup::<T>::perform_curator_in_group_auth_with_permission(
                 curator_id,
                 curator_group_id,
                 &sender,
+                CuratorGroupPermission::CreateChannels,
             )
         }
         ContentActor::Member(member_id) => {
@@ -123,15 +150,18 @@ pub fn ensure_actor_authorized_to_create_channel<T: Trait>(
 
             ensure_member_auth_success::<T>(member_id, &sender)
         }
-        // TODO:
-        // ContentActor::Dao(_daoId) => ...,
+        ContentActor::Dao(dao_id) => {
+            let sender = ensure_signed(origin)?;
+
+            ensure_dao_auth_success::<T>(dao_id, &sender)
+        }
     }
 }
 
 // Enure actor can update channels and videos in the channel
 pub fn ensure_actor_authorized_to_update_channel<T: Trait>(
     origin: T::Origin,
-    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
     owner: &ChannelOwner<T::MemberId, T::CuratorGroupId, T::DAOId>,
 ) -> DispatchResult {
     // Only owner of a channel can update and delete channel assets.
@@ -150,10 +180,11 @@ pub fn ensure_actor_authorized_to_update_channel<T: Trait>(
             let sender = ensure_signed(origin)?;
 
             // Authorize curator, performing all checks to ensure curator can act
-            CuratorGroup::<T>::perform_curator_in_group_auth(
+            CuratorGroup::<T>::perform_curator_in_group_auth_with_permission(
                 curator_id,
                 curator_group_id,
                 &sender,
+                CuratorGroupPermission::ManageChannels,
             )?;
 
             // Ensure curator group is the channel owner.
@@ -177,28 +208,101 @@ pub fn ensure_actor_authorized_to_update_channel<T: Trait>(
 
             Ok(())
         }
-        // TODO:
-        // ContentActor::Dao(_daoId) => ...,
+        ContentActor::Dao(dao_id) => {
+            let sender = ensure_signed(origin)?;
+
+            ensure_dao_auth_success::<T>(dao_id, &sender)?;
+
+            // Ensure the DAO is the channel owner.
+            ensure!(
+                *owner == ChannelOwner::Dao(*dao_id),
+                Error::<T>::ActorNotAuthorized
+            );
+
+            Ok(())
+        }
+    }
+}
+
+// Enure actor can update channel content, either as the channel owner
+// or as a channel collaborator granted given permission
+pub fn ensure_actor_authorized_to_update_channel_content<T: Trait>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+    channel_id: &T::ChannelId,
+    owner: &ChannelOwner<T::MemberId, T::CuratorGroupId, T::DAOId>,
+    permission: ChannelCollaboratorPermission,
+) -> DispatchResult {
+    if let ContentActor::Member(member_id) = actor {
+        if Module::<T>::channel_collaborators(channel_id, member_id).contains(&permission) {
+            let sender = ensure_signed(origin)?;
+
+            return ensure_member_auth_success::<T>(member_id, &sender);
+        }
+    }
+
+    ensure_actor_authorized_to_update_channel::<T>(origin, actor, owner)
+}
+
+// Enure actor can manage a video NFT, either as the channel owner
+// when the NFT is held by the channel owner, or as the member holding it
+pub fn ensure_actor_authorized_to_manage_nft<T: Trait>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
+    nft_owner: &NftOwner<T::MemberId>,
+    channel_owner: &ChannelOwner<T::MemberId, T::CuratorGroupId, T::DAOId>,
+) -> DispatchResult {
+    match nft_owner {
+        NftOwner::ChannelOwner => {
+            ensure_actor_authorized_to_update_channel::<T>(origin, actor, channel_owner)
+        }
+        NftOwner::Member(member_id) => {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(member_id, &sender)?;
+
+            // Ensure the member holds the NFT.
+            ensure!(
+                *actor == ContentActor::Member(*member_id),
+                Error::<T>::ActorNotAuthorized
+            );
+
+            Ok(())
+        }
     }
 }
 
 // Enure actor can update or delete channels and videos
 pub fn ensure_actor_authorized_to_set_featured_videos<T: Trait>(
     origin: T::Origin,
-    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
 ) -> DispatchResult {
-    // Only Lead authorized to set featured videos
-    if let ContentActor::Lead = actor {
-        let sender = ensure_signed(origin)?;
-        ensure_lead_auth_success::<T>(&sender)
-    } else {
-        Err(Error::<T>::ActorNotAuthorized.into())
+    // Only lead and curators of permitted curator groups can set featured videos
+    match actor {
+        ContentActor::Lead => {
+            let sender = ensure_signed(origin)?;
+            ensure_lead_auth_success::<T>(&sender)
+        }
+        ContentActor::Curator(curator_group_id, curator_id) => {
+            let sender = ensure_signed(origin)?;
+
+            // Authorize curator, performing all checks to ensure curator can act
+            CuratorGroup::<T>::perform_curator_in_group_auth_with_permission(
+                curator_id,
+                curator_group_id,
+                &sender,
+                CuratorGroupPermission::SetFeaturedVideos,
+            )
+        }
+        ContentActor::Member(_) | ContentActor::Dao(_) => {
+            Err(Error::<T>::ActorNotAuthorized.into())
+        }
     }
 }
 
 pub fn ensure_actor_authorized_to_censor<T: Trait>(
     origin: T::Origin,
-    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
     owner: &ChannelOwner<T::MemberId, T::CuratorGroupId, T::DAOId>,
 ) -> DispatchResult {
     // Only lead and curators can censor channels and videos
@@ -212,10 +316,11 @@ pub fn ensure_actor_authorized_to_censor<T: Trait>(
             let sender = ensure_signed(origin)?;
 
             // Authorize curator, performing all checks to ensure curator can act
-            CuratorGroup::<T>::perform_curator_in_group_auth(
+            CuratorGroup::<T>::perform_curator_in_group_auth_with_permission(
                 curator_id,
                 curator_group_id,
                 &sender,
+                CuratorGroupPermission::CensorContent,
             )?;
 
             // Curators cannot censor curator group channels
@@ -225,18 +330,16 @@ pub fn ensure_actor_authorized_to_censor<T: Trait>(
                 Ok(())
             }
         },
-        ContentActor::Member(_) => {
-            // Members cannot censore channels!
+        ContentActor::Member(_) | ContentActor::Dao(_) => {
+            // Members and DAOs cannot censore channels!
             Err(Error::<T>::ActorNotAuthorized.into())
         }
-        // TODO:
-        // ContentActor::Dao(_daoId) => ...,
     }
 }
 
 pub fn ensure_actor_authorized_to_manage_categories<T: Trait>(
     origin: T::Origin,
-    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
 ) -> DispatchResult {
     // Only lead and curators can manage categories
     match actor {
@@ -248,24 +351,60 @@ pub fn ensure_actor_authorized_to_manage_categories<T: Trait>(
             let sender = ensure_signed(origin)?;
 
             // Authorize curator, performing all checks to ensure curator can act
-            CuratorGroup::<T>::perform_curator_in_group_auth(
+            CuratorGroup::<T>::perform_curator_in_group_auth_with_permission(
                 curator_id,
                 curator_group_id,
                 &sender,
+                CuratorGroupPermission::ManageCategories,
             )
         },
-        ContentActor::Member(_) => {
-            // Members cannot censore channels!
+        ContentActor::Member(_) | ContentActor::Dao(_) => {
+            // Members and DAOs cannot manage categories!
             Err(Error::<T>::ActorNotAuthorized.into())
         }
-        // TODO:
-        // ContentActor::Dao(_daoId) => ...,
+    }
+}
+
+pub fn ensure_actor_authorized_to_create_person<T: Trait>(
+    origin: T::Origin,
+    actor: &PersonActor<T::MemberId, T::CuratorId>,
+) -> DispatchResult {
+    // Members and curators can create persons
+    match actor {
+        PersonActor::Member(member_id) => {
+            let sender = ensure_signed(origin)?;
+            ensure_member_auth_success::<T>(member_id, &sender)
+        }
+        PersonActor::Curator(curator_id) => {
+            let sender = ensure_signed(origin)?;
+            ensure_curator_auth_success::<T>(curator_id, &sender)
+        }
+    }
+}
+
+pub fn ensure_actor_authorized_to_update_or_delete_person<T: Trait>(
+    origin: T::Origin,
+    actor: &PersonActor<T::MemberId, T::CuratorId>,
+    controller: &PersonController<T::MemberId>,
+) -> DispatchResult {
+    ensure_actor_authorized_to_create_person::<T>(origin, actor)?;
+
+    // Only controlling member can update and delete member controlled persons.
+    // Any curator can update and delete curator controlled persons.
+    match (actor, controller) {
+        (PersonActor::Member(member_id), PersonController::Member(controller_id))
+            if member_id == controller_id =>
+        {
+            Ok(())
+        }
+        (PersonActor::Curator(_), PersonController::Curators) => Ok(()),
+        _ => Err(Error::<T>::ActorNotAuthorized.into()),
     }
 }
 
 // pub fn ensure_actor_authorized_to_delete_stale_assets<T: Trait>(
 //     origin: T::Origin,
-//     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+//     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId, T::DAOId>,
 // ) -> DispatchResult {
 //     // Only Lead and (sudo) can delete assets no longer associated with a channel or person.
 //     if let ContentActor::Lead = actor {
@@ -284,18 +423,20 @@ pub enum ContentActor<
     CuratorGroupId: Default + Clone + Copy,
     CuratorId: Default + Clone + Copy,
     MemberId: Default + Clone + Copy,
+    DAOId: Default + Clone + Copy,
 > {
     Curator(CuratorGroupId, CuratorId),
     Member(MemberId),
     Lead,
-    // Dao,
+    Dao(DAOId),
 }
 
 impl<
         CuratorGroupId: Default + Clone + Copy,
         CuratorId: Default + Clone + Copy,
         MemberId: Default + Clone + Copy,
-    > Default for ContentActor<CuratorGroupId, CuratorId, MemberId>
+        DAOId: Default + Clone + Copy,
+    > Default for ContentActor<CuratorGroupId, CuratorId, MemberId, DAOId>
 {
     fn default() -> Self {
         Self::Lead
@@ -0,0 +1,65 @@
+use super::*;
+
+/// Reason a channel or video is reported for
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    IllegalContent,
+    CopyrightInfringement,
+    Other,
+}
+
+impl Default for ReportReason {
+    fn default() -> Self {
+        ReportReason::Other
+    }
+}
+
+/// Stage of a content report
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ContentReportStatus {
+    /// Awaiting curator triage
+    Pending,
+    /// Content was censored, the stake was refunded and the reporter rewarded
+    Upheld,
+    /// Report was found frivolous, the stake was slashed into the report stake pool
+    Dismissed,
+    /// Content was deleted before the report was triaged, the stake was refunded
+    Closed,
+}
+
+impl Default for ContentReportStatus {
+    fn default() -> Self {
+        ContentReportStatus::Pending
+    }
+}
+
+/// Report of a channel or video filed by a member
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ContentReportRecord<ChannelId, VideoId, MemberId, AccountId, Balance> {
+    /// The reported channel or video
+    pub content: CensoredContent<ChannelId, VideoId>,
+    /// Member who filed the report
+    pub reporter: MemberId,
+    /// Account the stake is reserved on
+    pub reporter_account: AccountId,
+    /// Stake reserved until the report is triaged
+    pub stake: Balance,
+    /// Why the content was reported
+    pub reason: ReportReason,
+    /// Current stage of the report
+    pub status: ContentReportStatus,
+}
+
+// ContentReport type alias for simplification.
+pub type ContentReport<T> = ContentReportRecord<
+    <T as StorageOwnership>::ChannelId,
+    <T as Trait>::VideoId,
+    <T as MembershipTypes>::MemberId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+>;
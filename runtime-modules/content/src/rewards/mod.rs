@@ -0,0 +1,58 @@
+use super::*;
+use sp_runtime::traits::Hash;
+
+/// Cumulative earnings of a channel, a leaf of the rewards commitment merkle tree
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ChannelRewardClaimRecord<ChannelId, Balance> {
+    /// Channel the earnings belong to
+    pub channel_id: ChannelId,
+    /// Total amount earned by the channel since its creation
+    pub cumulative_earnings: Balance,
+}
+
+// ChannelRewardClaim type alias for simplification.
+pub type ChannelRewardClaim<T> =
+    ChannelRewardClaimRecord<<T as StorageOwnership>::ChannelId, minting::BalanceOf<T>>;
+
+/// Side of the sibling node in the merkle proof
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Default for Side {
+    fn default() -> Self {
+        Side::Right
+    }
+}
+
+/// Sibling node on the path from a leaf to the merkle root
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ProofElementRecord<Hash> {
+    pub hash: Hash,
+    pub side: Side,
+}
+
+// ProofElement type alias for simplification.
+pub type ProofElement<T> = ProofElementRecord<<T as frame_system::Trait>::Hash>;
+
+impl<ChannelId: Encode, Balance: Encode> ChannelRewardClaimRecord<ChannelId, Balance> {
+    /// Compute the merkle root from the claim and the proof of its inclusion.
+    /// Leaves are hashes of the claims, inner nodes are hashes of their (left, right) children.
+    pub fn compute_merkle_root<T: Trait>(
+        &self,
+        proof: &[ProofElement<T>],
+    ) -> <T as frame_system::Trait>::Hash {
+        proof.iter().fold(
+            <T as frame_system::Trait>::Hashing::hash_of(self),
+            |node, sibling| match sibling.side {
+                Side::Left => <T as frame_system::Trait>::Hashing::hash_of(&(sibling.hash, node)),
+                Side::Right => <T as frame_system::Trait>::Hashing::hash_of(&(node, sibling.hash)),
+            },
+        )
+    }
+}
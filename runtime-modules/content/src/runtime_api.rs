@@ -0,0 +1,14 @@
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Content directory queries for storage and distribution nodes
+    pub trait ContentApi<MemberId, ChannelId>
+    where
+        MemberId: Codec,
+        ChannelId: Codec,
+    {
+        /// Whether the member has an active paid subscription to the channel, used to
+        /// enforce access to gated content
+        fn has_active_subscription(member_id: MemberId, channel_id: ChannelId) -> bool;
+    }
+}
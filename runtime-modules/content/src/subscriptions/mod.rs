@@ -0,0 +1,36 @@
+use super::*;
+
+/// Paid subscription tier defined by the channel owner
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct SubscriptionTierRecord<Balance, BlockNumber> {
+    /// Amount paid to the channel reward account for each period
+    pub price: Balance,
+    /// Number of blocks a single payment keeps the subscription active
+    pub period: BlockNumber,
+}
+
+// SubscriptionTier type alias for simplification.
+pub type SubscriptionTier<T> =
+    SubscriptionTierRecord<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>;
+
+/// Subscription of a member to a channel, renewed with a recurring payment at the end of each period
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct SubscriptionRecord<SubscriptionTierId, AccountId, BlockNumber> {
+    /// Tier the member subscribed to
+    pub tier_id: SubscriptionTierId,
+    /// Account the recurring payments are taken from
+    pub payer: AccountId,
+    /// Block at which the subscription is renewed or expires
+    pub paid_until: BlockNumber,
+    /// Whether the subscription is renewed at the end of the paid period
+    pub auto_renew: bool,
+}
+
+// Subscription type alias for simplification.
+pub type Subscription<T> = SubscriptionRecord<
+    <T as Trait>::SubscriptionTierId,
+    <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
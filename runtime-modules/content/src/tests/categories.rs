@@ -0,0 +1,252 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video};
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+
+fn create_channel_category(parent_category_id: Option<u64>) -> u64 {
+    let category_id = Content::next_channel_category_id();
+
+    assert_ok!(Content::create_channel_category(
+        Origin::signed(LEAD_ORIGIN),
+        ContentActor::Lead,
+        ChannelCategoryCreationParameters {
+            meta: vec![],
+            parent_category_id,
+        }
+    ));
+
+    category_id
+}
+
+fn create_video_category(parent_category_id: Option<u64>) -> u64 {
+    let category_id = Content::next_video_category_id();
+
+    assert_ok!(Content::create_video_category(
+        Origin::signed(LEAD_ORIGIN),
+        ContentActor::Lead,
+        VideoCategoryCreationParameters {
+            meta: vec![],
+            parent_category_id,
+        }
+    ));
+
+    category_id
+}
+
+#[test]
+fn category_hierarchy() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        // Parent category must exist
+        assert_err!(
+            Content::create_channel_category(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                ChannelCategoryCreationParameters {
+                    meta: vec![],
+                    parent_category_id: Some(Content::next_channel_category_id()),
+                }
+            ),
+            Error::<Test>::CategoryDoesNotExist
+        );
+
+        let parent_id = create_channel_category(None);
+        let child_id = create_channel_category(Some(parent_id));
+
+        assert_eq!(
+            Content::channel_category_by_id(parent_id),
+            CategoryRecord {
+                parent_category_id: None,
+                depth: 0,
+                subcategories_count: 1,
+                items_count: 0,
+            }
+        );
+        assert_eq!(
+            Content::channel_category_by_id(child_id),
+            CategoryRecord {
+                parent_category_id: Some(parent_id),
+                depth: 1,
+                subcategories_count: 0,
+                items_count: 0,
+            }
+        );
+
+        // Cannot nest categories deeper than allowed
+        assert_err!(
+            Content::create_channel_category(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                ChannelCategoryCreationParameters {
+                    meta: vec![],
+                    parent_category_id: Some(child_id),
+                }
+            ),
+            Error::<Test>::CategoryDepthLimitReached
+        );
+
+        // Cannot delete category with subcategories
+        assert_err!(
+            Content::delete_channel_category(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                parent_id
+            ),
+            Error::<Test>::CategoryHasSubcategories
+        );
+
+        assert_ok!(Content::delete_channel_category(
+            Origin::signed(LEAD_ORIGIN),
+            ContentActor::Lead,
+            child_id
+        ));
+
+        assert_eq!(
+            Content::channel_category_by_id(parent_id).subcategories_count,
+            0
+        );
+
+        assert_ok!(Content::delete_channel_category(
+            Origin::signed(LEAD_ORIGIN),
+            ContentActor::Lead,
+            parent_id
+        ));
+
+        assert!(!ChannelCategoryById::<Test>::contains_key(parent_id));
+    })
+}
+
+#[test]
+fn channel_and_video_category_assignment() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        let channel_category_id = create_channel_category(None);
+        let video_category_id = create_video_category(None);
+
+        // Category must exist
+        assert_err!(
+            Content::set_channel_category(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                Some(channel_category_id + 1)
+            ),
+            Error::<Test>::CategoryDoesNotExist
+        );
+
+        // Only channel owner can assign category
+        assert_err!(
+            Content::set_channel_category(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                Some(channel_category_id)
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::set_channel_category(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            Some(channel_category_id)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelCategorySet(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                Some(channel_category_id)
+            ))
+        );
+
+        assert_eq!(
+            Content::channel_by_id(channel_id).category,
+            Some(channel_category_id)
+        );
+        assert_eq!(
+            Content::channel_category_by_id(channel_category_id).items_count,
+            1
+        );
+
+        assert_ok!(Content::set_video_category(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            Some(video_category_id)
+        ));
+
+        assert_eq!(
+            Content::video_by_id(video_id).category,
+            Some(video_category_id)
+        );
+        assert_eq!(
+            Content::video_category_by_id(video_category_id).items_count,
+            1
+        );
+
+        // Cannot delete categories with items
+        assert_err!(
+            Content::delete_channel_category(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                channel_category_id
+            ),
+            Error::<Test>::CategoryHasItems
+        );
+        assert_err!(
+            Content::delete_video_category(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                video_category_id
+            ),
+            Error::<Test>::CategoryHasItems
+        );
+
+        // Unassigning channel category
+        assert_ok!(Content::set_channel_category(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            None
+        ));
+
+        assert_eq!(
+            Content::channel_category_by_id(channel_category_id).items_count,
+            0
+        );
+
+        // Deleting video unassigns its category
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id
+        ));
+
+        assert_eq!(
+            Content::video_category_by_id(video_category_id).items_count,
+            0
+        );
+
+        assert_ok!(Content::delete_channel_category(
+            Origin::signed(LEAD_ORIGIN),
+            ContentActor::Lead,
+            channel_category_id
+        ));
+        assert_ok!(Content::delete_video_category(
+            Origin::signed(LEAD_ORIGIN),
+            ContentActor::Lead,
+            video_category_id
+        ));
+    })
+}
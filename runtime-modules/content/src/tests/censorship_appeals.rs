@@ -0,0 +1,274 @@
+#![cfg(test)]
+
+use super::curators;
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video};
+use crate::*;
+use frame_support::traits::Currency;
+use frame_support::{assert_err, assert_ok};
+
+fn censor_channel(channel_id: ChannelId, group_id: CuratorGroupId, is_censored: bool) {
+    assert_ok!(Content::update_channel_censorship_status(
+        Origin::signed(FIRST_CURATOR_ORIGIN),
+        ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+        channel_id,
+        is_censored,
+        vec![]
+    ));
+}
+
+fn censor_video(video_id: VideoId, group_id: CuratorGroupId, is_censored: bool) {
+    assert_ok!(Content::update_video_censorship_status(
+        Origin::signed(FIRST_CURATOR_ORIGIN),
+        ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+        video_id,
+        is_censored,
+        vec![]
+    ));
+}
+
+fn appeal_censorship(censored: CensoredContent<ChannelId, VideoId>) -> u64 {
+    let appeal_id = Content::next_censorship_appeal_id();
+
+    assert_ok!(Content::appeal_censorship(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        ContentActor::Member(FIRST_MEMBER_ID),
+        censored
+    ));
+
+    appeal_id
+}
+
+#[test]
+fn censorship_appeal_accepted() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let stake = CensorshipAppealStake::get();
+        let _ = Balances::deposit_creating(&FIRST_MEMBER_ORIGIN, stake);
+
+        let channel_id = create_member_channel();
+        let censored = CensoredContent::Channel(channel_id);
+
+        // Cannot appeal channel which is not censored
+        assert_err!(
+            Content::appeal_censorship(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                censored.clone()
+            ),
+            Error::<Test>::ContentIsNotCensored
+        );
+
+        let group_id = curators::add_curator_to_new_group(FIRST_CURATOR_ID);
+        censor_channel(channel_id, group_id, true);
+
+        // Only channel owner can appeal
+        assert_err!(
+            Content::appeal_censorship(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                censored.clone()
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        let appeal_id = appeal_censorship(censored.clone());
+
+        let appeal = Content::censorship_appeal_by_id(appeal_id);
+        assert_eq!(
+            appeal,
+            CensorshipAppealRecord {
+                censored: censored.clone(),
+                appellant: FIRST_MEMBER_ORIGIN,
+                stake,
+                deadline: 1 + CensorshipAppealPeriod::get(),
+                status: CensorshipAppealStatus::Pending,
+            }
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::CensorshipAppealFiled(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                appeal_id,
+                appeal
+            ))
+        );
+
+        // Stake is reserved
+        assert_eq!(Balances::reserved_balance(FIRST_MEMBER_ORIGIN), stake);
+
+        // Censorship can only be appealed once
+        assert_err!(
+            Content::appeal_censorship(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                censored.clone()
+            ),
+            Error::<Test>::CensorshipAlreadyAppealed
+        );
+
+        // Only lead can resolve appeals
+        assert_err!(
+            Content::resolve_censorship_appeal(
+                Origin::signed(FIRST_CURATOR_ORIGIN),
+                appeal_id,
+                true,
+                vec![]
+            ),
+            Error::<Test>::LeadAuthFailed
+        );
+
+        assert_ok!(Content::resolve_censorship_appeal(
+            Origin::signed(LEAD_ORIGIN),
+            appeal_id,
+            true,
+            b"rationale".to_vec()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::CensorshipAppealResolved(
+                appeal_id,
+                CensorshipAppealStatus::Accepted,
+                b"rationale".to_vec()
+            ))
+        );
+
+        // Channel is uncensored and stake refunded
+        assert!(!Content::channel_by_id(channel_id).is_censored);
+        assert_eq!(Balances::free_balance(FIRST_MEMBER_ORIGIN), stake);
+        assert_eq!(Balances::reserved_balance(FIRST_MEMBER_ORIGIN), 0);
+
+        // Appeal is kept in history
+        assert_eq!(
+            Content::censorship_appeal_by_id(appeal_id).status,
+            CensorshipAppealStatus::Accepted
+        );
+        assert!(CensorshipAppealHistory::<Test>::contains_key(
+            &censored, appeal_id
+        ));
+
+        // Resolved appeal cannot be resolved again
+        assert_err!(
+            Content::resolve_censorship_appeal(Origin::root(), appeal_id, false, vec![]),
+            Error::<Test>::CensorshipAppealIsNotPending
+        );
+    })
+}
+
+#[test]
+fn censorship_appeal_rejected() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let stake = CensorshipAppealStake::get();
+        let _ = Balances::deposit_creating(&FIRST_MEMBER_ORIGIN, 2 * stake);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+        let censored = CensoredContent::Video(video_id);
+
+        let group_id = curators::add_curator_to_new_group(FIRST_CURATOR_ID);
+        censor_video(video_id, group_id, true);
+
+        let appeal_id = appeal_censorship(censored.clone());
+
+        // Proposals can resolve appeals
+        assert_ok!(Content::resolve_censorship_appeal(
+            Origin::root(),
+            appeal_id,
+            false,
+            vec![]
+        ));
+
+        // Video stays censored and stake is slashed
+        assert!(Content::video_by_id(video_id).is_censored);
+        assert_eq!(Balances::free_balance(FIRST_MEMBER_ORIGIN), stake);
+        assert_eq!(Balances::reserved_balance(FIRST_MEMBER_ORIGIN), 0);
+
+        // Upheld censorship cannot be appealed again
+        assert_err!(
+            Content::appeal_censorship(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                censored.clone()
+            ),
+            Error::<Test>::CensorshipAlreadyAppealed
+        );
+
+        // New censorship can be appealed
+        censor_video(video_id, group_id, false);
+        censor_video(video_id, group_id, true);
+
+        let second_appeal_id = appeal_censorship(censored.clone());
+
+        assert_eq!(
+            CensorshipAppealHistory::<Test>::iter_prefix(&censored).count(),
+            2
+        );
+
+        // Lifting censorship closes pending appeal and refunds the stake
+        censor_video(video_id, group_id, false);
+
+        assert_eq!(
+            Content::censorship_appeal_by_id(second_appeal_id).status,
+            CensorshipAppealStatus::Closed
+        );
+        assert_eq!(Balances::free_balance(FIRST_MEMBER_ORIGIN), stake);
+    })
+}
+
+#[test]
+fn censorship_appeal_expires() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let stake = CensorshipAppealStake::get();
+
+        let channel_id = create_member_channel();
+        let censored = CensoredContent::Channel(channel_id);
+
+        let group_id = curators::add_curator_to_new_group(FIRST_CURATOR_ID);
+        censor_channel(channel_id, group_id, true);
+
+        // Stake must be available
+        assert_err!(
+            Content::appeal_censorship(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                censored.clone()
+            ),
+            Error::<Test>::InsufficientBalanceForAppealStake
+        );
+
+        let _ = Balances::deposit_creating(&FIRST_MEMBER_ORIGIN, stake);
+
+        let appeal_id = appeal_censorship(censored);
+
+        let deadline = Content::censorship_appeal_by_id(appeal_id).deadline;
+
+        run_to_block(deadline - 1);
+        assert!(Content::channel_by_id(channel_id).is_censored);
+
+        // Unresolved appeal is granted at the deadline
+        run_to_block(deadline);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::CensorshipAppealResolved(
+                appeal_id,
+                CensorshipAppealStatus::Expired,
+                vec![]
+            ))
+        );
+
+        assert!(!Content::channel_by_id(channel_id).is_censored);
+        assert_eq!(Balances::free_balance(FIRST_MEMBER_ORIGIN), stake);
+        assert_eq!(Balances::reserved_balance(FIRST_MEMBER_ORIGIN), 0);
+    })
+}
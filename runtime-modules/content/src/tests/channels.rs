@@ -2,7 +2,9 @@
 
 use super::curators;
 use super::mock::*;
+use super::videos::{create_member_channel, create_member_video};
 use crate::*;
+use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok};
 
 #[test]
@@ -99,6 +101,8 @@ fn curator_owned_channels() {
                     series: vec![],
                     is_censored: false,
                     reward_account: None,
+                    assets: vec![],
+                    category: None,
                 },
                 ChannelCreationParameters {
                     assets: vec![],
@@ -179,6 +183,8 @@ fn member_owned_channels() {
                     series: vec![],
                     is_censored: false,
                     reward_account: None,
+                    assets: vec![],
+                    category: None,
                 },
                 ChannelCreationParameters {
                     assets: vec![],
@@ -213,6 +219,8 @@ fn member_owned_channels() {
                     series: vec![],
                     is_censored: false,
                     reward_account: None,
+                    assets: vec![],
+                    category: None,
                 },
                 ChannelCreationParameters {
                     assets: vec![],
@@ -246,6 +254,8 @@ fn member_owned_channels() {
                     series: vec![],
                     is_censored: false,
                     reward_account: None,
+                    assets: vec![],
+                    category: None,
                 },
                 ChannelUpdateParameters {
                     assets: None,
@@ -272,6 +282,270 @@ fn member_owned_channels() {
     })
 }
 
+#[test]
+fn dao_owned_channels() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        // DAO account must authenticate
+        assert_err!(
+            Content::create_channel(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Dao(FIRST_DAO_ID),
+                ChannelCreationParameters {
+                    assets: vec![],
+                    meta: vec![],
+                    reward_account: None,
+                }
+            ),
+            Error::<Test>::DaoAuthFailed
+        );
+
+        assert_err!(
+            Content::create_channel(
+                Origin::signed(FIRST_DAO_ORIGIN),
+                ContentActor::Dao(SECOND_DAO_ID),
+                ChannelCreationParameters {
+                    assets: vec![],
+                    meta: vec![],
+                    reward_account: None,
+                }
+            ),
+            Error::<Test>::DaoAuthFailed
+        );
+
+        let channel_id = Content::next_channel_id();
+
+        // DAO can create the channel
+        assert_ok!(Content::create_channel(
+            Origin::signed(FIRST_DAO_ORIGIN),
+            ContentActor::Dao(FIRST_DAO_ID),
+            ChannelCreationParameters {
+                assets: vec![],
+                meta: vec![],
+                reward_account: None,
+            }
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelCreated(
+                ContentActor::Dao(FIRST_DAO_ID),
+                channel_id,
+                ChannelRecord {
+                    owner: ChannelOwner::Dao(FIRST_DAO_ID),
+                    videos: vec![],
+                    playlists: vec![],
+                    series: vec![],
+                    is_censored: false,
+                    reward_account: None,
+                    assets: vec![],
+                    category: None,
+                },
+                ChannelCreationParameters {
+                    assets: vec![],
+                    meta: vec![],
+                    reward_account: None,
+                }
+            ))
+        );
+
+        // DAO can update its channel and publish videos in it
+        assert_ok!(Content::update_channel(
+            Origin::signed(FIRST_DAO_ORIGIN),
+            ContentActor::Dao(FIRST_DAO_ID),
+            channel_id,
+            ChannelUpdateParameters {
+                assets: None,
+                new_meta: Some(b"metablob".to_vec()),
+                reward_account: None,
+            }
+        ));
+
+        let video_id = Content::next_video_id();
+        assert_ok!(Content::create_video(
+            Origin::signed(FIRST_DAO_ORIGIN),
+            ContentActor::Dao(FIRST_DAO_ID),
+            channel_id,
+            VideoCreationParameters {
+                assets: vec![],
+                meta: vec![],
+                visibility: VideoVisibility::Public,
+            }
+        ));
+        assert_eq!(Content::video_by_id(video_id).in_channel, channel_id);
+
+        // Members cannot update DAO owned channels
+        assert_err!(
+            Content::update_channel(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                ChannelUpdateParameters {
+                    assets: None,
+                    new_meta: None,
+                    reward_account: None,
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // DAOs cannot censor channels
+        assert_err!(
+            Content::update_channel_censorship_status(
+                Origin::signed(FIRST_DAO_ORIGIN),
+                ContentActor::Dao(FIRST_DAO_ID),
+                channel_id,
+                true,
+                vec![]
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+    })
+}
+
+#[test]
+fn channel_deletion() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        // Channel playlist
+        let playlist_id = Content::next_playlist_id();
+        assert_ok!(Content::create_playlist(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            PlaylistCreationParameters {
+                videos: vec![video_id],
+                cross_channel: false,
+                meta: vec![],
+            }
+        ));
+
+        // Channel series with a new episode
+        let series_id = Content::next_series_id();
+        let episode_id = Content::next_video_id();
+        assert_ok!(Content::create_series(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            SeriesParameters {
+                assets: None,
+                seasons: Some(vec![Some(SeasonParameters {
+                    assets: None,
+                    episodes: Some(vec![Some(EpisodeParameters::NewVideo(
+                        VideoCreationParameters {
+                            assets: vec![],
+                            meta: vec![],
+                            visibility: VideoVisibility::Public,
+                        }
+                    ))]),
+                    meta: None,
+                })]),
+                meta: None,
+            }
+        ));
+
+        // Person credited in the channel video
+        let person_id = Content::next_person_id();
+        assert_ok!(Content::create_person(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            PersonActor::Member(FIRST_MEMBER_ID),
+            PersonCreationParameters {
+                assets: vec![],
+                meta: vec![],
+            }
+        ));
+        assert_ok!(Content::add_person_to_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            person_id
+        ));
+
+        // Channel video included in a playlist of another channel
+        let other_channel_id = Content::next_channel_id();
+        assert_ok!(Content::create_channel(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            ChannelCreationParameters {
+                assets: vec![],
+                meta: vec![],
+                reward_account: None,
+            }
+        ));
+
+        let other_playlist_id = Content::next_playlist_id();
+        assert_ok!(Content::create_playlist(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            other_channel_id,
+            PlaylistCreationParameters {
+                videos: vec![video_id],
+                cross_channel: true,
+                meta: vec![],
+            }
+        ));
+
+        // Only channel owner can delete the channel
+        assert_err!(
+            Content::delete_channel(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                vec![]
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::delete_channel(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            vec![]
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelDeleted(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id
+            ))
+        );
+
+        // Channel content is deleted
+        assert!(!ChannelById::<Test>::contains_key(channel_id));
+        assert!(!VideoById::<Test>::contains_key(video_id));
+        assert!(!VideoById::<Test>::contains_key(episode_id));
+        assert!(!PlaylistById::<Test>::contains_key(playlist_id));
+        assert!(!SeriesById::<Test>::contains_key(series_id));
+        assert!(!PersonInVideo::<Test>::contains_key(video_id, person_id));
+        assert!(!VideoOfPerson::<Test>::contains_key(person_id, video_id));
+
+        // Channel videos are removed from playlists of other channels
+        assert!(Content::playlist_by_id(other_playlist_id).videos.is_empty());
+        assert!(!VideoInPlaylist::<Test>::contains_key(
+            video_id,
+            other_playlist_id
+        ));
+
+        assert_err!(
+            Content::delete_channel(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                vec![]
+            ),
+            Error::<Test>::ChannelDoesNotExist
+        );
+    })
+}
+
 #[test]
 fn channel_censoring() {
     with_default_mock_builder(|| {
@@ -387,3 +661,280 @@ fn channel_censoring() {
         ));
     })
 }
+
+#[test]
+fn channel_ownership_transfer() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = Content::next_channel_id();
+        assert_ok!(Content::create_channel(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            ChannelCreationParameters {
+                assets: vec![],
+                meta: vec![],
+                reward_account: Some(FIRST_MEMBER_ORIGIN),
+            }
+        ));
+
+        let payment = 100;
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, payment);
+
+        let params = ChannelOwnershipTransferRequestParametersRecord {
+            channel_id,
+            new_owner: ChannelOwner::Member(SECOND_MEMBER_ID),
+            payment,
+            new_reward_account: Some(SECOND_MEMBER_ORIGIN),
+        };
+
+        // Requester must act as the requested new owner
+        assert_err!(
+            Content::request_channel_transfer(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                params.clone()
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        let request_id = Content::next_channel_transfer_request_id();
+        assert_ok!(Content::request_channel_transfer(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            params
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelOwnershipTransferRequested(
+                ContentActor::Member(SECOND_MEMBER_ID),
+                request_id,
+                ChannelOwnershipTransferRequestRecord {
+                    channel_id,
+                    new_owner: ChannelOwner::Member(SECOND_MEMBER_ID),
+                    payment,
+                    // Payment is taken from the account signing the request
+                    payer: SECOND_MEMBER_ORIGIN,
+                    new_reward_account: Some(SECOND_MEMBER_ORIGIN),
+                }
+            ))
+        );
+
+        // Payment is held in escrow
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), 0);
+        assert_eq!(
+            Balances::free_balance(Content::channel_ownership_payment_escrow_account()),
+            payment
+        );
+
+        // Only the current owner can accept
+        assert_err!(
+            Content::accept_channel_transfer(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                request_id
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::accept_channel_transfer(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            request_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelOwnershipTransferred(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                request_id
+            ))
+        );
+
+        // Ownership and reward account moved, payment released to previous owner
+        let channel = Content::channel_by_id(channel_id);
+        assert_eq!(channel.owner, ChannelOwner::Member(SECOND_MEMBER_ID));
+        assert_eq!(channel.reward_account, Some(SECOND_MEMBER_ORIGIN));
+        assert_eq!(Balances::free_balance(FIRST_MEMBER_ORIGIN), payment);
+        assert!(!ChannelOwnershipTransferRequestById::<Test>::contains_key(
+            request_id
+        ));
+    })
+}
+
+#[test]
+fn channel_ownership_transfer_request_can_be_withdrawn() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = Content::next_channel_id();
+        assert_ok!(Content::create_channel(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            ChannelCreationParameters {
+                assets: vec![],
+                meta: vec![],
+                reward_account: None,
+            }
+        ));
+
+        let payment = 100;
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, payment);
+
+        let request_id = Content::next_channel_transfer_request_id();
+        assert_ok!(Content::request_channel_transfer(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            ChannelOwnershipTransferRequestParametersRecord {
+                channel_id,
+                new_owner: ChannelOwner::Member(SECOND_MEMBER_ID),
+                payment,
+                new_reward_account: None,
+            }
+        ));
+
+        // Only the requester can withdraw the request
+        assert_err!(
+            Content::cancel_channel_transfer_request(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                request_id
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::cancel_channel_transfer_request(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            request_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelOwnershipTransferRequestWithdrawn(
+                ContentActor::Member(SECOND_MEMBER_ID),
+                request_id
+            ))
+        );
+
+        // Payment is refunded and the channel keeps its owner
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), payment);
+        assert_eq!(
+            Content::channel_by_id(channel_id).owner,
+            ChannelOwner::Member(FIRST_MEMBER_ID)
+        );
+
+        assert_err!(
+            Content::accept_channel_transfer(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                request_id
+            ),
+            Error::<Test>::ChannelOwnershipTransferRequestDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn channels_and_videos_imported_at_genesis() {
+    let channel_id = 5;
+    let video_id = 7;
+    let channel_category_id = 2;
+    let video_category_id = 3;
+    let video_subcategory_id = 4;
+
+    // Counters of the previous chain are not trusted
+    let channel_category = CategoryRecord {
+        parent_category_id: None,
+        depth: 0,
+        subcategories_count: 4,
+        items_count: 9,
+    };
+
+    let video_category = CategoryRecord {
+        parent_category_id: None,
+        depth: 0,
+        subcategories_count: 0,
+        items_count: 0,
+    };
+
+    let video_subcategory = CategoryRecord {
+        parent_category_id: Some(video_category_id),
+        depth: 1,
+        subcategories_count: 0,
+        items_count: 0,
+    };
+
+    let channel = ChannelRecord {
+        owner: ChannelOwner::Member(FIRST_MEMBER_ID),
+        videos: vec![video_id],
+        playlists: vec![1],
+        series: vec![1],
+        is_censored: false,
+        reward_account: None,
+        assets: vec![],
+        category: Some(channel_category_id),
+    };
+
+    let video = Video {
+        in_channel: channel_id,
+        in_series: Some(1),
+        is_censored: false,
+        assets: vec![],
+        category: Some(video_category_id),
+        visibility: VideoVisibility::Draft,
+        publish_at: Some(3),
+    };
+
+    ExtBuilder::default()
+        .channel_categories(vec![(channel_category_id, channel_category)])
+        .channels(vec![(channel_id, channel)])
+        .video_categories(vec![
+            (video_category_id, video_category),
+            (video_subcategory_id, video_subcategory),
+        ])
+        .videos(vec![(video_id, video)])
+        .build()
+        .execute_with(|| {
+            // Playlists and series are not imported, references to them are dropped
+            let channel = Content::channel_by_id(channel_id);
+            assert_eq!(channel.videos, vec![video_id]);
+            assert!(channel.playlists.is_empty() && channel.series.is_empty());
+            assert_eq!(Content::video_by_id(video_id).in_series, None);
+
+            // Category counters are recomputed from the imported records
+            let channel_category = Content::channel_category_by_id(channel_category_id);
+            assert_eq!(channel_category.items_count, 1);
+            assert_eq!(channel_category.subcategories_count, 0);
+
+            let video_category = Content::video_category_by_id(video_category_id);
+            assert_eq!(video_category.items_count, 1);
+            assert_eq!(video_category.subcategories_count, 1);
+
+            // Publishing blocks of the previous chain are dropped and the video is published
+            let video = Content::video_by_id(video_id);
+            assert_eq!(video.visibility, VideoVisibility::Public);
+            assert_eq!(video.publish_at, None);
+            assert!(!VideosByPublishBlock::<Test>::contains_key(3, video_id));
+
+            // Imported channel and its videos can be managed as usual
+            assert_ok!(Content::update_video(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                VideoUpdateParameters {
+                    assets: None,
+                    new_meta: Some(vec![]),
+                }
+            ));
+
+            assert_ok!(Content::delete_video(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id
+            ));
+        })
+}
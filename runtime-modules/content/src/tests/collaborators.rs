@@ -0,0 +1,256 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::create_member_channel;
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+
+fn permissions(
+    permissions: &[ChannelCollaboratorPermission],
+) -> BTreeSet<ChannelCollaboratorPermission> {
+    permissions.iter().copied().collect()
+}
+
+#[test]
+fn channel_collaborator_management() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let upload_videos = permissions(&[ChannelCollaboratorPermission::UploadVideos]);
+
+        // Only channel owner can add collaborators
+        assert_err!(
+            Content::set_channel_collaborator(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                SECOND_MEMBER_ID,
+                upload_videos.clone()
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Collaborator must be granted some permissions
+        assert_err!(
+            Content::set_channel_collaborator(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SECOND_MEMBER_ID,
+                BTreeSet::new()
+            ),
+            Error::<Test>::NoCollaboratorPermissions
+        );
+
+        // Owner cannot be a collaborator
+        assert_err!(
+            Content::set_channel_collaborator(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                FIRST_MEMBER_ID,
+                upload_videos.clone()
+            ),
+            Error::<Test>::ActorIsAlreadyChannelOwner
+        );
+
+        assert_ok!(Content::set_channel_collaborator(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            SECOND_MEMBER_ID,
+            upload_videos.clone()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelCollaboratorSet(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SECOND_MEMBER_ID,
+                upload_videos.clone()
+            ))
+        );
+
+        assert_eq!(
+            Content::channel_collaborators(channel_id, SECOND_MEMBER_ID),
+            upload_videos
+        );
+
+        assert_ok!(Content::remove_channel_collaborator(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            SECOND_MEMBER_ID
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelCollaboratorRemoved(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SECOND_MEMBER_ID
+            ))
+        );
+
+        assert!(!ChannelCollaborators::<Test>::contains_key(
+            channel_id,
+            SECOND_MEMBER_ID
+        ));
+
+        // Cannot remove member who is not a collaborator
+        assert_err!(
+            Content::remove_channel_collaborator(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SECOND_MEMBER_ID
+            ),
+            Error::<Test>::CollaboratorDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn channel_collaborator_permissions_are_scoped() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+
+        assert_ok!(Content::set_channel_collaborator(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            SECOND_MEMBER_ID,
+            permissions(&[ChannelCollaboratorPermission::UploadVideos])
+        ));
+
+        // Collaborator can upload videos
+        let video_id = Content::next_video_id();
+        assert_ok!(Content::create_video(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            channel_id,
+            VideoCreationParameters {
+                assets: vec![],
+                meta: vec![],
+                visibility: VideoVisibility::Public,
+            }
+        ));
+
+        // But cannot edit metadata
+        assert_err!(
+            Content::update_video(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                video_id,
+                VideoUpdateParameters {
+                    assets: None,
+                    new_meta: Some(vec![]),
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Nor manage playlists
+        assert_err!(
+            Content::create_playlist(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                PlaylistCreationParameters {
+                    videos: vec![video_id],
+                    cross_channel: false,
+                    meta: vec![],
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::set_channel_collaborator(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            SECOND_MEMBER_ID,
+            permissions(&[
+                ChannelCollaboratorPermission::EditMetadata,
+                ChannelCollaboratorPermission::ManagePlaylists,
+            ])
+        ));
+
+        assert_ok!(Content::update_channel(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            channel_id,
+            ChannelUpdateParameters {
+                assets: None,
+                new_meta: Some(vec![]),
+                reward_account: None,
+            }
+        ));
+
+        // Only owner can update the reward account
+        assert_err!(
+            Content::update_channel(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                ChannelUpdateParameters {
+                    assets: None,
+                    new_meta: None,
+                    reward_account: Some(Some(SECOND_MEMBER_ORIGIN)),
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::create_playlist(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            channel_id,
+            PlaylistCreationParameters {
+                videos: vec![video_id],
+                cross_channel: false,
+                meta: vec![],
+            }
+        ));
+
+        // Upload permission was revoked
+        assert_err!(
+            Content::delete_video(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                video_id
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Collaborators cannot delete the channel
+        assert_err!(
+            Content::delete_channel(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                vec![]
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Collaborators are removed with the channel
+        assert_ok!(Content::delete_channel(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            vec![]
+        ));
+
+        assert!(!ChannelCollaborators::<Test>::contains_key(
+            channel_id,
+            SECOND_MEMBER_ID
+        ));
+    })
+}
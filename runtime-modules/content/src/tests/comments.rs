@@ -0,0 +1,294 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video};
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+
+fn post_comment(video_id: VideoId, parent_comment_id: Option<u64>) -> u64 {
+    let comment_id = Content::next_comment_id();
+
+    assert_ok!(Content::post_comment(
+        Origin::signed(SECOND_MEMBER_ORIGIN),
+        SECOND_MEMBER_ID,
+        video_id,
+        parent_comment_id,
+        b"comment".to_vec()
+    ));
+
+    comment_id
+}
+
+#[test]
+fn video_reactions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        // Member must authenticate
+        assert_err!(
+            Content::like_video(Origin::signed(UNKNOWN_ORIGIN), SECOND_MEMBER_ID, video_id),
+            Error::<Test>::MemberAuthFailed
+        );
+
+        assert_ok!(Content::like_video(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            video_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoLiked(SECOND_MEMBER_ID, video_id))
+        );
+
+        // Each member can like a video once
+        assert_err!(
+            Content::like_video(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id
+            ),
+            Error::<Test>::VideoAlreadyLiked
+        );
+
+        assert_ok!(Content::like_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            FIRST_MEMBER_ID,
+            video_id
+        ));
+
+        assert_eq!(Content::likes_count_by_video(video_id), 2);
+
+        assert_ok!(Content::unlike_video(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            video_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoUnliked(SECOND_MEMBER_ID, video_id))
+        );
+
+        assert_eq!(Content::likes_count_by_video(video_id), 1);
+
+        // Cannot unlike video which is not liked
+        assert_err!(
+            Content::unlike_video(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id
+            ),
+            Error::<Test>::VideoNotLiked
+        );
+
+        // Likes are removed with the video
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id
+        ));
+
+        assert!(!VideoLikedBy::<Test>::contains_key(
+            video_id,
+            FIRST_MEMBER_ID
+        ));
+        assert_eq!(Content::likes_count_by_video(video_id), 0);
+    })
+}
+
+#[test]
+fn video_comments() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        let comment_id = post_comment(video_id, None);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::CommentPosted(
+                SECOND_MEMBER_ID,
+                video_id,
+                comment_id,
+                None,
+                b"comment".to_vec()
+            ))
+        );
+
+        let reply_id = post_comment(video_id, Some(comment_id));
+
+        assert_eq!(
+            Content::comment_by_id(video_id, reply_id),
+            Comment {
+                author: SECOND_MEMBER_ID,
+                parent_comment_id: Some(comment_id),
+                is_moderated: false,
+            }
+        );
+
+        // Cannot reply to comment in another thread
+        let other_video_id = create_member_video(channel_id);
+        assert_err!(
+            Content::post_comment(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                other_video_id,
+                Some(comment_id),
+                b"comment".to_vec()
+            ),
+            Error::<Test>::CommentDoesNotExist
+        );
+
+        // Comment text is validated
+        assert_err!(
+            Content::post_comment(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id,
+                None,
+                vec![]
+            ),
+            Error::<Test>::CommentTextTooShort
+        );
+        assert_err!(
+            Content::post_comment(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id,
+                None,
+                vec![0; 22]
+            ),
+            Error::<Test>::CommentTextTooLong
+        );
+
+        // Only channel owner can moderate comments
+        assert_err!(
+            Content::moderate_comment(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                video_id,
+                comment_id,
+                b"spam".to_vec()
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::moderate_comment(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            comment_id,
+            b"spam".to_vec()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::CommentModerated(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                comment_id,
+                b"spam".to_vec()
+            ))
+        );
+
+        assert!(Content::comment_by_id(video_id, comment_id).is_moderated);
+
+        assert_err!(
+            Content::moderate_comment(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                comment_id,
+                b"spam".to_vec()
+            ),
+            Error::<Test>::CommentAlreadyModerated
+        );
+
+        // Owner can disable comments
+        assert_ok!(Content::set_video_comments_status(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            false
+        ));
+
+        assert_err!(
+            Content::post_comment(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id,
+                None,
+                b"comment".to_vec()
+            ),
+            Error::<Test>::VideoCommentsDisabled
+        );
+
+        // And enable them again
+        assert_ok!(Content::set_video_comments_status(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            true
+        ));
+
+        post_comment(video_id, None);
+
+        // Comments are removed with the video
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id
+        ));
+
+        assert!(!CommentById::<Test>::contains_key(video_id, reply_id));
+    })
+}
+
+#[test]
+fn comment_constraints() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let text_constraint = InputValidationLengthConstraint::new(2, 10);
+        let moderation_rationale_constraint = InputValidationLengthConstraint::new(3, 30);
+
+        // Only the lead or root can set the constraints
+        assert_err!(
+            Content::set_comment_constraints(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                text_constraint,
+                moderation_rationale_constraint
+            ),
+            Error::<Test>::LeadAuthFailed
+        );
+
+        assert_ok!(Content::set_comment_constraints(
+            Origin::signed(LEAD_ORIGIN),
+            text_constraint,
+            moderation_rationale_constraint
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::CommentConstraintsSet(
+                text_constraint,
+                moderation_rationale_constraint
+            ))
+        );
+
+        assert_eq!(Content::comment_text_constraint(), text_constraint);
+        assert_eq!(
+            Content::comment_moderation_rationale_constraint(),
+            moderation_rationale_constraint
+        );
+    })
+}
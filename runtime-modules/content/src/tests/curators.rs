@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::mock::*;
+use super::videos::create_member_channel;
 use crate::*;
 use frame_support::{assert_err, assert_ok};
 
@@ -129,3 +130,106 @@ fn curator_group_management() {
         );
     })
 }
+
+#[test]
+fn curator_group_permissions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let curator_group_id = add_curator_to_new_group(FIRST_CURATOR_ID);
+
+        // By default group can perform all actions except setting featured videos
+        let group = Content::curator_group_by_id(curator_group_id);
+        assert_eq!(
+            *group.get_permissions(),
+            CuratorGroupPermission::default_permissions()
+        );
+        assert!(!group.has_permission(CuratorGroupPermission::SetFeaturedVideos));
+
+        let permissions: BTreeSet<_> = [
+            CuratorGroupPermission::CensorContent,
+            CuratorGroupPermission::SetFeaturedVideos,
+        ]
+        .iter()
+        .copied()
+        .collect();
+
+        // Only lead can set curator group permissions
+        assert_err!(
+            Content::set_curator_group_permissions(
+                Origin::signed(FIRST_CURATOR_ORIGIN),
+                curator_group_id,
+                permissions.clone()
+            ),
+            Error::<Test>::LeadAuthFailed
+        );
+
+        // Cannot set permissions of non existent group
+        assert_err!(
+            Content::set_curator_group_permissions(
+                Origin::signed(LEAD_ORIGIN),
+                curator_group_id + 1,
+                permissions.clone()
+            ),
+            Error::<Test>::CuratorGroupDoesNotExist
+        );
+
+        // Restrict group to moderation only
+        assert_ok!(Content::set_curator_group_permissions(
+            Origin::signed(LEAD_ORIGIN),
+            curator_group_id,
+            permissions.clone()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::CuratorGroupPermissionsSet(
+                curator_group_id,
+                permissions
+            ))
+        );
+
+        // Curator can no longer create channels
+        assert_err!(
+            Content::create_channel(
+                Origin::signed(FIRST_CURATOR_ORIGIN),
+                ContentActor::Curator(curator_group_id, FIRST_CURATOR_ID),
+                ChannelCreationParameters {
+                    assets: vec![],
+                    meta: vec![],
+                    reward_account: None,
+                }
+            ),
+            Error::<Test>::CuratorGroupLacksPermission
+        );
+
+        // Curator can no longer manage categories
+        assert_err!(
+            Content::create_video_category(
+                Origin::signed(FIRST_CURATOR_ORIGIN),
+                ContentActor::Curator(curator_group_id, FIRST_CURATOR_ID),
+                VideoCategoryCreationParameters::default()
+            ),
+            Error::<Test>::CuratorGroupLacksPermission
+        );
+
+        // Curator can still censor channels
+        let channel_id = create_member_channel();
+        assert_ok!(Content::update_channel_censorship_status(
+            Origin::signed(FIRST_CURATOR_ORIGIN),
+            ContentActor::Curator(curator_group_id, FIRST_CURATOR_ID),
+            channel_id,
+            true,
+            vec![]
+        ));
+
+        // Curator can now set featured videos
+        assert_ok!(Content::set_featured_videos(
+            Origin::signed(FIRST_CURATOR_ORIGIN),
+            ContentActor::Curator(curator_group_id, FIRST_CURATOR_ID),
+            None,
+            vec![]
+        ));
+    })
+}
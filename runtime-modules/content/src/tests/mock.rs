@@ -20,7 +20,9 @@ pub type CuratorId = <Test as ContentActorAuthenticator>::CuratorId;
 pub type CuratorGroupId = <Test as ContentActorAuthenticator>::CuratorGroupId;
 pub type MemberId = <Test as MembershipTypes>::MemberId;
 pub type ChannelId = <Test as StorageOwnership>::ChannelId;
-// pub type DAOId = <Test as StorageOwnership>::DAOId;
+pub type VideoId = <Test as Trait>::VideoId;
+pub type PersonId = <Test as Trait>::PersonId;
+pub type DAOId = <Test as StorageOwnership>::DAOId;
 
 /// Origins
 
@@ -31,6 +33,7 @@ pub const SECOND_CURATOR_ORIGIN: u64 = 3;
 
 pub const FIRST_MEMBER_ORIGIN: u64 = 4;
 pub const SECOND_MEMBER_ORIGIN: u64 = 5;
+pub const FIRST_DAO_ORIGIN: u64 = 6;
 pub const UNKNOWN_ORIGIN: u64 = 7777;
 
 // Members range from MemberId 1 to 10
@@ -47,6 +50,9 @@ pub const FIRST_CURATOR_GROUP_ID: CuratorGroupId = 1;
 pub const FIRST_MEMBER_ID: MemberId = 1;
 pub const SECOND_MEMBER_ID: MemberId = 2;
 
+pub const FIRST_DAO_ID: DAOId = 1;
+pub const SECOND_DAO_ID: DAOId = 2;
+
 impl_outer_origin! {
     pub enum Origin for Test {}
 }
@@ -139,6 +145,11 @@ impl GovernanceCurrency for Test {
     type Currency = balances::Module<Self>;
 }
 
+impl minting::Trait for Test {
+    type Currency = balances::Module<Self>;
+    type MintId = u64;
+}
+
 impl ContentActorAuthenticator for Test {
     type CuratorId = u64;
     type CuratorGroupId = u64;
@@ -164,6 +175,11 @@ impl ContentActorAuthenticator for Test {
     fn is_valid_curator_id(curator_id: &Self::CuratorId) -> bool {
         *curator_id == FIRST_CURATOR_ID || *curator_id == SECOND_CURATOR_ID
     }
+
+    fn is_dao_account(dao_id: &Self::DAOId, account_id: &Self::AccountId) -> bool {
+        let first_dao_account_id = ensure_signed(Origin::signed(FIRST_DAO_ORIGIN)).unwrap();
+        first_dao_account_id == *account_id && FIRST_DAO_ID == *dao_id
+    }
 }
 
 pub struct MockStorageSystem {}
@@ -191,17 +207,43 @@ impl StorageSystem<Test> for MockStorageSystem {
         Ok(())
     }
 
+    fn atomically_remove_all_content(
+        _owner: &StorageObjectOwner<Test>,
+        _content_ids: &[u64],
+    ) -> DispatchResult {
+        Ok(())
+    }
+
     fn can_remove_content(
         _owner: &StorageObjectOwner<Test>,
         _content_ids: &[u64],
     ) -> DispatchResult {
         Ok(())
     }
+
+    fn content_owners() -> Vec<(u64, StorageObjectOwner<Test>)> {
+        Vec::new()
+    }
 }
 
 parameter_types! {
     pub const MaxNumberOfCuratorsPerGroup: u32 = 10;
+    pub const MaxNumberOfVideosPerPlaylist: u32 = 5;
+    pub const MaxNumberOfFeaturedVideos: u32 = 2;
+    pub const MaxNumberOfVideosPerBatch: u32 = 3;
+    pub const MaxCategoryDepth: u32 = 1;
     pub const ChannelOwnershipPaymentEscrowId: [u8; 8] = *b"12345678";
+    pub const CensorshipAppealStake: u64 = 100;
+    pub const CensorshipAppealPeriod: u64 = 10;
+    pub const NftPaymentEscrowId: [u8; 8] = *b"87654321";
+    pub const ContentReportPoolId: [u8; 8] = *b"11223344";
+    pub const ContentReportStake: u64 = 20;
+    pub const ContentReportReward: u64 = 10;
+    pub const MaxCreatorRoyalty: Perbill = Perbill::from_percent(50);
+    pub const MinAuctionDuration: u64 = 5;
+    pub const MaxAuctionDuration: u64 = 20;
+    pub const MinSubscriptionPeriod: u64 = 3;
+    pub const MaxSubscriptionRenewalsPerBlock: u32 = 2;
 }
 
 impl Trait for Test {
@@ -211,6 +253,12 @@ impl Trait for Test {
     /// Channel Transfer Payments Escrow Account seed for ModuleId to compute deterministic AccountId
     type ChannelOwnershipPaymentEscrowId = ChannelOwnershipPaymentEscrowId;
 
+    /// Video NFT Payments Escrow Account seed for ModuleId to compute deterministic AccountId
+    type NftPaymentEscrowId = NftPaymentEscrowId;
+
+    /// Content Report Stake Pool Account seed for ModuleId to compute deterministic AccountId
+    type ContentReportPoolId = ContentReportPoolId;
+
     /// Type of identifier for Videos
     type VideoId = u64;
 
@@ -232,14 +280,66 @@ impl Trait for Test {
     /// Type of identifier for Channel transfer requests
     type ChannelOwnershipTransferRequestId = u64;
 
+    /// Type of identifier for censorship appeals
+    type CensorshipAppealId = u64;
+
+    /// Type of identifier for video comments
+    type CommentId = u64;
+
+    /// Type of identifier for content reports
+    type ContentReportId = u64;
+
+    /// Type of identifier for channel subscription tiers
+    type SubscriptionTierId = u64;
+
+    /// Stake reserved from the appellant when filing a censorship appeal
+    type CensorshipAppealStake = CensorshipAppealStake;
+
+    /// Number of blocks the lead has to resolve a censorship appeal before it is granted
+    type CensorshipAppealPeriod = CensorshipAppealPeriod;
+
+    /// Stake reserved from the reporter when reporting a channel or video
+    type ContentReportStake = ContentReportStake;
+
+    /// Reward paid from the report stake pool to the reporter when reported content is censored
+    type ContentReportReward = ContentReportReward;
+
     /// The maximum number of curators per group constraint
     type MaxNumberOfCuratorsPerGroup = MaxNumberOfCuratorsPerGroup;
 
+    /// The maximum number of videos per playlist constraint
+    type MaxNumberOfVideosPerPlaylist = MaxNumberOfVideosPerPlaylist;
+
+    /// The maximum number of featured videos per list constraint
+    type MaxNumberOfFeaturedVideos = MaxNumberOfFeaturedVideos;
+
+    /// The maximum number of videos created, updated or deleted in a single batch
+    type MaxNumberOfVideosPerBatch = MaxNumberOfVideosPerBatch;
+
+    /// The maximum depth of channel and video subcategories, top level categories have depth zero
+    type MaxCategoryDepth = MaxCategoryDepth;
+
+    /// The maximum share of video NFT secondary sales paid to the channel reward account
+    type MaxCreatorRoyalty = MaxCreatorRoyalty;
+
+    /// The minimum number of blocks a video NFT auction accepts bids for
+    type MinAuctionDuration = MinAuctionDuration;
+
+    /// The maximum number of blocks a video NFT auction accepts bids for
+    type MaxAuctionDuration = MaxAuctionDuration;
+
+    /// The minimum number of blocks paid for by a single channel subscription payment
+    type MinSubscriptionPeriod = MinSubscriptionPeriod;
+
+    /// The maximum number of channel subscriptions renewed or expired in a single block
+    type MaxSubscriptionRenewalsPerBlock = MaxSubscriptionRenewalsPerBlock;
+
     // Type that handles asset uploads to storage frame_system
     type StorageSystem = MockStorageSystem;
 }
 
 pub type System = frame_system::Module<Test>;
+pub type Balances = balances::Module<Test>;
 pub type Content = Module<Test>;
 // #[derive (Default)]
 pub struct ExtBuilder {
@@ -252,6 +352,18 @@ pub struct ExtBuilder {
     next_series_id: u64,
     next_channel_transfer_request_id: u64,
     next_curator_group_id: u64,
+    next_censorship_appeal_id: u64,
+    next_comment_id: u64,
+    next_content_report_id: u64,
+    next_subscription_tier_id: u64,
+    comment_text_constraint: InputValidationLengthConstraint,
+    comment_moderation_rationale_constraint: InputValidationLengthConstraint,
+    content_mint_capacity: u64,
+    curator_group_by_id: Vec<(CuratorGroupId, CuratorGroup<Test>)>,
+    channel_category_by_id: Vec<(u64, ChannelCategory<Test>)>,
+    channel_by_id: Vec<(ChannelId, Channel<Test>)>,
+    video_category_by_id: Vec<(u64, VideoCategory<Test>)>,
+    video_by_id: Vec<(VideoId, Video<ChannelId, u64, u64, u64, u64>)>,
 }
 
 impl Default for ExtBuilder {
@@ -266,11 +378,54 @@ impl Default for ExtBuilder {
             next_series_id: 1,
             next_channel_transfer_request_id: 1,
             next_curator_group_id: 1,
+            next_censorship_appeal_id: 1,
+            next_comment_id: 1,
+            next_content_report_id: 1,
+            next_subscription_tier_id: 1,
+            comment_text_constraint: InputValidationLengthConstraint::new(1, 20),
+            comment_moderation_rationale_constraint: InputValidationLengthConstraint::new(1, 20),
+            content_mint_capacity: 1000,
+            curator_group_by_id: vec![],
+            channel_category_by_id: vec![],
+            channel_by_id: vec![],
+            video_category_by_id: vec![],
+            video_by_id: vec![],
         }
     }
 }
 
 impl ExtBuilder {
+    pub fn channel_categories(
+        self,
+        channel_category_by_id: Vec<(u64, ChannelCategory<Test>)>,
+    ) -> Self {
+        Self {
+            channel_category_by_id,
+            ..self
+        }
+    }
+
+    pub fn channels(self, channel_by_id: Vec<(ChannelId, Channel<Test>)>) -> Self {
+        Self {
+            channel_by_id,
+            ..self
+        }
+    }
+
+    pub fn video_categories(self, video_category_by_id: Vec<(u64, VideoCategory<Test>)>) -> Self {
+        Self {
+            video_category_by_id,
+            ..self
+        }
+    }
+
+    pub fn videos(self, video_by_id: Vec<(VideoId, Video<ChannelId, u64, u64, u64, u64>)>) -> Self {
+        Self {
+            video_by_id,
+            ..self
+        }
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
         let mut t = frame_system::GenesisConfig::default()
             .build_storage::<Test>()
@@ -286,6 +441,18 @@ impl ExtBuilder {
             next_series_id: self.next_series_id,
             next_channel_transfer_request_id: self.next_channel_transfer_request_id,
             next_curator_group_id: self.next_curator_group_id,
+            next_censorship_appeal_id: self.next_censorship_appeal_id,
+            next_comment_id: self.next_comment_id,
+            next_content_report_id: self.next_content_report_id,
+            next_subscription_tier_id: self.next_subscription_tier_id,
+            comment_text_constraint: self.comment_text_constraint,
+            comment_moderation_rationale_constraint: self.comment_moderation_rationale_constraint,
+            content_mint_capacity: self.content_mint_capacity,
+            curator_group_by_id: self.curator_group_by_id,
+            channel_category_by_id: self.channel_category_by_id,
+            channel_by_id: self.channel_by_id,
+            video_category_by_id: self.video_category_by_id,
+            video_by_id: self.video_by_id,
         }
         .assimilate_storage(&mut t)
         .unwrap();
@@ -305,5 +472,6 @@ pub fn run_to_block(n: u64) {
         <System as OnFinalize<u64>>::on_finalize(System::block_number());
         System::set_block_number(System::block_number() + 1);
         <System as OnInitialize<u64>>::on_initialize(System::block_number());
+        <Content as OnInitialize<u64>>::on_initialize(System::block_number());
     }
 }
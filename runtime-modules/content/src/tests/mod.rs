@@ -1,6 +1,17 @@
 #![cfg(test)]
 
+mod categories;
+mod censorship_appeals;
 mod channels;
+mod collaborators;
+mod comments;
 mod curators;
 mod mock;
+mod nft;
+mod persons;
+mod playlists;
+mod reports;
+mod rewards;
+mod series;
+mod subscriptions;
 mod videos;
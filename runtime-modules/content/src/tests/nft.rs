@@ -0,0 +1,459 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video};
+use crate::*;
+use frame_support::traits::Currency;
+use frame_support::{assert_err, assert_ok};
+
+const REWARD_ACCOUNT: u64 = 100;
+const THIRD_MEMBER_ORIGIN: u64 = 8;
+const THIRD_MEMBER_ID: MemberId = 3;
+
+fn issue_nft(video_id: VideoId, creator_royalty: Option<Perbill>) {
+    assert_ok!(Content::issue_nft(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        ContentActor::Member(FIRST_MEMBER_ID),
+        video_id,
+        creator_royalty,
+        None
+    ));
+}
+
+fn set_reward_account(channel_id: ChannelId) {
+    assert_ok!(Content::update_channel(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        ContentActor::Member(FIRST_MEMBER_ID),
+        channel_id,
+        ChannelUpdateParameters {
+            assets: None,
+            new_meta: None,
+            reward_account: Some(Some(REWARD_ACCOUNT)),
+        }
+    ));
+}
+
+#[test]
+fn nft_issuance_and_transfer() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        // Only channel owner can issue NFT
+        assert_err!(
+            Content::issue_nft(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                video_id,
+                None,
+                None
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_err!(
+            Content::issue_nft(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                Some(Perbill::from_percent(60)),
+                None
+            ),
+            Error::<Test>::CreatorRoyaltyTooHigh
+        );
+
+        issue_nft(video_id, Some(Perbill::from_percent(10)));
+
+        let nft = Content::nft_by_video(video_id);
+        assert_eq!(
+            nft,
+            OwnedNftRecord {
+                owner: NftOwner::ChannelOwner,
+                creator_royalty: Some(Perbill::from_percent(10)),
+                transactional_status: NftTransactionalStatus::Idle,
+            }
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::NftIssued(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                nft
+            ))
+        );
+
+        // NFT can only be issued once
+        assert_err!(
+            Content::issue_nft(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                None,
+                None
+            ),
+            Error::<Test>::NftAlreadyIssued
+        );
+
+        assert_ok!(Content::transfer_nft(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            NftOwner::Member(SECOND_MEMBER_ID)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::NftTransferred(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                NftOwner::Member(SECOND_MEMBER_ID)
+            ))
+        );
+
+        // Channel owner no longer holds the NFT
+        assert_err!(
+            Content::transfer_nft(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                NftOwner::ChannelOwner
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Video cannot be removed while its NFT is held by a member
+        assert_err!(
+            Content::delete_video(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id
+            ),
+            Error::<Test>::VideoNftIsNotRemovable
+        );
+
+        assert_ok!(Content::transfer_nft(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            video_id,
+            NftOwner::ChannelOwner
+        ));
+
+        // NFT is burned with the video
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id
+        ));
+
+        assert!(!NftByVideo::<Test>::contains_key(video_id));
+    })
+}
+
+#[test]
+fn nft_fixed_price_sale() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        set_reward_account(channel_id);
+        issue_nft(video_id, Some(Perbill::from_percent(10)));
+
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, 100);
+        let _ = Balances::deposit_creating(&THIRD_MEMBER_ORIGIN, 200);
+
+        // NFT must be on sale
+        assert_err!(
+            Content::buy_nft(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id
+            ),
+            Error::<Test>::NftIsNotOnSale
+        );
+
+        assert_ok!(Content::sell_nft(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            100
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::NftSellOrderMade(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                100
+            ))
+        );
+
+        // NFT on sale cannot be transferred
+        assert_err!(
+            Content::transfer_nft(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                NftOwner::Member(SECOND_MEMBER_ID)
+            ),
+            Error::<Test>::NftIsOnSale
+        );
+
+        assert_ok!(Content::buy_nft(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            video_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::NftBought(SECOND_MEMBER_ID, video_id, 100))
+        );
+
+        // Primary sale is paid to the channel reward account in full
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 100);
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), 0);
+
+        let nft = Content::nft_by_video(video_id);
+        assert_eq!(nft.owner, NftOwner::Member(SECOND_MEMBER_ID));
+        assert_eq!(nft.transactional_status, NftTransactionalStatus::Idle);
+
+        assert_ok!(Content::sell_nft(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            ContentActor::Member(SECOND_MEMBER_ID),
+            video_id,
+            200
+        ));
+
+        assert_ok!(Content::buy_nft(
+            Origin::signed(THIRD_MEMBER_ORIGIN),
+            THIRD_MEMBER_ID,
+            video_id
+        ));
+
+        // Creator royalty of secondary sale is paid to the channel reward account
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 120);
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), 180);
+        assert_eq!(Balances::free_balance(THIRD_MEMBER_ORIGIN), 0);
+
+        assert_eq!(
+            Content::nft_by_video(video_id).owner,
+            NftOwner::Member(THIRD_MEMBER_ID)
+        );
+
+        // Sale can be canceled
+        assert_ok!(Content::sell_nft(
+            Origin::signed(THIRD_MEMBER_ORIGIN),
+            ContentActor::Member(THIRD_MEMBER_ID),
+            video_id,
+            300
+        ));
+
+        assert_ok!(Content::cancel_nft_sale(
+            Origin::signed(THIRD_MEMBER_ORIGIN),
+            ContentActor::Member(THIRD_MEMBER_ID),
+            video_id
+        ));
+
+        assert_eq!(
+            Content::nft_by_video(video_id).transactional_status,
+            NftTransactionalStatus::Idle
+        );
+    })
+}
+
+#[test]
+fn nft_english_auction() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        issue_nft(video_id, None);
+
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, 100);
+        let _ = Balances::deposit_creating(&THIRD_MEMBER_ORIGIN, 100);
+
+        assert_err!(
+            Content::start_english_auction(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                AuctionParams {
+                    starting_price: 10,
+                    min_bid_step: 5,
+                    duration: MinAuctionDuration::get() - 1,
+                }
+            ),
+            Error::<Test>::AuctionDurationTooShort
+        );
+
+        assert_ok!(Content::start_english_auction(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            AuctionParams {
+                starting_price: 10,
+                min_bid_step: 5,
+                duration: MinAuctionDuration::get(),
+            }
+        ));
+
+        let end = 1 + MinAuctionDuration::get();
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::EnglishAuctionStarted(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                EnglishAuctionRecord {
+                    starting_price: 10,
+                    min_bid_step: 5,
+                    end,
+                    seller_account: FIRST_MEMBER_ORIGIN,
+                    top_bid: None,
+                }
+            ))
+        );
+
+        // Bid must reach the starting price
+        assert_err!(
+            Content::make_bid(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id,
+                5
+            ),
+            Error::<Test>::BidTooLow
+        );
+
+        assert_ok!(Content::make_bid(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            video_id,
+            10
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::AuctionBidMade(SECOND_MEMBER_ID, video_id, 10))
+        );
+
+        // Bid is held in escrow
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), 90);
+
+        // Bid must exceed the top bid by the minimal step
+        assert_err!(
+            Content::make_bid(
+                Origin::signed(THIRD_MEMBER_ORIGIN),
+                THIRD_MEMBER_ID,
+                video_id,
+                12
+            ),
+            Error::<Test>::BidTooLow
+        );
+
+        assert_ok!(Content::make_bid(
+            Origin::signed(THIRD_MEMBER_ORIGIN),
+            THIRD_MEMBER_ID,
+            video_id,
+            15
+        ));
+
+        // Outbid bid is refunded
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), 100);
+        assert_eq!(Balances::free_balance(THIRD_MEMBER_ORIGIN), 85);
+
+        // Auction which received bids cannot be canceled
+        assert_err!(
+            Content::cancel_nft_sale(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id
+            ),
+            Error::<Test>::AuctionHasBids
+        );
+
+        run_to_block(end);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::EnglishAuctionSettled(
+                video_id,
+                Some(THIRD_MEMBER_ID)
+            ))
+        );
+
+        // Winning bid is released to the seller
+        assert_eq!(Balances::free_balance(FIRST_MEMBER_ORIGIN), 15);
+
+        let nft = Content::nft_by_video(video_id);
+        assert_eq!(nft.owner, NftOwner::Member(THIRD_MEMBER_ID));
+        assert_eq!(nft.transactional_status, NftTransactionalStatus::Idle);
+        assert!(!AuctionsByEndBlock::<Test>::contains_key(end, video_id));
+
+        // Auction is over
+        assert_err!(
+            Content::make_bid(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id,
+                20
+            ),
+            Error::<Test>::NftIsNotInAuction
+        );
+    })
+}
+
+#[test]
+fn unclaimed_payment_can_be_claimed() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let escrow_account = Content::nft_payment_escrow_account();
+
+        assert_err!(
+            Content::claim_payment(Origin::signed(SECOND_MEMBER_ORIGIN), escrow_account),
+            Error::<Test>::NoUnclaimedPayment
+        );
+
+        // Outbid refund which could not be transferred when due
+        let _ = Balances::deposit_creating(&escrow_account, 10);
+        UnclaimedPayments::<Test>::insert(escrow_account, SECOND_MEMBER_ORIGIN, 10);
+
+        assert_ok!(Content::claim_payment(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            escrow_account
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::PaymentClaimed(
+                escrow_account,
+                SECOND_MEMBER_ORIGIN,
+                10
+            ))
+        );
+
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), 10);
+        assert!(!UnclaimedPayments::<Test>::contains_key(
+            escrow_account,
+            SECOND_MEMBER_ORIGIN
+        ));
+
+        // Payment is claimed only once
+        assert_err!(
+            Content::claim_payment(Origin::signed(SECOND_MEMBER_ORIGIN), escrow_account),
+            Error::<Test>::NoUnclaimedPayment
+        );
+    })
+}
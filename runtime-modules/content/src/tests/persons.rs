@@ -0,0 +1,242 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video, upload_asset};
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+
+fn create_member_person() -> PersonId {
+    let person_id = Content::next_person_id();
+
+    assert_ok!(Content::create_person(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        PersonActor::Member(FIRST_MEMBER_ID),
+        PersonCreationParameters {
+            assets: vec![],
+            meta: vec![],
+        }
+    ));
+
+    person_id
+}
+
+#[test]
+fn person_management() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let person_id = create_member_person();
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::PersonCreated(
+                PersonActor::Member(FIRST_MEMBER_ID),
+                person_id,
+                vec![],
+                PersonCreationParameters {
+                    assets: vec![],
+                    meta: vec![],
+                }
+            ))
+        );
+
+        assert_eq!(
+            Content::person_by_id(person_id).controlled_by,
+            PersonController::Member(FIRST_MEMBER_ID)
+        );
+
+        // Other members and curators cannot update member controlled persons
+        assert_err!(
+            Content::update_person(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                PersonActor::Member(SECOND_MEMBER_ID),
+                person_id,
+                PersonUpdateParameters {
+                    assets: None,
+                    new_meta: None,
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_err!(
+            Content::update_person(
+                Origin::signed(FIRST_CURATOR_ORIGIN),
+                PersonActor::Curator(FIRST_CURATOR_ID),
+                person_id,
+                PersonUpdateParameters {
+                    assets: None,
+                    new_meta: None,
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::update_person(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            PersonActor::Member(FIRST_MEMBER_ID),
+            person_id,
+            PersonUpdateParameters {
+                assets: None,
+                new_meta: Some(b"metablob".to_vec()),
+            }
+        ));
+
+        // Any curator can manage curator controlled persons
+        let curator_person_id = Content::next_person_id();
+        assert_ok!(Content::create_person(
+            Origin::signed(FIRST_CURATOR_ORIGIN),
+            PersonActor::Curator(FIRST_CURATOR_ID),
+            PersonCreationParameters {
+                assets: vec![],
+                meta: vec![],
+            }
+        ));
+
+        assert_eq!(
+            Content::person_by_id(curator_person_id).controlled_by,
+            PersonController::Curators
+        );
+
+        assert_ok!(Content::delete_person(
+            Origin::signed(SECOND_CURATOR_ORIGIN),
+            PersonActor::Curator(SECOND_CURATOR_ID),
+            curator_person_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::PersonDeleted(
+                PersonActor::Curator(SECOND_CURATOR_ID),
+                curator_person_id
+            ))
+        );
+
+        assert!(!PersonById::<Test>::contains_key(curator_person_id));
+    })
+}
+
+#[test]
+fn video_credits() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+        let person_id = create_member_person();
+
+        // Only channel owner can credit persons in videos
+        assert_err!(
+            Content::add_person_to_video(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                video_id,
+                person_id
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::add_person_to_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            person_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::PersonAddedToVideo(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                person_id
+            ))
+        );
+
+        assert!(PersonInVideo::<Test>::contains_key(video_id, person_id));
+        assert!(VideoOfPerson::<Test>::contains_key(person_id, video_id));
+
+        assert_err!(
+            Content::add_person_to_video(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                person_id
+            ),
+            Error::<Test>::PersonAlreadyInVideo
+        );
+
+        assert_ok!(Content::remove_person_from_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            person_id
+        ));
+
+        assert!(!PersonInVideo::<Test>::contains_key(video_id, person_id));
+        assert!(!VideoOfPerson::<Test>::contains_key(person_id, video_id));
+
+        assert_err!(
+            Content::remove_person_from_video(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                person_id
+            ),
+            Error::<Test>::PersonNotInVideo
+        );
+
+        // Credits are removed with the video and the person
+        assert_ok!(Content::add_person_to_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            person_id
+        ));
+
+        assert_ok!(Content::delete_person(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            PersonActor::Member(FIRST_MEMBER_ID),
+            person_id
+        ));
+
+        assert!(!PersonInVideo::<Test>::contains_key(video_id, person_id));
+        assert!(!VideoOfPerson::<Test>::contains_key(person_id, video_id));
+    })
+}
+
+#[test]
+fn person_assets_are_tracked() {
+    with_default_mock_builder(|| {
+        let person_id = Content::next_person_id();
+
+        assert_ok!(Content::create_person(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            PersonActor::Member(FIRST_MEMBER_ID),
+            PersonCreationParameters {
+                assets: vec![upload_asset(1)],
+                meta: vec![],
+            }
+        ));
+        assert_eq!(Content::person_by_id(person_id).assets, vec![1]);
+
+        assert_ok!(Content::update_person(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            PersonActor::Member(FIRST_MEMBER_ID),
+            person_id,
+            PersonUpdateParameters {
+                assets: Some(vec![upload_asset(2)]),
+                new_meta: None,
+            }
+        ));
+        assert_eq!(Content::person_by_id(person_id).assets, vec![1, 2]);
+
+        assert_ok!(Content::delete_person(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            PersonActor::Member(FIRST_MEMBER_ID),
+            person_id
+        ));
+        assert!(!PersonById::<Test>::contains_key(person_id));
+    })
+}
@@ -0,0 +1,198 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video};
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+
+#[test]
+fn member_can_manage_playlists() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let first_video_id = create_member_video(channel_id);
+        let second_video_id = create_member_video(channel_id);
+
+        let playlist_id = Content::next_playlist_id();
+        assert_ok!(Content::create_playlist(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            PlaylistCreationParameters {
+                videos: vec![second_video_id, first_video_id],
+                cross_channel: false,
+                meta: b"metablob".to_vec(),
+            }
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::PlaylistCreated(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                playlist_id,
+                PlaylistCreationParameters {
+                    videos: vec![second_video_id, first_video_id],
+                    cross_channel: false,
+                    meta: b"metablob".to_vec(),
+                }
+            ))
+        );
+
+        // Playlist keeps video order and is added to the channel
+        let playlist = Content::playlist_by_id(playlist_id);
+        assert_eq!(playlist.in_channel, channel_id);
+        assert_eq!(playlist.videos, vec![second_video_id, first_video_id]);
+        assert_eq!(
+            Content::channel_by_id(channel_id).playlists,
+            vec![playlist_id]
+        );
+
+        // Member cannot update a playlist in a channel they do not own
+        assert_err!(
+            Content::update_playlist(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                playlist_id,
+                PlaylistUpdateParameters {
+                    videos: None,
+                    new_meta: None,
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Owner can reorder videos
+        assert_ok!(Content::update_playlist(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            playlist_id,
+            PlaylistUpdateParameters {
+                videos: Some(vec![first_video_id, second_video_id]),
+                new_meta: None,
+            }
+        ));
+
+        assert_eq!(
+            Content::playlist_by_id(playlist_id).videos,
+            vec![first_video_id, second_video_id]
+        );
+
+        // Deleting a video removes it from the playlist
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            first_video_id
+        ));
+
+        assert_eq!(
+            Content::playlist_by_id(playlist_id).videos,
+            vec![second_video_id]
+        );
+
+        assert_ok!(Content::delete_playlist(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            playlist_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::PlaylistDeleted(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                playlist_id
+            ))
+        );
+
+        assert!(!PlaylistById::<Test>::contains_key(playlist_id));
+        assert!(Content::channel_by_id(channel_id).playlists.is_empty());
+    })
+}
+
+#[test]
+fn playlist_videos_are_validated() {
+    with_default_mock_builder(|| {
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        let other_channel_id = create_member_channel();
+        let other_video_id = create_member_video(other_channel_id);
+
+        // Videos must exist
+        assert_err!(
+            Content::create_playlist(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                PlaylistCreationParameters {
+                    videos: vec![Content::next_video_id()],
+                    cross_channel: false,
+                    meta: vec![],
+                }
+            ),
+            Error::<Test>::VideoDoesNotExist
+        );
+
+        // Videos cannot be repeated
+        assert_err!(
+            Content::create_playlist(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                PlaylistCreationParameters {
+                    videos: vec![video_id, video_id],
+                    cross_channel: false,
+                    meta: vec![],
+                }
+            ),
+            Error::<Test>::DuplicateVideoInPlaylist
+        );
+
+        // Videos of other channels require a cross channel playlist
+        assert_err!(
+            Content::create_playlist(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                PlaylistCreationParameters {
+                    videos: vec![video_id, other_video_id],
+                    cross_channel: false,
+                    meta: vec![],
+                }
+            ),
+            Error::<Test>::VideoNotInPlaylistChannel
+        );
+
+        assert_ok!(Content::create_playlist(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            PlaylistCreationParameters {
+                videos: vec![video_id, other_video_id],
+                cross_channel: true,
+                meta: vec![],
+            }
+        ));
+
+        // Playlist length is limited
+        let videos: Vec<VideoId> = (0..=MaxNumberOfVideosPerPlaylist::get())
+            .map(|_| create_member_video(channel_id))
+            .collect();
+
+        assert_err!(
+            Content::create_playlist(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                PlaylistCreationParameters {
+                    videos,
+                    cross_channel: false,
+                    meta: vec![],
+                }
+            ),
+            Error::<Test>::VideosPerPlaylistLimitReached
+        );
+    })
+}
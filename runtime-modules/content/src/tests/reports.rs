@@ -0,0 +1,255 @@
+#![cfg(test)]
+
+use super::curators;
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video};
+use crate::*;
+use frame_support::traits::Currency;
+use frame_support::{assert_err, assert_ok};
+
+const THIRD_MEMBER_ORIGIN: u64 = 8;
+const THIRD_MEMBER_ID: u64 = 3;
+
+fn report_video(origin: u64, member_id: MemberId, video_id: VideoId) -> u64 {
+    let report_id = Content::next_content_report_id();
+
+    assert_ok!(Content::report_video(
+        Origin::signed(origin),
+        member_id,
+        video_id,
+        ReportReason::Spam
+    ));
+
+    report_id
+}
+
+#[test]
+fn content_reports_upheld() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let stake = ContentReportStake::get();
+        let reward = ContentReportReward::get();
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, stake);
+        let _ = Balances::deposit_creating(&THIRD_MEMBER_ORIGIN, stake);
+
+        // Pool can only cover one of the rewards
+        let _ = Balances::deposit_creating(&Content::content_report_pool_account(), reward);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+        let content = CensoredContent::Video(video_id);
+
+        let first_report_id = report_video(SECOND_MEMBER_ORIGIN, SECOND_MEMBER_ID, video_id);
+
+        let report = Content::content_report_by_id(first_report_id);
+        assert_eq!(
+            report,
+            ContentReportRecord {
+                content: content.clone(),
+                reporter: SECOND_MEMBER_ID,
+                reporter_account: SECOND_MEMBER_ORIGIN,
+                stake,
+                reason: ReportReason::Spam,
+                status: ContentReportStatus::Pending,
+            }
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ContentReported(
+                SECOND_MEMBER_ID,
+                first_report_id,
+                report
+            ))
+        );
+
+        // Stake is reserved
+        assert_eq!(Balances::reserved_balance(SECOND_MEMBER_ORIGIN), stake);
+
+        // Member can have only one pending report of the content
+        assert_err!(
+            Content::report_video(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id,
+                ReportReason::Other
+            ),
+            Error::<Test>::ContentAlreadyReported
+        );
+
+        let second_report_id = report_video(THIRD_MEMBER_ORIGIN, THIRD_MEMBER_ID, video_id);
+
+        let group_id = curators::add_curator_to_new_group(FIRST_CURATOR_ID);
+
+        // Members cannot resolve reports
+        assert_err!(
+            Content::resolve_content_reports(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                content.clone(),
+                true,
+                vec![]
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::resolve_content_reports(
+            Origin::signed(FIRST_CURATOR_ORIGIN),
+            ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+            content.clone(),
+            true,
+            b"rationale".to_vec()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoCensorshipStatusUpdated(
+                ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+                video_id,
+                true,
+                b"rationale".to_vec()
+            ))
+        );
+
+        assert!(Content::video_by_id(video_id).is_censored);
+
+        // Stakes are refunded and reporters rewarded as far as the pool allows
+        assert_eq!(Balances::reserved_balance(SECOND_MEMBER_ORIGIN), 0);
+        assert_eq!(Balances::reserved_balance(THIRD_MEMBER_ORIGIN), 0);
+        assert_eq!(
+            Balances::free_balance(SECOND_MEMBER_ORIGIN)
+                + Balances::free_balance(THIRD_MEMBER_ORIGIN),
+            2 * stake + reward
+        );
+        assert_eq!(
+            Balances::free_balance(Content::content_report_pool_account()),
+            0
+        );
+
+        for report_id in [first_report_id, second_report_id].iter() {
+            assert_eq!(
+                Content::content_report_by_id(report_id).status,
+                ContentReportStatus::Upheld
+            );
+        }
+        assert!(PendingContentReports::<Test>::iter_prefix(&content)
+            .next()
+            .is_none());
+
+        // Censored content cannot be reported
+        assert_err!(
+            Content::report_video(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                video_id,
+                ReportReason::Spam
+            ),
+            Error::<Test>::ContentIsAlreadyCensored
+        );
+    })
+}
+
+#[test]
+fn content_reports_dismissed() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let stake = ContentReportStake::get();
+
+        let channel_id = create_member_channel();
+        let content = CensoredContent::Channel(channel_id);
+
+        // Stake must be covered by the reporter balance
+        assert_err!(
+            Content::report_channel(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                channel_id,
+                ReportReason::Harassment
+            ),
+            Error::<Test>::InsufficientBalanceForReportStake
+        );
+
+        let group_id = curators::add_curator_to_new_group(FIRST_CURATOR_ID);
+
+        // Only reported content can be triaged
+        assert_err!(
+            Content::resolve_content_reports(
+                Origin::signed(FIRST_CURATOR_ORIGIN),
+                ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+                content.clone(),
+                false,
+                vec![]
+            ),
+            Error::<Test>::NoPendingContentReports
+        );
+
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, stake);
+
+        let report_id = Content::next_content_report_id();
+        assert_ok!(Content::report_channel(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            channel_id,
+            ReportReason::Harassment
+        ));
+
+        assert_ok!(Content::resolve_content_reports(
+            Origin::signed(FIRST_CURATOR_ORIGIN),
+            ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+            content.clone(),
+            false,
+            b"rationale".to_vec()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ContentReportsDismissed(
+                ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+                content,
+                b"rationale".to_vec()
+            ))
+        );
+
+        // Channel is not censored and the stake is slashed into the pool
+        assert!(!Content::channel_by_id(channel_id).is_censored);
+        assert_eq!(
+            Content::content_report_by_id(report_id).status,
+            ContentReportStatus::Dismissed
+        );
+        assert_eq!(Balances::total_balance(&SECOND_MEMBER_ORIGIN), 0);
+        assert_eq!(
+            Balances::free_balance(Content::content_report_pool_account()),
+            stake
+        );
+    })
+}
+
+#[test]
+fn content_reports_closed_on_deletion() {
+    with_default_mock_builder(|| {
+        let stake = ContentReportStake::get();
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, stake);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        let report_id = report_video(SECOND_MEMBER_ORIGIN, SECOND_MEMBER_ID, video_id);
+
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id
+        ));
+
+        // Stake is refunded
+        assert_eq!(
+            Content::content_report_by_id(report_id).status,
+            ContentReportStatus::Closed
+        );
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), stake);
+    })
+}
@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::create_member_channel;
+use crate::*;
+use frame_support::{assert_err, assert_ok, StorageValue};
+use sp_runtime::traits::Hash;
+
+const REWARD_ACCOUNT: u64 = 100;
+
+fn hash_of<E: Encode>(item: &E) -> <Test as frame_system::Trait>::Hash {
+    <Test as frame_system::Trait>::Hashing::hash_of(item)
+}
+
+// Commit to the earnings of two channels, returning the proof of the first one
+fn commit_rewards(
+    claim: &ChannelRewardClaim<Test>,
+    other_claim: &ChannelRewardClaim<Test>,
+) -> Vec<ProofElement<Test>> {
+    let commitment = hash_of(&(hash_of(claim), hash_of(other_claim)));
+
+    assert_ok!(Content::update_rewards_commitment(
+        Origin::signed(LEAD_ORIGIN),
+        commitment
+    ));
+
+    vec![ProofElementRecord {
+        hash: hash_of(other_claim),
+        side: Side::Right,
+    }]
+}
+
+#[test]
+fn channel_reward_claims() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+
+        let mut claim = ChannelRewardClaimRecord {
+            channel_id,
+            cumulative_earnings: 100,
+        };
+        let other_claim = ChannelRewardClaimRecord {
+            channel_id: channel_id + 1,
+            cumulative_earnings: 50,
+        };
+
+        // Only lead can post rewards commitment
+        assert_err!(
+            Content::update_rewards_commitment(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                hash_of(&claim)
+            ),
+            Error::<Test>::LeadAuthFailed
+        );
+
+        let proof = commit_rewards(&claim, &other_claim);
+
+        // Reward account must be set
+        assert_err!(
+            Content::claim_channel_reward(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                proof.clone(),
+                claim.clone()
+            ),
+            Error::<Test>::ChannelRewardAccountNotSet
+        );
+
+        assert_ok!(Content::update_channel(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            ChannelUpdateParameters {
+                assets: None,
+                new_meta: None,
+                reward_account: Some(Some(REWARD_ACCOUNT)),
+            }
+        ));
+
+        // Only channel owner can claim
+        assert_err!(
+            Content::claim_channel_reward(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                proof.clone(),
+                claim.clone()
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Claim must match the commitment
+        assert_err!(
+            Content::claim_channel_reward(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                proof.clone(),
+                ChannelRewardClaimRecord {
+                    channel_id,
+                    cumulative_earnings: 200,
+                }
+            ),
+            Error::<Test>::RewardClaimProofInvalid
+        );
+
+        assert_ok!(Content::claim_channel_reward(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            proof.clone(),
+            claim.clone()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelRewardClaimed(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                100
+            ))
+        );
+
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 100);
+        assert_eq!(Content::claimed_rewards_by_channel(channel_id), 100);
+
+        // Same earnings cannot be claimed twice
+        assert_err!(
+            Content::claim_channel_reward(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                proof,
+                claim.clone()
+            ),
+            Error::<Test>::RewardAlreadyClaimed
+        );
+
+        // Only the earnings accrued since the last claim are paid
+        claim.cumulative_earnings = 250;
+        let proof = commit_rewards(&claim, &other_claim);
+
+        assert_ok!(Content::claim_channel_reward(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            proof,
+            claim.clone()
+        ));
+
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 250);
+    })
+}
+
+#[test]
+fn channel_reward_claims_are_limited_by_mint_capacity() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+
+        assert_ok!(Content::update_channel(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            ChannelUpdateParameters {
+                assets: None,
+                new_meta: None,
+                reward_account: Some(Some(REWARD_ACCOUNT)),
+            }
+        ));
+
+        let claim = ChannelRewardClaimRecord {
+            channel_id,
+            cumulative_earnings: 100,
+        };
+        let proof = commit_rewards(&claim, &claim);
+
+        // Only root can set content mint capacity
+        assert!(Content::set_content_mint_capacity(Origin::signed(LEAD_ORIGIN), 50).is_err());
+
+        assert_ok!(Content::set_content_mint_capacity(Origin::root(), 50));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ContentMintCapacityChanged(
+                Content::content_mint().unwrap(),
+                50
+            ))
+        );
+
+        assert_err!(
+            Content::claim_channel_reward(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                proof.clone(),
+                claim.clone()
+            ),
+            Error::<Test>::InsufficientContentMintCapacity
+        );
+
+        assert_ok!(Content::set_content_mint_capacity(Origin::root(), 100));
+
+        assert_ok!(Content::claim_channel_reward(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            proof,
+            claim
+        ));
+
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 100);
+    })
+}
+
+#[test]
+fn content_mint_must_be_created() {
+    with_default_mock_builder(|| {
+        ContentMint::<Test>::kill();
+
+        assert_err!(
+            Content::set_content_mint_capacity(Origin::root(), 50),
+            Error::<Test>::ContentMintNotFound
+        );
+    })
+}
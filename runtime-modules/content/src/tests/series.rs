@@ -0,0 +1,286 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::{create_member_channel, create_member_video, upload_asset};
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+
+fn new_episode() -> Option<EpisodeParameters<VideoId, ContentParameters<Test>>> {
+    Some(EpisodeParameters::NewVideo(VideoCreationParameters {
+        assets: vec![],
+        meta: vec![],
+        visibility: VideoVisibility::Public,
+    }))
+}
+
+fn season_with(
+    episodes: Vec<Option<EpisodeParameters<VideoId, ContentParameters<Test>>>>,
+) -> Option<SeasonParameters<VideoId, ContentParameters<Test>>> {
+    Some(SeasonParameters {
+        assets: None,
+        episodes: Some(episodes),
+        meta: None,
+    })
+}
+
+#[test]
+fn member_can_manage_series() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        let series_id = Content::next_series_id();
+        let new_video_id = Content::next_video_id();
+
+        let params = SeriesParameters {
+            assets: None,
+            seasons: Some(vec![season_with(vec![
+                Some(EpisodeParameters::ExistingVideo(video_id)),
+                new_episode(),
+            ])]),
+            meta: Some(b"metablob".to_vec()),
+        };
+
+        assert_ok!(Content::create_series(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            params.clone()
+        ));
+
+        let series = Content::series_by_id(series_id);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::SeriesCreated(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                series_id,
+                vec![],
+                params,
+                series.clone()
+            ))
+        );
+
+        // Episodes are created inline or referenced, and marked as part of the series
+        assert_eq!(series.in_channel, channel_id);
+        assert_eq!(series.seasons[0].episodes, vec![video_id, new_video_id]);
+        assert_eq!(Content::video_by_id(video_id).in_series, Some(series_id));
+        assert_eq!(
+            Content::video_by_id(new_video_id).in_series,
+            Some(series_id)
+        );
+        assert_eq!(
+            Content::channel_by_id(channel_id).videos,
+            vec![video_id, new_video_id]
+        );
+        assert_eq!(Content::channel_by_id(channel_id).series, vec![series_id]);
+
+        // Episodes cannot be deleted
+        assert_err!(
+            Content::delete_video(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id
+            ),
+            Error::<Test>::VideoInSeason
+        );
+
+        // Keep the first episode, truncate the rest and add a new season
+        assert_ok!(Content::update_series(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            series_id,
+            SeriesParameters {
+                assets: None,
+                seasons: Some(vec![season_with(vec![None]), season_with(vec![])]),
+                meta: None,
+            }
+        ));
+
+        let series = Content::series_by_id(series_id);
+        assert_eq!(series.seasons.len(), 2);
+        assert_eq!(series.seasons[0].episodes, vec![video_id]);
+        assert_eq!(Content::video_by_id(new_video_id).in_series, None);
+
+        // Released episodes can be deleted
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            new_video_id
+        ));
+
+        assert_ok!(Content::delete_series(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            series_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::SeriesDeleted(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                series_id
+            ))
+        );
+
+        assert!(!SeriesById::<Test>::contains_key(series_id));
+        assert!(Content::channel_by_id(channel_id).series.is_empty());
+        assert_eq!(Content::video_by_id(video_id).in_series, None);
+    })
+}
+
+#[test]
+fn series_assets_are_tracked() {
+    with_default_mock_builder(|| {
+        let channel_id = create_member_channel();
+
+        let series_id = Content::next_series_id();
+        let episode_video_id = Content::next_video_id();
+
+        assert_ok!(Content::create_series(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            SeriesParameters {
+                assets: Some(vec![upload_asset(1)]),
+                seasons: Some(vec![Some(SeasonParameters {
+                    assets: Some(vec![upload_asset(2)]),
+                    episodes: Some(vec![Some(EpisodeParameters::NewVideo(
+                        VideoCreationParameters {
+                            assets: vec![upload_asset(3)],
+                            meta: vec![],
+                            visibility: VideoVisibility::Public,
+                        }
+                    ))]),
+                    meta: None,
+                })]),
+                meta: None,
+            }
+        ));
+
+        // Episode assets are tracked by the episode video
+        assert_eq!(Content::series_by_id(series_id).assets, vec![1, 2]);
+        assert_eq!(Content::video_by_id(episode_video_id).assets, vec![3]);
+
+        assert_ok!(Content::update_series(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            series_id,
+            SeriesParameters {
+                assets: Some(vec![upload_asset(4)]),
+                seasons: None,
+                meta: None,
+            }
+        ));
+        assert_eq!(Content::series_by_id(series_id).assets, vec![1, 2, 4]);
+    })
+}
+
+#[test]
+fn series_episodes_are_validated() {
+    with_default_mock_builder(|| {
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        let other_channel_id = create_member_channel();
+        let other_video_id = create_member_video(other_channel_id);
+
+        // New seasons must be provided
+        assert_err!(
+            Content::create_series(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SeriesParameters {
+                    assets: None,
+                    seasons: Some(vec![None]),
+                    meta: None,
+                }
+            ),
+            Error::<Test>::SeasonParametersMissing
+        );
+
+        // New episodes must be provided
+        assert_err!(
+            Content::create_series(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SeriesParameters {
+                    assets: None,
+                    seasons: Some(vec![season_with(vec![None])]),
+                    meta: None,
+                }
+            ),
+            Error::<Test>::EpisodeParametersMissing
+        );
+
+        // Episodes must belong to the series channel
+        assert_err!(
+            Content::create_series(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SeriesParameters {
+                    assets: None,
+                    seasons: Some(vec![season_with(vec![Some(
+                        EpisodeParameters::ExistingVideo(other_video_id)
+                    )])]),
+                    meta: None,
+                }
+            ),
+            Error::<Test>::VideoNotInSeriesChannel
+        );
+
+        // Episodes cannot be repeated
+        assert_err!(
+            Content::create_series(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SeriesParameters {
+                    assets: None,
+                    seasons: Some(vec![
+                        season_with(vec![Some(EpisodeParameters::ExistingVideo(video_id))]),
+                        season_with(vec![Some(EpisodeParameters::ExistingVideo(video_id))]),
+                    ]),
+                    meta: None,
+                }
+            ),
+            Error::<Test>::DuplicateEpisodeInSeries
+        );
+
+        assert_ok!(Content::create_series(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            SeriesParameters {
+                assets: None,
+                seasons: Some(vec![season_with(vec![Some(
+                    EpisodeParameters::ExistingVideo(video_id)
+                )])]),
+                meta: None,
+            }
+        ));
+
+        // Episodes cannot be shared between series
+        assert_err!(
+            Content::create_series(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SeriesParameters {
+                    assets: None,
+                    seasons: Some(vec![season_with(vec![Some(
+                        EpisodeParameters::ExistingVideo(video_id)
+                    )])]),
+                    meta: None,
+                }
+            ),
+            Error::<Test>::VideoAlreadyInSeries
+        );
+    })
+}
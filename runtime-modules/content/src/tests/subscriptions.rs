@@ -0,0 +1,312 @@
+#![cfg(test)]
+
+use super::mock::*;
+use super::videos::create_member_channel;
+use crate::*;
+use frame_support::traits::Currency;
+use frame_support::{assert_err, assert_ok};
+
+const REWARD_ACCOUNT: u64 = 100;
+const PRICE: u64 = 10;
+const PERIOD: u64 = 5;
+
+// Create member channel paying to the reward account, with a single subscription tier
+fn create_channel_with_subscription_tier() -> (ChannelId, u64) {
+    let channel_id = create_member_channel();
+
+    assert_ok!(Content::update_channel(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        ContentActor::Member(FIRST_MEMBER_ID),
+        channel_id,
+        ChannelUpdateParameters {
+            assets: None,
+            new_meta: None,
+            reward_account: Some(Some(REWARD_ACCOUNT)),
+        }
+    ));
+
+    let tier_id = Content::next_subscription_tier_id();
+    let tier = SubscriptionTierRecord {
+        price: PRICE,
+        period: PERIOD,
+    };
+
+    assert_ok!(Content::create_subscription_tier(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        ContentActor::Member(FIRST_MEMBER_ID),
+        channel_id,
+        tier.clone()
+    ));
+
+    assert_eq!(
+        System::events().last().unwrap().event,
+        MetaEvent::content(RawEvent::SubscriptionTierCreated(
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            tier_id,
+            tier
+        ))
+    );
+
+    (channel_id, tier_id)
+}
+
+#[test]
+fn channel_subscription_renewals() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let (channel_id, tier_id) = create_channel_with_subscription_tier();
+
+        // Only channel owner can create tiers
+        assert_err!(
+            Content::create_subscription_tier(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                SubscriptionTierRecord {
+                    price: PRICE,
+                    period: PERIOD,
+                }
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Tiers must be paid
+        assert_err!(
+            Content::create_subscription_tier(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SubscriptionTierRecord {
+                    price: 0,
+                    period: PERIOD,
+                }
+            ),
+            Error::<Test>::SubscriptionPriceIsZero
+        );
+
+        // Tiers cannot be renewed more often than the minimum period
+        assert_err!(
+            Content::create_subscription_tier(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                SubscriptionTierRecord {
+                    price: PRICE,
+                    period: MinSubscriptionPeriod::get() - 1,
+                }
+            ),
+            Error::<Test>::SubscriptionPeriodTooShort
+        );
+
+        // First period must be covered by the subscriber balance
+        assert_err!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                channel_id,
+                tier_id
+            ),
+            Error::<Test>::InsufficientBalanceForSubscription
+        );
+
+        // Balance covers two periods
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, 2 * PRICE);
+
+        assert_ok!(Content::subscribe_to_channel(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            channel_id,
+            tier_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelSubscribed(
+                SECOND_MEMBER_ID,
+                channel_id,
+                SubscriptionRecord {
+                    tier_id,
+                    payer: SECOND_MEMBER_ORIGIN,
+                    paid_until: 1 + PERIOD,
+                    auto_renew: true,
+                }
+            ))
+        );
+
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), PRICE);
+        assert!(Content::has_active_subscription(
+            &SECOND_MEMBER_ID,
+            &channel_id
+        ));
+
+        assert_err!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                channel_id,
+                tier_id
+            ),
+            Error::<Test>::AlreadySubscribedToChannel
+        );
+
+        // Subscription is renewed at the end of the paid period
+        run_to_block(1 + PERIOD);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelSubscriptionRenewed(
+                SECOND_MEMBER_ID,
+                channel_id,
+                1 + 2 * PERIOD
+            ))
+        );
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 2 * PRICE);
+        assert!(Content::has_active_subscription(
+            &SECOND_MEMBER_ID,
+            &channel_id
+        ));
+
+        // Subscription expires when the next period cannot be paid
+        run_to_block(1 + 2 * PERIOD);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelSubscriptionExpired(
+                SECOND_MEMBER_ID,
+                channel_id
+            ))
+        );
+        assert!(!Content::has_active_subscription(
+            &SECOND_MEMBER_ID,
+            &channel_id
+        ));
+    })
+}
+
+#[test]
+fn canceled_channel_subscription_is_not_renewed() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let (channel_id, tier_id) = create_channel_with_subscription_tier();
+
+        let _ = Balances::deposit_creating(&SECOND_MEMBER_ORIGIN, 2 * PRICE);
+
+        assert_ok!(Content::subscribe_to_channel(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            channel_id,
+            tier_id
+        ));
+
+        assert_ok!(Content::cancel_channel_subscription(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            channel_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelSubscriptionCanceled(
+                SECOND_MEMBER_ID,
+                channel_id
+            ))
+        );
+
+        // Subscription stays active until the end of the paid period
+        run_to_block(PERIOD);
+        assert!(Content::has_active_subscription(
+            &SECOND_MEMBER_ID,
+            &channel_id
+        ));
+
+        run_to_block(1 + PERIOD);
+        assert!(!Content::has_active_subscription(
+            &SECOND_MEMBER_ID,
+            &channel_id
+        ));
+        assert_eq!(Balances::free_balance(SECOND_MEMBER_ORIGIN), PRICE);
+
+        // Removed tiers cannot be subscribed to
+        assert_ok!(Content::remove_subscription_tier(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            tier_id
+        ));
+
+        assert_err!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                channel_id,
+                tier_id
+            ),
+            Error::<Test>::SubscriptionTierDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn channel_subscription_renewals_are_limited_per_block() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let (channel_id, tier_id) = create_channel_with_subscription_tier();
+
+        // One more subscription than renewed in a single block
+        let subscribers: Vec<(u64, MemberId)> = (0..MaxSubscriptionRenewalsPerBlock::get() as u64
+            + 1)
+            .map(|index| (20 + index, 2 + index))
+            .collect();
+
+        for (account_id, member_id) in subscribers.iter() {
+            let _ = Balances::deposit_creating(account_id, 2 * PRICE);
+
+            assert_ok!(Content::subscribe_to_channel(
+                Origin::signed(*account_id),
+                *member_id,
+                channel_id,
+                tier_id
+            ));
+        }
+
+        run_to_block(1 + PERIOD);
+
+        assert_eq!(
+            Balances::free_balance(REWARD_ACCOUNT),
+            (subscribers.len() as u64 + MaxSubscriptionRenewalsPerBlock::get() as u64) * PRICE
+        );
+        assert_eq!(
+            SubscriptionsByRenewalBlock::<Test>::iter_prefix(1 + PERIOD).count(),
+            1
+        );
+
+        // Subscription with the postponed renewal stays active
+        assert!(subscribers
+            .iter()
+            .all(|(_, member_id)| Content::has_active_subscription(member_id, &channel_id)));
+
+        // Postponed renewal is processed in the next block, paying from the renewal block
+        run_to_block(2 + PERIOD);
+
+        assert_eq!(
+            Balances::free_balance(REWARD_ACCOUNT),
+            2 * subscribers.len() as u64 * PRICE
+        );
+        assert_eq!(
+            SubscriptionsByRenewalBlock::<Test>::iter_prefix(1 + PERIOD).count(),
+            0
+        );
+        assert_eq!(
+            SubscriptionsByRenewalBlock::<Test>::iter_prefix(1 + 2 * PERIOD).count(),
+            subscribers.len()
+        );
+        assert_eq!(Content::next_subscription_renewal_block(), Some(3 + PERIOD));
+    })
+}
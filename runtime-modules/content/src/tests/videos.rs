@@ -5,7 +5,7 @@ use super::mock::*;
 use crate::*;
 use frame_support::{assert_err, assert_ok};
 
-fn create_member_channel() -> ChannelId {
+pub fn create_member_channel() -> ChannelId {
     let channel_id = Content::next_channel_id();
 
     // Member can create the channel
@@ -22,6 +22,23 @@ fn create_member_channel() -> ChannelId {
     channel_id
 }
 
+pub fn create_member_video(channel_id: ChannelId) -> VideoId {
+    let video_id = Content::next_video_id();
+
+    assert_ok!(Content::create_video(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        ContentActor::Member(FIRST_MEMBER_ID),
+        channel_id,
+        VideoCreationParameters {
+            assets: vec![],
+            meta: vec![],
+            visibility: VideoVisibility::Public,
+        }
+    ));
+
+    video_id
+}
+
 #[test]
 fn member_can_create_videos() {
     with_default_mock_builder(|| {
@@ -37,6 +54,7 @@ fn member_can_create_videos() {
             VideoCreationParameters {
                 assets: vec![NewAsset::Urls(vec![b"https://somewhere.com/".to_vec()])],
                 meta: b"metablob".to_vec(),
+                visibility: VideoVisibility::Public,
             }
         ));
 
@@ -49,6 +67,7 @@ fn member_can_create_videos() {
                 VideoCreationParameters {
                     assets: vec![NewAsset::Urls(vec![b"https://somewhere.com/".to_vec()])],
                     meta: b"metablob".to_vec(),
+                    visibility: VideoVisibility::Public,
                 }
             ))
         );
@@ -93,6 +112,7 @@ fn member_can_create_videos() {
                 VideoCreationParameters {
                     assets: vec![],
                     meta: vec![],
+                    visibility: VideoVisibility::Public,
                 }
             ),
             Error::<Test>::ActorNotAuthorized
@@ -139,6 +159,127 @@ fn member_can_create_videos() {
     })
 }
 
+pub fn upload_asset(content_id: u64) -> NewAsset<ContentParameters<Test>> {
+    NewAsset::Upload(ContentParametersRecord {
+        content_id,
+        type_id: 1,
+        size: 1,
+        ipfs_content_id: vec![],
+        content_hash: Default::default(),
+        chunks_merkle_root: vec![],
+    })
+}
+
+#[test]
+fn video_assets_are_tracked() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+        let channel_id = create_member_channel();
+
+        let video_id = Content::next_video_id();
+        assert_ok!(Content::create_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            VideoCreationParameters {
+                assets: vec![
+                    upload_asset(1),
+                    NewAsset::Urls(vec![b"https://somewhere.com/".to_vec()])
+                ],
+                meta: vec![],
+                visibility: VideoVisibility::Public,
+            }
+        ));
+
+        assert_ok!(Content::update_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            VideoUpdateParameters {
+                assets: Some(vec![upload_asset(2), upload_asset(3)]),
+                new_meta: None,
+            }
+        ));
+
+        // Uploaded assets are tracked on the video
+        assert_eq!(Content::video_by_id(video_id).assets, vec![1, 2, 3]);
+
+        // Only tracked video assets can be removed
+        assert_err!(
+            Content::remove_video_assets(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                vec![2, 4]
+            ),
+            Error::<Test>::VideoAssetNotFound
+        );
+
+        // Member cannot remove assets of a video in a channel they do not own
+        assert_err!(
+            Content::remove_video_assets(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                video_id,
+                vec![2]
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::remove_video_assets(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            vec![2]
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoAssetsRemoved(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                vec![2]
+            ))
+        );
+
+        assert_eq!(Content::video_by_id(video_id).assets, vec![1, 3]);
+
+        // Channel assets are tracked separately
+        assert_ok!(Content::update_channel(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            ChannelUpdateParameters {
+                assets: Some(vec![upload_asset(4)]),
+                new_meta: None,
+                reward_account: None,
+            }
+        ));
+
+        assert_eq!(Content::channel_by_id(channel_id).assets, vec![4]);
+
+        assert_err!(
+            Content::remove_channel_assets(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                vec![1]
+            ),
+            Error::<Test>::ChannelAssetNotFound
+        );
+
+        assert_ok!(Content::remove_channel_assets(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            vec![4]
+        ));
+
+        assert!(Content::channel_by_id(channel_id).assets.is_empty());
+    })
+}
+
 #[test]
 fn curators_can_censor_videos() {
     with_default_mock_builder(|| {
@@ -154,6 +295,7 @@ fn curators_can_censor_videos() {
             VideoCreationParameters {
                 assets: vec![NewAsset::Urls(vec![b"https://somewhere.com/".to_vec()])],
                 meta: b"metablob".to_vec(),
+                visibility: VideoVisibility::Public,
             }
         ));
 
@@ -227,28 +369,467 @@ fn featured_videos() {
         // Run to block one to see emitted events
         run_to_block(1);
 
+        let channel_id = create_member_channel();
+        let first_video_id = create_member_video(channel_id);
+        let second_video_id = create_member_video(channel_id);
+
         // Lead can update curator owned channels
         assert_ok!(Content::set_featured_videos(
             Origin::signed(LEAD_ORIGIN),
             ContentActor::Lead,
-            vec![1, 2, 3]
+            None,
+            vec![first_video_id, second_video_id]
         ));
 
         assert_eq!(
             System::events().last().unwrap().event,
             MetaEvent::content(RawEvent::FeaturedVideosSet(
                 ContentActor::Lead,
-                vec![1, 2, 3]
+                None,
+                vec![first_video_id, second_video_id]
             ))
         );
 
+        assert_eq!(
+            Content::featured_videos(None),
+            vec![first_video_id, second_video_id]
+        );
+
+        assert_err!(
+            Content::set_featured_videos(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                None,
+                vec![first_video_id]
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        // Featured videos must exist
+        assert_err!(
+            Content::set_featured_videos(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                None,
+                vec![second_video_id + 1]
+            ),
+            Error::<Test>::VideoDoesNotExist
+        );
+
+        // Featured video lists are limited
+        assert_err!(
+            Content::set_featured_videos(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                None,
+                vec![first_video_id, second_video_id, first_video_id]
+            ),
+            Error::<Test>::FeaturedVideosLimitExceeded
+        );
+
+        // Category must exist
         assert_err!(
             Content::set_featured_videos(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                Some(Content::next_video_category_id()),
+                vec![first_video_id]
+            ),
+            Error::<Test>::CategoryDoesNotExist
+        );
+
+        let category_id = Content::next_video_category_id();
+        assert_ok!(Content::create_video_category(
+            Origin::signed(LEAD_ORIGIN),
+            ContentActor::Lead,
+            VideoCategoryCreationParameters::default()
+        ));
+
+        assert_ok!(Content::set_featured_videos(
+            Origin::signed(LEAD_ORIGIN),
+            ContentActor::Lead,
+            Some(category_id),
+            vec![first_video_id]
+        ));
+
+        assert_eq!(
+            Content::featured_videos(Some(category_id)),
+            vec![first_video_id]
+        );
+
+        // Censored video is removed from all featured lists
+        let group_id = curators::add_curator_to_new_group(FIRST_CURATOR_ID);
+        assert_ok!(Content::update_video_censorship_status(
+            Origin::signed(FIRST_CURATOR_ORIGIN),
+            ContentActor::Curator(group_id, FIRST_CURATOR_ID),
+            first_video_id,
+            true,
+            vec![]
+        ));
+
+        assert_eq!(Content::featured_videos(None), vec![second_video_id]);
+        assert!(Content::featured_videos(Some(category_id)).is_empty());
+
+        // Censored video cannot be featured
+        assert_err!(
+            Content::set_featured_videos(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                None,
+                vec![first_video_id]
+            ),
+            Error::<Test>::CensoredVideoCannotBeFeatured
+        );
+
+        // Deleted video is removed from all featured lists
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            second_video_id
+        ));
+
+        assert!(Content::featured_videos(None).is_empty());
+        assert!(!FeaturedVideoLists::<Test>::contains_key(
+            second_video_id,
+            None::<u64>
+        ));
+    })
+}
+
+#[test]
+fn only_public_videos_can_be_featured() {
+    with_default_mock_builder(|| {
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        assert_ok!(Content::set_featured_videos(
+            Origin::signed(LEAD_ORIGIN),
+            ContentActor::Lead,
+            None,
+            vec![video_id]
+        ));
+
+        // Video made non-public is removed from all featured lists
+        assert_ok!(Content::set_video_visibility(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            VideoVisibility::Unlisted,
+            None
+        ));
+
+        assert!(Content::featured_videos(None).is_empty());
+        assert!(!FeaturedVideoLists::<Test>::contains_key(
+            video_id,
+            None::<u64>
+        ));
+
+        // Non-public video cannot be featured
+        assert_err!(
+            Content::set_featured_videos(
+                Origin::signed(LEAD_ORIGIN),
+                ContentActor::Lead,
+                None,
+                vec![video_id]
+            ),
+            Error::<Test>::NonPublicVideoCannotBeFeatured
+        );
+    })
+}
+
+#[test]
+fn scheduled_video_publishing() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+
+        let video_id = Content::next_video_id();
+        assert_ok!(Content::create_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            VideoCreationParameters {
+                assets: vec![],
+                meta: vec![],
+                visibility: VideoVisibility::Draft,
+            }
+        ));
+
+        assert_eq!(
+            Content::video_by_id(video_id).visibility,
+            VideoVisibility::Draft
+        );
+
+        // Public video cannot be scheduled
+        assert_err!(
+            Content::set_video_visibility(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                VideoVisibility::Public,
+                Some(10)
+            ),
+            Error::<Test>::VideoIsAlreadyPublic
+        );
+
+        // Publish block must be in the future
+        assert_err!(
+            Content::set_video_visibility(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                VideoVisibility::Draft,
+                Some(1)
+            ),
+            Error::<Test>::VideoPublishBlockNotInFuture
+        );
+
+        // Only channel owner can change visibility
+        assert_err!(
+            Content::set_video_visibility(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                video_id,
+                VideoVisibility::Unlisted,
+                Some(10)
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+
+        assert_ok!(Content::set_video_visibility(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            VideoVisibility::Unlisted,
+            Some(10)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoVisibilitySet(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_id,
+                VideoVisibility::Unlisted,
+                Some(10)
+            ))
+        );
+
+        // Rescheduling replaces the previous schedule
+        assert_ok!(Content::set_video_visibility(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            VideoVisibility::Unlisted,
+            Some(5)
+        ));
+
+        assert!(!VideosByPublishBlock::<Test>::contains_key(10, video_id));
+
+        run_to_block(4);
+        assert_eq!(
+            Content::video_by_id(video_id).visibility,
+            VideoVisibility::Unlisted
+        );
+
+        run_to_block(5);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoPublished(video_id))
+        );
+
+        let video = Content::video_by_id(video_id);
+        assert_eq!(video.visibility, VideoVisibility::Public);
+        assert_eq!(video.publish_at, None);
+        assert!(!VideosByPublishBlock::<Test>::contains_key(5, video_id));
+    })
+}
+
+#[test]
+fn scheduled_video_deletion() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let video_id = create_member_video(channel_id);
+
+        assert_ok!(Content::set_video_visibility(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id,
+            VideoVisibility::Draft,
+            Some(10)
+        ));
+
+        assert_ok!(Content::delete_video(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            video_id
+        ));
+
+        // Deleted video is no longer scheduled
+        assert!(!VideosByPublishBlock::<Test>::contains_key(10, video_id));
+    })
+}
+
+fn video_creation_parameters() -> VideoCreationParameters<ContentParameters<Test>> {
+    VideoCreationParameters {
+        assets: vec![NewAsset::Urls(vec![b"https://somewhere.com/".to_vec()])],
+        meta: vec![],
+        visibility: VideoVisibility::Public,
+    }
+}
+
+#[test]
+fn batch_video_operations() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let channel_id = create_member_channel();
+        let other_channel_id = create_member_channel();
+        let other_video_id = create_member_video(other_channel_id);
+
+        // Batch must not be empty
+        assert_err!(
+            Content::create_videos(
                 Origin::signed(FIRST_MEMBER_ORIGIN),
                 ContentActor::Member(FIRST_MEMBER_ID),
-                vec![1, 2, 3]
+                channel_id,
+                vec![]
+            ),
+            Error::<Test>::InvalidVideoBatchSize
+        );
+
+        // Batch must not exceed the maximum size
+        assert_err!(
+            Content::create_videos(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                vec![video_creation_parameters(); 4]
+            ),
+            Error::<Test>::InvalidVideoBatchSize
+        );
+
+        // Only channel owner and collaborators can create videos
+        assert_err!(
+            Content::create_videos(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                ContentActor::Member(SECOND_MEMBER_ID),
+                channel_id,
+                vec![video_creation_parameters()]
             ),
             Error::<Test>::ActorNotAuthorized
         );
+
+        let first_video_id = Content::next_video_id();
+        assert_ok!(Content::create_videos(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            vec![video_creation_parameters(); 3]
+        ));
+
+        let video_ids: Vec<VideoId> = (first_video_id..first_video_id + 3).collect();
+        assert_eq!(Content::channel_by_id(channel_id).videos, video_ids);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoCreated(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                first_video_id + 2,
+                video_creation_parameters()
+            ))
+        );
+
+        let update = VideoUpdateParameters {
+            assets: None,
+            new_meta: Some(b"new meta".to_vec()),
+        };
+
+        // Videos of other channels cannot be updated in the batch
+        assert_err!(
+            Content::update_videos(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                vec![
+                    (video_ids[0], update.clone()),
+                    (other_video_id, update.clone())
+                ]
+            ),
+            Error::<Test>::VideoNotInChannel
+        );
+
+        // Each video can be included only once
+        assert_err!(
+            Content::update_videos(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                vec![
+                    (video_ids[0], update.clone()),
+                    (video_ids[0], update.clone())
+                ]
+            ),
+            Error::<Test>::DuplicateVideoInBatch
+        );
+
+        assert_ok!(Content::update_videos(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            vec![
+                (video_ids[0], update.clone()),
+                (video_ids[1], update.clone())
+            ]
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoUpdated(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_ids[1],
+                update
+            ))
+        );
+
+        // Nothing is deleted if any of the videos cannot be deleted
+        assert_err!(
+            Content::delete_videos(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                ContentActor::Member(FIRST_MEMBER_ID),
+                channel_id,
+                vec![video_ids[0], other_video_id]
+            ),
+            Error::<Test>::VideoNotInChannel
+        );
+        assert!(VideoById::<Test>::contains_key(video_ids[0]));
+
+        assert_ok!(Content::delete_videos(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            ContentActor::Member(FIRST_MEMBER_ID),
+            channel_id,
+            vec![video_ids[0], video_ids[2]]
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::VideoDeleted(
+                ContentActor::Member(FIRST_MEMBER_ID),
+                video_ids[2]
+            ))
+        );
+
+        assert!(!VideoById::<Test>::contains_key(video_ids[0]));
+        assert!(!VideoById::<Test>::contains_key(video_ids[2]));
+        assert_eq!(
+            Content::channel_by_id(channel_id).videos,
+            vec![video_ids[1]]
+        );
     })
 }
@@ -0,0 +1,489 @@
+//! # DAO module
+//! Minimal on-chain collective of members.
+//!
+//! ## Overview
+//!
+//! A DAO is a set of members and an approval threshold. Any member can propose a call,
+//! other members approve it, and once the number of approvals reaches the threshold
+//! the call is dispatched as a signed origin of the DAO account.
+//!
+//! The DAO account is derived from the module id and the DAO id, so other modules
+//! can recognize calls made on behalf of a DAO (eg.: the content directory allows DAOs
+//! to own channels, the data directory lets them upload content using their own voucher).
+//!
+//! ## Supported extrinsics
+//!
+//! - [create_dao](./struct.Module.html#method.create_dao) - creates a new DAO.
+//! - [update_dao](./struct.Module.html#method.update_dao) - updates DAO members and threshold,
+//! can only be called by the DAO itself.
+//! - [propose](./struct.Module.html#method.propose) - proposes a call on behalf of the DAO.
+//! - [approve](./struct.Module.html#method.approve) - approves a pending DAO proposal.
+//! - [cancel_proposal](./struct.Module.html#method.cancel_proposal) - cancels a pending
+//! DAO proposal by its proposer.
+//!
+//! The weight of a proposed call is stored with the proposal: `propose` is weighed including
+//! the call weight, `approve` requires a weight limit covering it, as the call may be dispatched.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Codec, Decode, Encode};
+use frame_support::dispatch::{DispatchResult, Dispatchable};
+use frame_support::traits::Get;
+use frame_support::weights::{GetDispatchInfo, Weight};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, Parameter, StorageDoubleMap,
+};
+use frame_system::{ensure_signed, RawOrigin};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::traits::{BaseArithmetic, One};
+use sp_runtime::traits::{AccountIdConversion, MaybeSerialize, Member};
+use sp_runtime::ModuleId;
+use sp_std::boxed::Box;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
+
+use common::origin::ActorOriginValidator;
+use common::{MembershipTypes, StorageOwnership};
+
+/// Member id type alias
+pub type MemberId<T> = <T as MembershipTypes>::MemberId;
+
+/// DAO id type alias
+pub type DAOId<T> = <T as StorageOwnership>::DAOId;
+
+/// DAO module configuration trait.
+pub trait Trait: frame_system::Trait + MembershipTypes + StorageOwnership {
+    /// DAO module event type.
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// DAO proposal id representation.
+    type DaoProposalId: Parameter
+        + Member
+        + BaseArithmetic
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerialize
+        + Ord
+        + PartialEq;
+
+    /// Calls a DAO can dispatch once its members approve them.
+    /// Can be instantiated by external module Call enum members.
+    type DaoCall: Parameter + Dispatchable<Origin = Self::Origin> + GetDispatchInfo;
+
+    /// Validates member id and origin combination.
+    type MemberOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
+
+    /// Module id used to derive DAO accounts.
+    type DaoModuleId: Get<ModuleId>;
+
+    /// Maximum number of members in a DAO.
+    type MaxMembersPerDao: Get<u32>;
+}
+
+/// DAO: a set of members and the number of approvals required to dispatch a call.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct DaoRecord<MemberId: Ord> {
+    /// DAO members
+    pub members: BTreeSet<MemberId>,
+
+    /// Number of member approvals required to dispatch a proposed call
+    pub threshold: u32,
+}
+
+impl<MemberId: Ord> DaoRecord<MemberId> {
+    /// Checks whether given member belongs to the DAO.
+    pub fn is_member(&self, member_id: &MemberId) -> bool {
+        self.members.contains(member_id)
+    }
+
+    /// Checks whether given approvals are enough to dispatch a proposed call.
+    /// Approvals of members no longer in the DAO are not counted.
+    pub fn is_approved(&self, approvals: &BTreeSet<MemberId>) -> bool {
+        approvals.intersection(&self.members).count() as u32 >= self.threshold
+    }
+}
+
+/// Pending DAO proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct DaoProposal<MemberId: Ord> {
+    /// Member who proposed the call
+    pub proposer: MemberId,
+
+    /// Encoded call to dispatch on behalf of the DAO
+    pub call: Vec<u8>,
+
+    /// Members who approved the call
+    pub approvals: BTreeSet<MemberId>,
+
+    /// Weight of the call, charged to the member whose approval dispatches it
+    pub weight: Weight,
+}
+
+decl_error! {
+    /// DAO module predefined errors
+    pub enum Error for Module<T: Trait> {
+        /// DAO with given id does not exist
+        DaoDoesNotExist,
+
+        /// DAO proposal with given id does not exist
+        ProposalDoesNotExist,
+
+        /// Member authentication failed
+        MemberAuthFailed,
+
+        /// Member does not belong to the DAO
+        NotDaoMember,
+
+        /// DAO must have at least one member
+        NoDaoMembers,
+
+        /// Max number of members per DAO limit reached
+        MaxMembersPerDaoLimitReached,
+
+        /// Threshold must be at least one and cannot exceed the number of DAO members
+        InvalidThreshold,
+
+        /// Origin is not the DAO account
+        NotDaoAccount,
+
+        /// Member has already approved the proposal
+        ProposalAlreadyApproved,
+
+        /// Only proposer can cancel the proposal
+        NotProposer,
+
+        /// Approval weight limit is less than the proposed call weight
+        WeightLimitTooLow,
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Dao {
+        /// DAOs by id
+        pub DaoById get(fn dao_by_id): map hasher(blake2_128_concat) DAOId<T> => DaoRecord<MemberId<T>>;
+
+        /// Next DAO id
+        pub NextDaoId get(fn next_dao_id): DAOId<T>;
+
+        /// Pending DAO proposals
+        pub ProposalById get(fn proposal_by_id): double_map hasher(blake2_128_concat) DAOId<T>,
+            hasher(blake2_128_concat) T::DaoProposalId => DaoProposal<MemberId<T>>;
+
+        /// Next DAO proposal id
+        pub NextProposalId get(fn next_proposal_id): T::DaoProposalId;
+    }
+}
+
+decl_event! {
+    pub enum Event<T>
+    where
+        MemberId = <T as MembershipTypes>::MemberId,
+        DAOId = <T as StorageOwnership>::DAOId,
+        DaoProposalId = <T as Trait>::DaoProposalId,
+        AccountId = <T as frame_system::Trait>::AccountId,
+    {
+        /// DAO created
+        /// Params:
+        /// - DAO id
+        /// - DAO account
+        /// - members
+        /// - threshold
+        DaoCreated(DAOId, AccountId, BTreeSet<MemberId>, u32),
+
+        /// DAO members and threshold updated
+        /// Params:
+        /// - DAO id
+        /// - members
+        /// - threshold
+        DaoUpdated(DAOId, BTreeSet<MemberId>, u32),
+
+        /// Call proposed on behalf of the DAO
+        /// Params:
+        /// - DAO id
+        /// - proposal id
+        /// - proposer
+        ProposalCreated(DAOId, DaoProposalId, MemberId),
+
+        /// DAO proposal approved by a member
+        /// Params:
+        /// - DAO id
+        /// - proposal id
+        /// - approving member
+        ProposalApproved(DAOId, DaoProposalId, MemberId),
+
+        /// DAO proposal reached the threshold and was dispatched
+        /// Params:
+        /// - DAO id
+        /// - proposal id
+        /// - whether the dispatched call succeeded
+        ProposalExecuted(DAOId, DaoProposalId, bool),
+
+        /// DAO proposal cancelled by its proposer
+        /// Params:
+        /// - DAO id
+        /// - proposal id
+        ProposalCancelled(DAOId, DaoProposalId),
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        /// Predefined errors
+        type Error = Error<T>;
+
+        /// Setup events
+        fn deposit_event() = default;
+
+        /// Exports const - max number of members per DAO.
+        const MaxMembersPerDao: u32 = T::MaxMembersPerDao::get();
+
+        /// Create a DAO. The creating member does not have to be one of the DAO members.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_dao(
+            origin,
+            member_id: MemberId<T>,
+            members: BTreeSet<MemberId<T>>,
+            threshold: u32,
+        ) {
+            Self::ensure_member_origin(origin, member_id)?;
+
+            Self::ensure_valid_dao_parameters(&members, threshold)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let dao_id = Self::next_dao_id();
+
+            <DaoById<T>>::insert(dao_id, DaoRecord {
+                members: members.clone(),
+                threshold,
+            });
+
+            <NextDaoId<T>>::mutate(|id| *id += One::one());
+
+            Self::deposit_event(RawEvent::DaoCreated(dao_id, Self::dao_account_id(dao_id), members, threshold));
+        }
+
+        /// Update DAO members and threshold. Must be dispatched by the DAO itself,
+        /// so the change has to be approved by the current members.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_dao(
+            origin,
+            dao_id: DAOId<T>,
+            members: BTreeSet<MemberId<T>>,
+            threshold: u32,
+        ) {
+            Self::ensure_dao_exists(dao_id)?;
+
+            let sender = ensure_signed(origin)?;
+            ensure!(Self::is_dao_account(dao_id, &sender), Error::<T>::NotDaoAccount);
+
+            Self::ensure_valid_dao_parameters(&members, threshold)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DaoById<T>>::insert(dao_id, DaoRecord {
+                members: members.clone(),
+                threshold,
+            });
+
+            Self::deposit_event(RawEvent::DaoUpdated(dao_id, members, threshold));
+        }
+
+        /// Propose a call on behalf of the DAO. Proposal is approved by the proposer,
+        /// so it is dispatched right away when the DAO threshold is one.
+        #[weight = 10_000_000u64.saturating_add(call.get_dispatch_info().weight)] // TODO: adjust weight
+        pub fn propose(
+            origin,
+            member_id: MemberId<T>,
+            dao_id: DAOId<T>,
+            call: Box<T::DaoCall>,
+        ) {
+            Self::ensure_member_origin(origin, member_id)?;
+
+            let dao = Self::ensure_dao_exists(dao_id)?;
+
+            ensure!(dao.is_member(&member_id), Error::<T>::NotDaoMember);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let proposal_id = Self::next_proposal_id();
+
+            let mut approvals = BTreeSet::new();
+            approvals.insert(member_id);
+
+            let proposal = DaoProposal {
+                proposer: member_id,
+                call: call.encode(),
+                approvals,
+                weight: call.get_dispatch_info().weight,
+            };
+
+            <NextProposalId<T>>::mutate(|id| *id += One::one());
+
+            Self::deposit_event(RawEvent::ProposalCreated(dao_id, proposal_id, member_id));
+
+            Self::approve_or_execute(&dao, dao_id, proposal_id, proposal);
+        }
+
+        /// Approve pending DAO proposal. Proposal is dispatched once the DAO threshold is reached.
+        /// Weight limit must cover the weight of the proposed call.
+        #[weight = 10_000_000u64.saturating_add(*weight_limit)] // TODO: adjust weight
+        pub fn approve(
+            origin,
+            member_id: MemberId<T>,
+            dao_id: DAOId<T>,
+            proposal_id: T::DaoProposalId,
+            weight_limit: Weight,
+        ) {
+            Self::ensure_member_origin(origin, member_id)?;
+
+            let dao = Self::ensure_dao_exists(dao_id)?;
+
+            ensure!(dao.is_member(&member_id), Error::<T>::NotDaoMember);
+
+            let mut proposal = Self::ensure_proposal_exists(dao_id, proposal_id)?;
+
+            ensure!(proposal.approvals.insert(member_id), Error::<T>::ProposalAlreadyApproved);
+
+            ensure!(proposal.weight <= weight_limit, Error::<T>::WeightLimitTooLow);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::deposit_event(RawEvent::ProposalApproved(dao_id, proposal_id, member_id));
+
+            Self::approve_or_execute(&dao, dao_id, proposal_id, proposal);
+        }
+
+        /// Cancel pending DAO proposal. Only the proposer can cancel it.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn cancel_proposal(
+            origin,
+            member_id: MemberId<T>,
+            dao_id: DAOId<T>,
+            proposal_id: T::DaoProposalId,
+        ) {
+            Self::ensure_member_origin(origin, member_id)?;
+
+            let proposal = Self::ensure_proposal_exists(dao_id, proposal_id)?;
+
+            ensure!(proposal.proposer == member_id, Error::<T>::NotProposer);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ProposalById<T>>::remove(dao_id, proposal_id);
+
+            Self::deposit_event(RawEvent::ProposalCancelled(dao_id, proposal_id));
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Account used by the DAO to dispatch approved calls.
+    pub fn dao_account_id(dao_id: DAOId<T>) -> T::AccountId {
+        T::DaoModuleId::get().into_sub_account(dao_id)
+    }
+
+    /// Checks whether given account is the account of an existing DAO.
+    pub fn is_dao_account(dao_id: DAOId<T>, account_id: &T::AccountId) -> bool {
+        <DaoById<T>>::contains_key(dao_id) && Self::dao_account_id(dao_id) == *account_id
+    }
+
+    fn ensure_member_origin(origin: T::Origin, member_id: MemberId<T>) -> DispatchResult {
+        T::MemberOriginValidator::ensure_actor_origin(origin, member_id)
+            .map_err(|_| Error::<T>::MemberAuthFailed)?;
+        Ok(())
+    }
+
+    fn ensure_dao_exists(dao_id: DAOId<T>) -> Result<DaoRecord<MemberId<T>>, Error<T>> {
+        ensure!(
+            <DaoById<T>>::contains_key(dao_id),
+            Error::<T>::DaoDoesNotExist
+        );
+        Ok(Self::dao_by_id(dao_id))
+    }
+
+    fn ensure_proposal_exists(
+        dao_id: DAOId<T>,
+        proposal_id: T::DaoProposalId,
+    ) -> Result<DaoProposal<MemberId<T>>, Error<T>> {
+        ensure!(
+            <ProposalById<T>>::contains_key(dao_id, proposal_id),
+            Error::<T>::ProposalDoesNotExist
+        );
+        Ok(Self::proposal_by_id(dao_id, proposal_id))
+    }
+
+    fn ensure_valid_dao_parameters(
+        members: &BTreeSet<MemberId<T>>,
+        threshold: u32,
+    ) -> DispatchResult {
+        ensure!(!members.is_empty(), Error::<T>::NoDaoMembers);
+        ensure!(
+            members.len() as u32 <= T::MaxMembersPerDao::get(),
+            Error::<T>::MaxMembersPerDaoLimitReached
+        );
+        ensure!(
+            threshold > 0 && threshold <= members.len() as u32,
+            Error::<T>::InvalidThreshold
+        );
+        Ok(())
+    }
+
+    // Store the proposal, or dispatch it on behalf of the DAO if it gathered enough approvals.
+    fn approve_or_execute(
+        dao: &DaoRecord<MemberId<T>>,
+        dao_id: DAOId<T>,
+        proposal_id: T::DaoProposalId,
+        proposal: DaoProposal<MemberId<T>>,
+    ) {
+        if !dao.is_approved(&proposal.approvals) {
+            <ProposalById<T>>::insert(dao_id, proposal_id, proposal);
+            return;
+        }
+
+        <ProposalById<T>>::remove(dao_id, proposal_id);
+
+        let origin = T::Origin::from(RawOrigin::Signed(Self::dao_account_id(dao_id)));
+
+        let executed = match T::DaoCall::decode(&mut &proposal.call[..]) {
+            Ok(call) => call.dispatch(origin).is_ok(),
+            Err(_) => false,
+        };
+
+        Self::deposit_event(RawEvent::ProposalExecuted(dao_id, proposal_id, executed));
+    }
+}
+
+impl<T: Trait> ActorOriginValidator<T::Origin, DAOId<T>, T::AccountId> for Module<T> {
+    /// Ensures the origin is the signed origin of the DAO account.
+    fn ensure_actor_origin(
+        origin: T::Origin,
+        dao_id: DAOId<T>,
+    ) -> Result<T::AccountId, &'static str> {
+        let account_id = ensure_signed(origin)?;
+
+        if Self::is_dao_account(dao_id, &account_id) {
+            Ok(account_id)
+        } else {
+            Err(Error::<T>::NotDaoAccount.into())
+        }
+    }
+}
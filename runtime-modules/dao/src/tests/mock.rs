@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+use crate::*;
+
+use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::{impl_outer_dispatch, impl_outer_event, impl_outer_origin, parameter_types};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+
+pub type MemberId = <Test as MembershipTypes>::MemberId;
+pub type DaoId = <Test as StorageOwnership>::DAOId;
+// DAO accounts are derived from the module id and the DAO id, so the account id
+// must be wide enough to tell apart sub-accounts of the module.
+pub type AccountId = u128;
+
+/// Origins
+
+pub const FIRST_MEMBER_ORIGIN: AccountId = 1;
+pub const SECOND_MEMBER_ORIGIN: AccountId = 2;
+pub const NON_DAO_MEMBER_ORIGIN: AccountId = 4;
+
+/// Runtime Id's
+
+pub const FIRST_MEMBER_ID: MemberId = 1;
+pub const SECOND_MEMBER_ID: MemberId = 2;
+pub const THIRD_MEMBER_ID: MemberId = 3;
+pub const NON_DAO_MEMBER_ID: MemberId = 4;
+
+impl_outer_origin! {
+    pub enum Origin for Test {}
+}
+
+mod dao {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum MetaEvent for Test {
+        dao<T>,
+        frame_system<T>,
+    }
+}
+
+impl_outer_dispatch! {
+    pub enum MetaCall for Test where origin: Origin {
+        dao::Dao,
+        frame_system::System,
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Test;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: u32 = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+
+impl frame_system::Trait for Test {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = MetaCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = MetaEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type MaximumExtrinsicWeight = ();
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type PalletInfo = ();
+    type SystemWeightInfo = ();
+}
+
+impl common::MembershipTypes for Test {
+    type MemberId = u64;
+    type ActorId = u64;
+}
+
+impl common::StorageOwnership for Test {
+    type ChannelId = u64;
+    type DAOId = u64;
+    type ContentId = u64;
+    type DataObjectTypeId = u64;
+}
+
+parameter_types! {
+    pub const DaoModuleId: ModuleId = ModuleId(*b"m:dao   ");
+    pub const MaxMembersPerDao: u32 = 5;
+}
+
+impl Trait for Test {
+    type Event = MetaEvent;
+    type DaoProposalId = u64;
+    type DaoCall = MetaCall;
+    type MemberOriginValidator = MockMemberOriginValidator;
+    type DaoModuleId = DaoModuleId;
+    type MaxMembersPerDao = MaxMembersPerDao;
+}
+
+// Members are authenticated by the account with the same id
+pub struct MockMemberOriginValidator;
+
+impl ActorOriginValidator<Origin, MemberId, AccountId> for MockMemberOriginValidator {
+    fn ensure_actor_origin(origin: Origin, member_id: MemberId) -> Result<AccountId, &'static str> {
+        let account_id = frame_system::ensure_signed(origin)?;
+
+        if account_id == member_id as AccountId {
+            Ok(account_id)
+        } else {
+            Err("Member auth failed")
+        }
+    }
+}
+
+pub type System = frame_system::Module<Test>;
+pub type Dao = Module<Test>;
+
+pub fn with_default_mock_builder<R, F: FnOnce() -> R>(f: F) -> R {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    sp_io::TestExternalities::from(t).execute_with(|| f())
+}
+
+// Recommendation from Parity on testing on_finalize
+// https://substrate.dev/docs/en/next/development/module/tests
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        <System as OnFinalize<u64>>::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        <System as OnInitialize<u64>>::on_initialize(System::block_number());
+    }
+}
@@ -0,0 +1,346 @@
+#![cfg(test)]
+
+mod mock;
+
+use crate::{Call, DaoRecord, Error, ProposalById, RawEvent};
+use frame_support::weights::{GetDispatchInfo, Weight};
+use frame_support::{assert_err, assert_ok};
+use mock::*;
+use sp_std::collections::btree_set::BTreeSet;
+
+fn members(ids: &[MemberId]) -> BTreeSet<MemberId> {
+    ids.iter().copied().collect()
+}
+
+fn create_dao(threshold: u32) -> DaoId {
+    let dao_id = Dao::next_dao_id();
+
+    assert_ok!(Dao::create_dao(
+        Origin::signed(FIRST_MEMBER_ORIGIN),
+        FIRST_MEMBER_ID,
+        members(&[FIRST_MEMBER_ID, SECOND_MEMBER_ID, THIRD_MEMBER_ID]),
+        threshold
+    ));
+
+    dao_id
+}
+
+fn update_dao_call(dao_id: DaoId, ids: &[MemberId], threshold: u32) -> Box<MetaCall> {
+    Box::new(MetaCall::Dao(Call::update_dao(
+        dao_id,
+        members(ids),
+        threshold,
+    )))
+}
+
+// Weight of the pending proposal call, zero if there is no such proposal
+fn proposal_weight(dao_id: DaoId, proposal_id: u64) -> Weight {
+    Dao::proposal_by_id(dao_id, proposal_id).weight
+}
+
+#[test]
+fn dao_creation() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let dao_id = create_dao(2);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::dao(RawEvent::DaoCreated(
+                dao_id,
+                Dao::dao_account_id(dao_id),
+                members(&[FIRST_MEMBER_ID, SECOND_MEMBER_ID, THIRD_MEMBER_ID]),
+                2
+            ))
+        );
+
+        assert_eq!(Dao::next_dao_id(), dao_id + 1);
+        assert!(Dao::is_dao_account(dao_id, &Dao::dao_account_id(dao_id)));
+        assert!(!Dao::is_dao_account(dao_id, &FIRST_MEMBER_ORIGIN));
+
+        // Each DAO gets its own account
+        let second_dao_id = create_dao(1);
+        assert_ne!(
+            Dao::dao_account_id(dao_id),
+            Dao::dao_account_id(second_dao_id)
+        );
+
+        // Member must authenticate
+        assert_err!(
+            Dao::create_dao(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                FIRST_MEMBER_ID,
+                members(&[FIRST_MEMBER_ID]),
+                1
+            ),
+            Error::<Test>::MemberAuthFailed
+        );
+
+        // Threshold must be reachable
+        assert_err!(
+            Dao::create_dao(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                FIRST_MEMBER_ID,
+                members(&[FIRST_MEMBER_ID]),
+                2
+            ),
+            Error::<Test>::InvalidThreshold
+        );
+
+        assert_err!(
+            Dao::create_dao(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                FIRST_MEMBER_ID,
+                members(&[FIRST_MEMBER_ID]),
+                0
+            ),
+            Error::<Test>::InvalidThreshold
+        );
+
+        assert_err!(
+            Dao::create_dao(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                FIRST_MEMBER_ID,
+                members(&[]),
+                0
+            ),
+            Error::<Test>::NoDaoMembers
+        );
+
+        // Members count is limited
+        let too_many_members: Vec<MemberId> = (0..=MaxMembersPerDao::get() as u64).collect();
+        assert_err!(
+            Dao::create_dao(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                FIRST_MEMBER_ID,
+                members(&too_many_members),
+                1
+            ),
+            Error::<Test>::MaxMembersPerDaoLimitReached
+        );
+    })
+}
+
+#[test]
+fn proposal_is_executed_when_threshold_reached() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let dao_id = create_dao(2);
+        let proposal_id = Dao::next_proposal_id();
+
+        assert_ok!(Dao::propose(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            FIRST_MEMBER_ID,
+            dao_id,
+            update_dao_call(dao_id, &[FIRST_MEMBER_ID, SECOND_MEMBER_ID], 1)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::dao(RawEvent::ProposalCreated(
+                dao_id,
+                proposal_id,
+                FIRST_MEMBER_ID
+            ))
+        );
+
+        // Proposer approval alone is not enough
+        assert_eq!(
+            Dao::proposal_by_id(dao_id, proposal_id).approvals,
+            members(&[FIRST_MEMBER_ID])
+        );
+        assert_eq!(Dao::dao_by_id(dao_id).threshold, 2);
+
+        assert_err!(
+            Dao::approve(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                FIRST_MEMBER_ID,
+                dao_id,
+                proposal_id,
+                proposal_weight(dao_id, proposal_id)
+            ),
+            Error::<Test>::ProposalAlreadyApproved
+        );
+
+        // Only DAO members can approve
+        assert_err!(
+            Dao::approve(
+                Origin::signed(NON_DAO_MEMBER_ORIGIN),
+                NON_DAO_MEMBER_ID,
+                dao_id,
+                proposal_id,
+                proposal_weight(dao_id, proposal_id)
+            ),
+            Error::<Test>::NotDaoMember
+        );
+
+        assert_ok!(Dao::approve(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            dao_id,
+            proposal_id,
+            proposal_weight(dao_id, proposal_id)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::dao(RawEvent::ProposalExecuted(dao_id, proposal_id, true))
+        );
+
+        assert!(!ProposalById::<Test>::contains_key(dao_id, proposal_id));
+        assert_eq!(
+            Dao::dao_by_id(dao_id),
+            DaoRecord {
+                members: members(&[FIRST_MEMBER_ID, SECOND_MEMBER_ID]),
+                threshold: 1,
+            }
+        );
+
+        // With threshold of one proposals are executed right away
+        let proposal_id = Dao::next_proposal_id();
+        assert_ok!(Dao::propose(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            dao_id,
+            update_dao_call(dao_id, &[SECOND_MEMBER_ID], 1)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::dao(RawEvent::ProposalExecuted(dao_id, proposal_id, true))
+        );
+        assert!(!Dao::dao_by_id(dao_id).is_member(&FIRST_MEMBER_ID));
+    })
+}
+
+#[test]
+fn failed_proposal_execution() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let dao_id = create_dao(1);
+        let other_dao_id = create_dao(1);
+        let proposal_id = Dao::next_proposal_id();
+
+        // A DAO cannot act on behalf of another DAO
+        assert_ok!(Dao::propose(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            FIRST_MEMBER_ID,
+            dao_id,
+            update_dao_call(other_dao_id, &[FIRST_MEMBER_ID], 1)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::dao(RawEvent::ProposalExecuted(dao_id, proposal_id, false))
+        );
+        assert_eq!(Dao::dao_by_id(other_dao_id).threshold, 1);
+        assert_eq!(Dao::dao_by_id(other_dao_id).members.len(), 3);
+
+        // DAO can only be updated through its own proposals
+        assert_err!(
+            Dao::update_dao(
+                Origin::signed(FIRST_MEMBER_ORIGIN),
+                dao_id,
+                members(&[FIRST_MEMBER_ID]),
+                1
+            ),
+            Error::<Test>::NotDaoAccount
+        );
+    })
+}
+
+#[test]
+fn proposal_can_be_cancelled_by_proposer() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let dao_id = create_dao(3);
+        let proposal_id = Dao::next_proposal_id();
+
+        assert_ok!(Dao::propose(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            FIRST_MEMBER_ID,
+            dao_id,
+            update_dao_call(dao_id, &[FIRST_MEMBER_ID], 1)
+        ));
+
+        assert_err!(
+            Dao::cancel_proposal(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                dao_id,
+                proposal_id
+            ),
+            Error::<Test>::NotProposer
+        );
+
+        assert_ok!(Dao::cancel_proposal(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            FIRST_MEMBER_ID,
+            dao_id,
+            proposal_id
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::dao(RawEvent::ProposalCancelled(dao_id, proposal_id))
+        );
+
+        assert_err!(
+            Dao::approve(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                dao_id,
+                proposal_id,
+                proposal_weight(dao_id, proposal_id)
+            ),
+            Error::<Test>::ProposalDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn approval_weight_limit_covers_proposed_call() {
+    with_default_mock_builder(|| {
+        let dao_id = create_dao(2);
+        let proposal_id = Dao::next_proposal_id();
+        let call = update_dao_call(dao_id, &[FIRST_MEMBER_ID], 1);
+
+        assert_ok!(Dao::propose(
+            Origin::signed(FIRST_MEMBER_ORIGIN),
+            FIRST_MEMBER_ID,
+            dao_id,
+            call.clone()
+        ));
+
+        let call_weight = call.get_dispatch_info().weight;
+        assert_eq!(proposal_weight(dao_id, proposal_id), call_weight);
+
+        assert_err!(
+            Dao::approve(
+                Origin::signed(SECOND_MEMBER_ORIGIN),
+                SECOND_MEMBER_ID,
+                dao_id,
+                proposal_id,
+                call_weight - 1
+            ),
+            Error::<Test>::WeightLimitTooLow
+        );
+
+        assert_ok!(Dao::approve(
+            Origin::signed(SECOND_MEMBER_ORIGIN),
+            SECOND_MEMBER_ID,
+            dao_id,
+            proposal_id,
+            call_weight
+        ));
+        assert!(!ProposalById::<Test>::contains_key(dao_id, proposal_id));
+    })
+}
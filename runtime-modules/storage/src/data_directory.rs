@@ -23,16 +23,22 @@
 
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
 use frame_system::ensure_root;
 use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
 use common::origin::ActorOriginValidator;
-pub use common::storage::{ContentParameters, StorageObjectOwner};
+pub use common::storage::{
+    ContentHash, ContentHashAlgorithm, ContentParameters, StorageObjectOwner,
+};
 pub(crate) use common::BlockAndTime;
 
 use crate::data_object_type_registry;
@@ -50,6 +56,11 @@ pub const DEFAULT_VOUCHER: Voucher = Voucher::new(110_000_000_000, 5_000);
 /// The default starting upload blocked status
 pub const DEFAULT_UPLOADING_BLOCKED_STATUS: bool = false;
 
+/// Version of the module storage layout, the runtime upgrade migrates older layouts
+pub(crate) const STORAGE_VERSION: u32 = 1;
+/// Size (bytes) of the content chunks the merkle root is computed over
+pub const DATA_OBJECT_CHUNK_SIZE: u64 = 262_144;
+
 /// The _Data directory_ main _Trait_.
 pub trait Trait:
     pallet_timestamp::Trait
@@ -68,6 +79,15 @@ pub trait Trait:
 
     /// Validates member id and origin combination.
     type MemberOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
+
+    /// Validates DAO id and origin combination.
+    type DaoOriginValidator: ActorOriginValidator<Self::Origin, DAOId<Self>, Self::AccountId>;
+
+    /// Number of blocks after which content still awaiting liaison judgement is rejected.
+    type ContentUploadTimeout: Get<Self::BlockNumber>;
+
+    /// Assigns the bags of the uploaded content to the storage buckets.
+    type StorageBagAssigner: StorageBagAssigner<Self>;
 }
 
 decl_error! {
@@ -111,6 +131,21 @@ decl_error! {
 
         /// Overflow detected when changing
         VoucherOverflow,
+
+        /// Content ids provided for removal are not unique.
+        DuplicateContentIds,
+
+        /// Provided content ids do not cover all the content of the owner.
+        OwnerContentNotFullyRemoved,
+
+        /// Liaison judgement of the content was already made.
+        ContentIsNotPending,
+
+        /// Content hash digest length does not match the hashing algorithm.
+        InvalidContentHash,
+
+        /// Chunks merkle root length does not match the runtime hash length.
+        InvalidChunksMerkleRoot,
     }
 }
 
@@ -123,6 +158,9 @@ pub enum LiaisonJudgement {
 
     /// Content accepted.
     Accepted,
+
+    /// Content rejected by the storage provider or not uploaded in time.
+    Rejected,
 }
 
 impl Default for LiaisonJudgement {
@@ -174,6 +212,35 @@ pub struct DataObjectInternal<
 
     /// IPFS content id.
     pub ipfs_content_id: Vec<u8>,
+
+    /// Multihash of the whole content.
+    pub content_hash: ContentHash,
+
+    /// Merkle root of the content chunks committed at upload time.
+    pub chunks_merkle_root: Vec<u8>,
+}
+
+impl<MemberId, ChannelId, DAOId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId>
+    DataObjectInternal<
+        MemberId,
+        ChannelId,
+        DAOId,
+        BlockNumber,
+        Moment,
+        DataObjectTypeId,
+        StorageProviderId,
+    >
+{
+    /// Number of chunks the content is split into, empty content has a single empty chunk.
+    pub fn chunks_count(&self) -> u64 {
+        let full_chunks = self.size / DATA_OBJECT_CHUNK_SIZE;
+
+        if self.size % DATA_OBJECT_CHUNK_SIZE == 0 {
+            full_chunks.max(1)
+        } else {
+            full_chunks + 1
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -321,6 +388,12 @@ decl_storage! {
         /// If all new uploads blocked
         pub UploadingBlocked get(fn uploading_blocked) config(): bool = DEFAULT_UPLOADING_BLOCKED_STATUS;
 
+        /// Content awaiting liaison judgement, keyed by the block its upload times out at.
+        pub PendingContentExpirations get(fn pending_content_expirations):
+            double_map hasher(blake2_128_concat) T::BlockNumber, hasher(blake2_128_concat) T::ContentId => ();
+
+        /// Version of the storage layout, chains started before the content hashes are at version zero.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>| STORAGE_VERSION): u32;
     }
 }
 
@@ -356,7 +429,13 @@ decl_event! {
         /// Params:
         /// - Id of the relationship.
         /// - Id of the storage provider.
-        ContentRejected(ContentId, StorageProviderId),
+        /// - Rejection rationale.
+        ContentRejected(ContentId, StorageProviderId, Vec<u8>),
+
+        /// Emits when pending content is rejected after the upload timeout.
+        /// Params:
+        /// - Ids of the rejected content.
+        ContentUploadTimedOut(Vec<ContentId>),
 
         /// Emits when the storage object owner voucher size limit update performed.
         /// Params:
@@ -412,6 +491,15 @@ decl_module! {
         /// Predefined errors.
         type Error = Error<T>;
 
+        /// Exports const - number of blocks after which pending content is rejected.
+        const ContentUploadTimeout: T::BlockNumber = T::ContentUploadTimeout::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::reject_timed_out_content(now);
+
+            10_000_000 //TODO: adjust weight
+        }
+
         /// Adds the content to the frame_system. The created DataObject
         /// awaits liaison to accept it.
         #[weight = 10_000_000] // TODO: adjust weight
@@ -443,6 +531,8 @@ decl_module! {
 
             Self::upload_content(content.clone(), owner.clone());
 
+            T::StorageBagAssigner::assign_storage_buckets(&owner);
+
             Self::deposit_event(RawEvent::ContentAdded(content, owner));
         }
 
@@ -476,9 +566,7 @@ decl_module! {
             <GlobalVoucher>::put(new_global_voucher);
 
             // Let's remove content
-            for content_id in &content_ids {
-                <DataByContentId<T>>::remove(content_id);
-            }
+            Self::remove_data_objects(&content_ids);
 
             Self::deposit_event(RawEvent::ContentRemoved(content_ids, owner));
         }
@@ -653,6 +741,9 @@ decl_module! {
             // == MUTATION SAFE ==
 
             if data.liaison_judgement == LiaisonJudgement::Pending {
+                // Content is no longer awaiting the upload
+                <PendingContentExpirations<T>>::remove(Self::upload_timeout_block(&data), content_id);
+
                 // Set the liaison which is updating the judgement
                 data.liaison = Some(storage_provider_id);
 
@@ -663,6 +754,36 @@ decl_module! {
             }
         }
 
+        /// Storage provider rejects a content. Requires signed storage provider account and its id.
+        /// The LiaisonJudgement can only be updated from Pending to Rejected.
+        /// Voucher consumed by the content upload is released.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub(crate) fn reject_content(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            content_id: T::ContentId,
+            rationale: Vec<u8>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            let data = Self::get_data_object(&content_id)?;
+
+            ensure!(
+                data.liaison_judgement == LiaisonJudgement::Pending,
+                Error::<T>::ContentIsNotPending
+            );
+
+            let (new_owner_voucher, new_global_voucher) = Self::ensure_rejected_content_voucher_can_be_released(&data)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::reject_data_object(content_id, data, Some(storage_provider_id), new_owner_voucher, new_global_voucher);
+
+            Self::deposit_event(RawEvent::ContentRejected(content_id, storage_provider_id, rationale));
+        }
+
         /// Locks / unlocks content uploading
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_content_uploading_status(origin, is_blocked: bool) {
@@ -702,10 +823,16 @@ impl<T: Trait> Module<T> {
         origin: T::Origin,
         owner: &ObjectOwner<T>,
     ) -> DispatchResult {
-        if let StorageObjectOwner::Member(member_id) = owner {
-            T::MemberOriginValidator::ensure_actor_origin(origin, *member_id)?;
-        } else {
-            ensure_root(origin)?;
+        match owner {
+            StorageObjectOwner::Member(member_id) => {
+                T::MemberOriginValidator::ensure_actor_origin(origin, *member_id)?;
+            }
+            StorageObjectOwner::DAO(dao_id) => {
+                T::DaoOriginValidator::ensure_actor_origin(origin, *dao_id)?;
+            }
+            _ => {
+                ensure_root(origin)?;
+            }
         };
         Ok(())
     }
@@ -743,8 +870,14 @@ impl<T: Trait> Module<T> {
         Ok(content)
     }
 
-    /// Calculates content voucher delta of existing data objects
+    /// Calculates content voucher delta of existing data objects.
+    /// Rejected data objects are skipped, as their voucher was already released.
     fn calculate_content_voucher(content: Vec<DataObject<T>>) -> Delta {
+        let content: Vec<_> = content
+            .into_iter()
+            .filter(|data| data.liaison_judgement != LiaisonJudgement::Rejected)
+            .collect();
+
         let content_length = content.len() as u64;
 
         let content_size = content
@@ -799,12 +932,117 @@ impl<T: Trait> Module<T> {
                 liaison: None,
                 liaison_judgement: LiaisonJudgement::Pending,
                 ipfs_content_id: content.ipfs_content_id,
+                content_hash: content.content_hash,
+                chunks_merkle_root: content.chunks_merkle_root,
             };
 
+            // Reject the content if no liaison judgement is made in time
+            <PendingContentExpirations<T>>::insert(
+                Self::upload_timeout_block(&data),
+                content.content_id,
+                (),
+            );
+
             <DataByContentId<T>>::insert(content.content_id, data);
         }
     }
 
+    // Block at which the content upload times out
+    fn upload_timeout_block(data: &DataObject<T>) -> T::BlockNumber {
+        data.added_at.block + T::ContentUploadTimeout::get()
+    }
+
+    // Remove data objects along with their pending upload timeouts
+    fn remove_data_objects(content_ids: &[T::ContentId]) {
+        for content_id in content_ids {
+            let data = Self::data_object_by_content_id(content_id);
+
+            if data.liaison_judgement == LiaisonJudgement::Pending {
+                <PendingContentExpirations<T>>::remove(
+                    Self::upload_timeout_block(&data),
+                    content_id,
+                );
+            }
+
+            T::StorageBagAssigner::remove_content_relationships(content_id, &data);
+
+            <DataByContentId<T>>::remove(content_id);
+        }
+    }
+
+    /// Ensures voucher consumed by the rejected content can be released. Returns new vouchers
+    /// if successful, Error otherwise.
+    fn ensure_rejected_content_voucher_can_be_released(
+        data: &DataObject<T>,
+    ) -> Result<(Voucher, Voucher), Error<T>> {
+        let rejection_voucher = Delta {
+            size: data.size,
+            objects: 1,
+        };
+
+        let new_owner_voucher =
+            Self::get_voucher(&data.owner).release_voucher::<T>(rejection_voucher)?;
+        let new_global_voucher = Self::global_voucher().release_voucher::<T>(rejection_voucher)?;
+
+        Ok((new_owner_voucher, new_global_voucher))
+    }
+
+    // Set rejected liaison judgement and release the voucher consumed by the content upload
+    fn reject_data_object(
+        content_id: T::ContentId,
+        mut data: DataObject<T>,
+        liaison: Option<StorageProviderId<T>>,
+        new_owner_voucher: Voucher,
+        new_global_voucher: Voucher,
+    ) {
+        <PendingContentExpirations<T>>::remove(Self::upload_timeout_block(&data), content_id);
+
+        // Updade owner voucher
+        <Vouchers<T>>::insert(&data.owner, new_owner_voucher);
+
+        // Update global voucher
+        <GlobalVoucher>::put(new_global_voucher);
+
+        // Rejected content is not stored by the storage providers
+        T::StorageBagAssigner::remove_content_relationships(&content_id, &data);
+
+        data.liaison = liaison;
+        data.liaison_judgement = LiaisonJudgement::Rejected;
+        <DataByContentId<T>>::insert(content_id, data);
+    }
+
+    // Reject the content whose upload times out at the given block
+    fn reject_timed_out_content(now: T::BlockNumber) {
+        let timed_out_content_ids: Vec<_> = <PendingContentExpirations<T>>::iter_prefix(now)
+            .map(|(content_id, _)| content_id)
+            .collect();
+
+        let mut rejected_content_ids = Vec::new();
+
+        for content_id in timed_out_content_ids {
+            let data = Self::data_object_by_content_id(content_id);
+
+            if let Ok((new_owner_voucher, new_global_voucher)) =
+                Self::ensure_rejected_content_voucher_can_be_released(&data)
+            {
+                Self::reject_data_object(
+                    content_id,
+                    data,
+                    None,
+                    new_owner_voucher,
+                    new_global_voucher,
+                );
+                rejected_content_ids.push(content_id);
+            }
+        }
+
+        <PendingContentExpirations<T>>::remove_prefix(now);
+
+        if !rejected_content_ids.is_empty() {
+            Self::deposit_event(RawEvent::ContentUploadTimedOut(rejected_content_ids));
+        }
+    }
+
     fn ensure_content_is_valid(
         multi_content: &[ContentParameters<T::ContentId, DataObjectTypeId<T>>],
     ) -> DispatchResult {
@@ -818,6 +1056,17 @@ impl<T: Trait> Module<T> {
                 !<DataByContentId<T>>::contains_key(&content.content_id),
                 Error::<T>::DataObjectAlreadyAdded
             );
+
+            ensure!(
+                content.content_hash.is_valid(),
+                Error::<T>::InvalidContentHash
+            );
+
+            // Chunks merkle root is built with the runtime hashing to challenge storage providers
+            ensure!(
+                content.chunks_merkle_root.len() == T::Hash::default().as_ref().len(),
+                Error::<T>::InvalidChunksMerkleRoot
+            );
         }
         Ok(())
     }
@@ -846,6 +1095,22 @@ impl<T: Trait> ContentIdExists<T> for Module<T> {
     }
 }
 
+/// Storage bag assignment helper.
+pub trait StorageBagAssigner<T: Trait> {
+    /// Assigns the bag of the storage object owner to the storage buckets, if not yet assigned.
+    fn assign_storage_buckets(bag_id: &ObjectOwner<T>);
+
+    /// Removes the storage relationships of the removed content and releases the storage bucket
+    /// capacity they used.
+    fn remove_content_relationships(content_id: &T::ContentId, data_object: &DataObject<T>);
+}
+
+impl<T: Trait> StorageBagAssigner<T> for () {
+    fn assign_storage_buckets(_bag_id: &ObjectOwner<T>) {}
+
+    fn remove_content_relationships(_content_id: &T::ContentId, _data_object: &DataObject<T>) {}
+}
+
 impl<T: Trait> common::storage::StorageSystem<T> for Module<T> {
     fn atomically_add_content(
         owner: ObjectOwner<T>,
@@ -869,7 +1134,10 @@ impl<T: Trait> common::storage::StorageSystem<T> for Module<T> {
         // Update global voucher
         <GlobalVoucher>::put(new_global_voucher);
 
-        Self::upload_content(content, owner);
+        Self::upload_content(content, owner.clone());
+
+        T::StorageBagAssigner::assign_storage_buckets(&owner);
+
         Ok(())
     }
 
@@ -896,9 +1164,46 @@ impl<T: Trait> common::storage::StorageSystem<T> for Module<T> {
         <GlobalVoucher>::put(new_global_voucher);
 
         // Let's remove content
-        for content_id in content_ids {
-            <DataByContentId<T>>::remove(content_id);
-        }
+        Self::remove_data_objects(content_ids);
+
+        Ok(())
+    }
+
+    fn atomically_remove_all_content(
+        owner: &ObjectOwner<T>,
+        content_ids: &[T::ContentId],
+    ) -> DispatchResult {
+        let unique_content_ids: BTreeSet<_> = content_ids.iter().collect();
+        ensure!(
+            unique_content_ids.len() == content_ids.len(),
+            Error::<T>::DuplicateContentIds
+        );
+
+        // Ensure content under given content ids can be successfully removed
+        let content = Self::ensure_content_can_be_removed(content_ids, owner)?;
+
+        let removal_voucher = Self::calculate_content_voucher(content);
+        let new_owner_voucher = Self::get_voucher(&owner).release_voucher::<T>(removal_voucher)?;
+        let new_global_voucher = Self::global_voucher().release_voucher::<T>(removal_voucher)?;
+
+        // Ensure no content of the owner is left behind
+        ensure!(
+            new_owner_voucher.get_objects_used() == 0,
+            Error::<T>::OwnerContentNotFullyRemoved
+        );
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // Release owner voucher.
+        <Vouchers<T>>::remove(owner);
+
+        // Update global voucher
+        <GlobalVoucher>::put(new_global_voucher);
+
+        // Let's remove content
+        Self::remove_data_objects(content_ids);
 
         Ok(())
     }
@@ -920,4 +1225,10 @@ impl<T: Trait> common::storage::StorageSystem<T> for Module<T> {
 
         Ok(())
     }
+
+    fn content_owners() -> Vec<(ContentId<T>, ObjectOwner<T>)> {
+        <DataByContentId<T>>::iter()
+            .map(|(content_id, data_object)| (content_id, data_object.owner))
+            .collect()
+    }
 }
@@ -1,17 +1,34 @@
 //! # Data object storage registry module
-//! Data object storage registry module for the Joystream platform allows to set relationships
-//! between the content and the storage providers. All extrinsics require storage working group registration.
+//! Data object storage registry module for the Joystream platform manages storage buckets and
+//! allows to set relationships between the content and the storage buckets.
 //!
 //! ## Comments
 //!
 //! Data object storage registry module uses  working group module to authorize actions.
-//! Only registered storage providers can call extrinsics.
+//! Storage buckets are managed by the storage working group leader. Content is placed into bags
+//! (one per storage object owner), which are assigned to the storage buckets by a deterministic policy:
+//! buckets with an operator, accepting new bags and having spare capacity are picked,
+//! least loaded first. Only the operator of a bucket assigned to the content bag can
+//! add a relationship for the content.
+//!
+//! Each storage challenge period a random ready relationship is challenged: the storage provider
+//! has to respond with the hash of a random content chunk and its merkle proof against the
+//! chunks merkle root committed at the content upload. Unanswered challenges unset the
+//! relationship ready state and optionally slash the storage provider role stake.
 //!
 //! ## Supported extrinsics
 //!
-//! - [add_relationship](./struct.Module.html#method.add_relationship) - Add storage provider-to-content relationship.
+//! - [create_storage_bucket](./struct.Module.html#method.create_storage_bucket) - Create a storage bucket.
+//! - [set_storage_bucket_operator](./struct.Module.html#method.set_storage_bucket_operator) - Set or unset the storage bucket operator.
+//! - [update_storage_bucket_status](./struct.Module.html#method.update_storage_bucket_status) - Allow or forbid assigning new bags to the storage bucket.
+//! - [update_storage_buckets_per_bag](./struct.Module.html#method.update_storage_buckets_per_bag) - Set number of storage buckets each bag is assigned to.
+//! - [assign_storage_buckets_to_bag](./struct.Module.html#method.assign_storage_buckets_to_bag) - Assign storage buckets to the bag by the policy.
+//! - [add_relationship](./struct.Module.html#method.add_relationship) - Add storage bucket-to-content relationship.
 //! - [set_relationship_ready](./struct.Module.html#method.set_relationship_ready)- Activates storage provider-to-content relationship.
+//!   Ready relationships are counted as replicas of the content, targeting the replication factor of the data object type.
 //! - [unset_relationship_ready](./struct.Module.html#method.unset_relationship_ready) - Deactivates storage provider-to-content relationship.
+//! - [respond_to_storage_challenge](./struct.Module.html#method.respond_to_storage_challenge) - Prove the challenged content chunk is retrievable.
+//! - [set_storage_challenge_slash_amount](./struct.Module.html#method.set_storage_challenge_slash_amount) - Set the role stake slashed on failed storage challenges.
 //!
 
 // Clippy linter requirement.
@@ -24,15 +41,36 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchResult;
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::{Get, Randomness};
+use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::BaseArithmetic;
-use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_arithmetic::traits::{BaseArithmetic, Zero};
+use sp_runtime::traits::{Hash, MaybeSerialize, Member};
 use sp_std::vec::Vec;
 
-use crate::data_directory::{self, ContentIdExists};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::data_directory::{self, ContentIdExists, LiaisonJudgement, Voucher};
+use crate::data_object_type_registry;
 use crate::*;
+use working_group::BalanceOf;
 
 const DEFAULT_FIRST_RELATIONSHIP_ID: u8 = 1;
+const DEFAULT_FIRST_STORAGE_BUCKET_ID: u8 = 1;
+
+/// The default number of storage buckets each bag is assigned to
+pub const DEFAULT_STORAGE_BUCKETS_PER_BAG: u64 = 1;
+
+/// Version of the module storage layout, the runtime upgrade migrates older layouts
+pub(crate) const STORAGE_VERSION: u32 = 1;
+
+/// Randomness subject of the storage challenge selection
+const STORAGE_CHALLENGE_RANDOMNESS_SUBJECT: &[u8] = b"storage_challenge";
+
+/// Bag groups the content of a single storage object owner (channel, member, etc.)
+pub type BagId<T> = ObjectOwner<T>;
 
 /// The _Data object storage registry_ main _Trait_.
 pub trait Trait:
@@ -54,8 +92,24 @@ pub trait Trait:
         + MaybeSerialize
         + PartialEq;
 
+    /// Type for storage bucket id
+    type StorageBucketId: Parameter
+        + Member
+        + BaseArithmetic
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerialize
+        + PartialEq;
+
     /// Ensures that a content exists
     type ContentIdExists: data_directory::ContentIdExists<Self>;
+
+    /// Source of randomness for the storage challenges
+    type Randomness: Randomness<Self::Hash>;
+
+    /// Number of blocks between the storage challenges, also the time to respond to a challenge
+    type StorageChallengePeriod: Get<Self::BlockNumber>;
 }
 
 decl_error! {
@@ -72,6 +126,42 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// No storage bucket found for this ID.
+        StorageBucketNotFound,
+
+        /// Only the storage bucket operator can store the content in the bucket.
+        NotStorageBucketOperator,
+
+        /// Storage bucket is not assigned to the content bag.
+        StorageBucketNotAssignedToBag,
+
+        /// Content is already stored in the storage bucket.
+        RelationshipAlreadyExists,
+
+        /// Rejected content cannot be stored in the storage bucket.
+        ContentRejected,
+
+        /// Storage bucket capacity limits are less than used.
+        StorageBucketLimitsLessThanUsed,
+
+        /// Storage bucket still has bags assigned or content stored.
+        StorageBucketIsNotEmpty,
+
+        /// Storage buckets per bag number should be positive.
+        StorageBucketsPerBagIsZero,
+
+        /// No storage challenge awaits the response.
+        NoActiveStorageChallenge,
+
+        /// Only the challenged storage provider can respond to the storage challenge.
+        NotChallengedStorageProvider,
+
+        /// Hash of the chunk and merkle proof do not match the content chunks merkle root.
+        InvalidStorageChallengeResponse,
+
+        /// Storage challenge slash amount should be positive.
+        StorageChallengeSlashAmountIsZero,
     }
 }
 
@@ -84,10 +174,53 @@ pub struct DataObjectStorageRelationship<T: Trait> {
     /// Storge provider id.
     pub storage_provider_id: StorageProviderId<T>,
 
+    /// Storage bucket the content is stored in.
+    pub storage_bucket_id: T::StorageBucketId,
+
     /// Active state (True=Active)
     pub ready: bool,
 }
 
+/// Storage bucket managed by the storage working group leader and operated by a storage provider.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, Default)]
+pub struct StorageBucketRecord<StorageProviderId> {
+    /// Storage provider operating the bucket.
+    pub operator: Option<StorageProviderId>,
+
+    /// Whether new bags can be assigned to the bucket.
+    pub accepting_new_bags: bool,
+
+    /// Capacity limits and usage of the bucket.
+    pub voucher: Voucher,
+
+    /// Number of bags assigned to the bucket.
+    pub assigned_bags: u64,
+}
+
+/// Alias for StorageBucketRecord
+pub type StorageBucket<T> = StorageBucketRecord<StorageProviderId<T>>;
+
+/// Challenge to prove the content of the ready relationship is retrievable.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, Default)]
+pub struct StorageChallengeRecord<DataObjectStorageRelationshipId, BlockNumber> {
+    /// Challenged relationship.
+    pub relationship_id: DataObjectStorageRelationshipId,
+
+    /// Index of the challenged content chunk.
+    pub chunk_index: u64,
+
+    /// Block after which the challenge fails if not responded.
+    pub deadline: BlockNumber,
+}
+
+/// Alias for StorageChallengeRecord
+pub type StorageChallenge<T> = StorageChallengeRecord<
+    <T as Trait>::DataObjectStorageRelationshipId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectStorageRegistry {
 
@@ -105,6 +238,44 @@ decl_storage! {
         /// Keeps a list of storage relationships per content id.
         pub RelationshipsByContentId get(fn relationships_by_content_id): map hasher(blake2_128_concat)
             T::ContentId => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Defines next storage bucket id.
+        pub NextStorageBucketId get(fn next_storage_bucket_id): T::StorageBucketId = T::StorageBucketId::from(DEFAULT_FIRST_STORAGE_BUCKET_ID);
+
+        /// Mapping of storage buckets
+        pub StorageBucketById get(fn storage_bucket_by_id): map hasher(blake2_128_concat)
+            T::StorageBucketId => Option<StorageBucket<T>>;
+
+        /// Keeps a list of storage buckets each bag is assigned to.
+        pub BagStorageBuckets get(fn bag_storage_buckets): map hasher(blake2_128_concat)
+            BagId<T> => Vec<T::StorageBucketId>;
+
+        /// Number of storage buckets each bag is assigned to.
+        pub StorageBucketsPerBag get(fn storage_buckets_per_bag): u64 = DEFAULT_STORAGE_BUCKETS_PER_BAG;
+
+        /// Number of ready relationships (replicas) per content id.
+        pub ReadyReplicasByContentId get(fn ready_replicas_by_content_id): map hasher(blake2_128_concat)
+            T::ContentId => u32;
+
+        /// Ready relationships by their index, used to pick a random relationship to challenge.
+        pub ReadyRelationshipByIndex get(fn ready_relationship_by_index): map hasher(blake2_128_concat)
+            u64 => T::DataObjectStorageRelationshipId;
+
+        /// Index of the ready relationship in the ReadyRelationshipByIndex map.
+        pub ReadyRelationshipIndex get(fn ready_relationship_index): map hasher(blake2_128_concat)
+            T::DataObjectStorageRelationshipId => Option<u64>;
+
+        /// Number of ready relationships.
+        pub ReadyRelationshipsCount get(fn ready_relationships_count): u64;
+
+        /// Storage challenge awaiting the storage provider response.
+        pub CurrentStorageChallenge get(fn current_storage_challenge): Option<StorageChallenge<T>>;
+
+        /// Role stake slashed from the storage provider failing the storage challenge, no slashing if not set.
+        pub StorageChallengeSlashAmount get(fn storage_challenge_slash_amount): Option<BalanceOf<T>>;
+
+        /// Version of the storage layout, chains started before the storage buckets are at version zero.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>| STORAGE_VERSION): u32;
     }
 }
 
@@ -113,14 +284,18 @@ decl_event! {
     pub enum Event<T> where
         <T as common::StorageOwnership>::ContentId,
         <T as Trait>::DataObjectStorageRelationshipId,
-        StorageProviderId = StorageProviderId<T>
+        <T as Trait>::StorageBucketId,
+        StorageProviderId = StorageProviderId<T>,
+        BagId = BagId<T>,
+        Balance = BalanceOf<T>
     {
         /// Emits on adding of the data object storage relationship.
         /// Params:
         /// - Id of the relationship.
         /// - Id of the content.
         /// - Id of the storage provider.
-        DataObjectStorageRelationshipAdded(DataObjectStorageRelationshipId, ContentId, StorageProviderId),
+        /// - Id of the storage bucket.
+        DataObjectStorageRelationshipAdded(DataObjectStorageRelationshipId, ContentId, StorageProviderId, StorageBucketId),
 
         /// Emits on adding of the data object storage relationship.
         /// Params:
@@ -128,6 +303,87 @@ decl_event! {
         /// - Id of the relationship.
         /// - Current state of the relationship (True=Active).
         DataObjectStorageRelationshipReadyUpdated(StorageProviderId, DataObjectStorageRelationshipId, bool),
+
+        /// Emits on creating of the storage bucket.
+        /// Params:
+        /// - Id of the storage bucket.
+        /// - Size limit.
+        /// - Objects limit.
+        /// - Whether new bags can be assigned to the bucket.
+        StorageBucketCreated(StorageBucketId, u64, u64, bool),
+
+        /// Emits on setting of the storage bucket operator.
+        /// Params:
+        /// - Id of the storage bucket.
+        /// - Id of the new operator (None if unset).
+        StorageBucketOperatorUpdated(StorageBucketId, Option<StorageProviderId>),
+
+        /// Emits on updating of the storage bucket status.
+        /// Params:
+        /// - Id of the storage bucket.
+        /// - Whether new bags can be assigned to the bucket.
+        StorageBucketStatusUpdated(StorageBucketId, bool),
+
+        /// Emits on updating of the storage bucket capacity limits.
+        /// Params:
+        /// - Id of the storage bucket.
+        /// - Size limit.
+        /// - Objects limit.
+        StorageBucketLimitsUpdated(StorageBucketId, u64, u64),
+
+        /// Emits on deleting of the storage bucket.
+        /// Params:
+        /// - Id of the storage bucket.
+        StorageBucketDeleted(StorageBucketId),
+
+        /// Emits on unassigning of the storage bucket from the bag.
+        /// Params:
+        /// - Id of the bag.
+        /// - Id of the storage bucket.
+        BagStorageBucketUnassigned(BagId, StorageBucketId),
+
+        /// Emits on updating of the number of storage buckets each bag is assigned to.
+        /// Params:
+        /// - New number of storage buckets per bag.
+        StorageBucketsPerBagUpdated(u64),
+
+        /// Emits when the number of ready replicas of the content falls below the target
+        /// replication factor.
+        /// Params:
+        /// - Id of the content.
+        /// - Number of ready replicas.
+        /// - Target replication factor.
+        ContentUnderReplicated(ContentId, u32, u32),
+
+        /// Emits on assigning of the storage buckets to the bag.
+        /// Params:
+        /// - Id of the bag.
+        /// - Ids of the newly assigned storage buckets.
+        BagStorageBucketsAssigned(BagId, Vec<StorageBucketId>),
+
+        /// Emits on issuing of the storage challenge.
+        /// Params:
+        /// - Id of the challenged relationship.
+        /// - Id of the challenged storage provider.
+        /// - Index of the challenged content chunk.
+        StorageChallengeIssued(DataObjectStorageRelationshipId, StorageProviderId, u64),
+
+        /// Emits on the valid storage challenge response.
+        /// Params:
+        /// - Id of the challenged relationship.
+        /// - Id of the challenged storage provider.
+        StorageChallengePassed(DataObjectStorageRelationshipId, StorageProviderId),
+
+        /// Emits when the storage challenge was not responded in time.
+        /// Params:
+        /// - Id of the challenged relationship.
+        /// - Id of the challenged storage provider.
+        StorageChallengeFailed(DataObjectStorageRelationshipId, StorageProviderId),
+
+        /// Emits on setting of the storage challenge slash amount.
+        /// Params:
+        /// - Role stake slashed on the failed storage challenge (None if slashing is disabled).
+        StorageChallengeSlashAmountUpdated(Option<Balance>),
     }
 }
 
@@ -140,21 +396,264 @@ decl_module! {
         /// Predefined errors.
         type Error = Error<T>;
 
-        /// Add storage provider-to-content relationship. The storage provider should be registered
-        /// in the storage working group.
+        /// Exports const - number of blocks between the storage challenges.
+        const StorageChallengePeriod: T::BlockNumber = T::StorageChallengePeriod::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let storage_challenge_period = T::StorageChallengePeriod::get();
+
+            if !storage_challenge_period.is_zero() && (now % storage_challenge_period).is_zero() {
+                Self::expire_storage_challenge();
+                Self::issue_storage_challenge(now + storage_challenge_period);
+            }
+
+            10_000_000 //TODO: adjust weight
+        }
+
+        /// Creates a storage bucket with given capacity limits. Requires leader privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_storage_bucket(
+            origin,
+            size_limit: u64,
+            objects_limit: u64,
+            accepting_new_bags: bool
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let storage_bucket_id = Self::next_storage_bucket_id();
+
+            let storage_bucket = StorageBucketRecord {
+                operator: None,
+                accepting_new_bags,
+                voucher: Voucher::new(size_limit, objects_limit),
+                assigned_bags: 0,
+            };
+
+            <StorageBucketById<T>>::insert(storage_bucket_id, storage_bucket);
+            <NextStorageBucketId<T>>::mutate(|n| {
+                *n += T::StorageBucketId::from(1);
+            });
+
+            Self::deposit_event(
+                RawEvent::StorageBucketCreated(storage_bucket_id, size_limit, objects_limit, accepting_new_bags)
+            );
+        }
+
+        /// Sets or unsets the storage provider operating the storage bucket. Requires leader privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_storage_bucket_operator(
+            origin,
+            storage_bucket_id: T::StorageBucketId,
+            operator: Option<StorageProviderId<T>>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let mut storage_bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            if let Some(storage_provider_id) = operator {
+                <StorageWorkingGroup<T>>::ensure_worker_exists(&storage_provider_id)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            storage_bucket.operator = operator;
+            <StorageBucketById<T>>::insert(storage_bucket_id, storage_bucket);
+
+            Self::deposit_event(RawEvent::StorageBucketOperatorUpdated(storage_bucket_id, operator));
+        }
+
+        /// Allows or forbids assigning new bags to the storage bucket. Requires leader privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_storage_bucket_status(
+            origin,
+            storage_bucket_id: T::StorageBucketId,
+            accepting_new_bags: bool
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let mut storage_bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            storage_bucket.accepting_new_bags = accepting_new_bags;
+            <StorageBucketById<T>>::insert(storage_bucket_id, storage_bucket);
+
+            Self::deposit_event(RawEvent::StorageBucketStatusUpdated(storage_bucket_id, accepting_new_bags));
+        }
+
+        /// Updates the capacity limits of the storage bucket, they cannot be less than the
+        /// capacity already used. Requires leader privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_storage_bucket_limits(
+            origin,
+            storage_bucket_id: T::StorageBucketId,
+            size_limit: u64,
+            objects_limit: u64
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let mut storage_bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            storage_bucket.voucher.set_new_size_limit::<T>(size_limit)
+                .map_err(|_| Error::<T>::StorageBucketLimitsLessThanUsed)?;
+            storage_bucket.voucher.set_new_objects_limit::<T>(objects_limit)
+                .map_err(|_| Error::<T>::StorageBucketLimitsLessThanUsed)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <StorageBucketById<T>>::insert(storage_bucket_id, storage_bucket);
+
+            Self::deposit_event(RawEvent::StorageBucketLimitsUpdated(storage_bucket_id, size_limit, objects_limit));
+        }
+
+        /// Deletes the storage bucket without bags assigned and content stored. Requires leader privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_storage_bucket(origin, storage_bucket_id: T::StorageBucketId) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let storage_bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            ensure!(
+                storage_bucket.assigned_bags == 0 && storage_bucket.voucher.get_objects_used() == 0,
+                Error::<T>::StorageBucketIsNotEmpty
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <StorageBucketById<T>>::remove(storage_bucket_id);
+
+            Self::deposit_event(RawEvent::StorageBucketDeleted(storage_bucket_id));
+        }
+
+        /// Unassigns the storage bucket from the bag, no more bag content can be stored in the bucket.
+        /// Content already stored in the bucket stays there until removed. Requires leader privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unassign_storage_bucket_from_bag(
+            origin,
+            bag_id: BagId<T>,
+            storage_bucket_id: T::StorageBucketId
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let mut storage_bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            let mut bag_storage_buckets = Self::bag_storage_buckets(&bag_id);
+
+            ensure!(
+                bag_storage_buckets.contains(&storage_bucket_id),
+                Error::<T>::StorageBucketNotAssignedToBag
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            bag_storage_buckets.retain(|id| *id != storage_bucket_id);
+            <BagStorageBuckets<T>>::insert(&bag_id, bag_storage_buckets);
+
+            storage_bucket.assigned_bags = storage_bucket.assigned_bags.saturating_sub(1);
+            <StorageBucketById<T>>::insert(storage_bucket_id, storage_bucket);
+
+            Self::deposit_event(RawEvent::BagStorageBucketUnassigned(bag_id, storage_bucket_id));
+        }
+
+        /// Sets the number of storage buckets each bag is assigned to. Requires leader privileges.
+        /// Applies to the bags assigned afterwards.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn add_relationship(origin, storage_provider_id: StorageProviderId<T>, cid: T::ContentId) {
+        pub fn update_storage_buckets_per_bag(origin, storage_buckets_per_bag: u64) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            ensure!(storage_buckets_per_bag > 0, Error::<T>::StorageBucketsPerBagIsZero);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            StorageBucketsPerBag::put(storage_buckets_per_bag);
+
+            Self::deposit_event(RawEvent::StorageBucketsPerBagUpdated(storage_buckets_per_bag));
+        }
+
+        /// Assigns storage buckets to the bag by the policy, up to the storage buckets per bag number.
+        /// Requires leader privileges. Bags are assigned on the content upload, this allows to
+        /// complete the assignment when not enough storage buckets were available at the time.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn assign_storage_buckets_to_bag(origin, bag_id: BagId<T>) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::assign_storage_buckets_by_policy(&bag_id);
+        }
+
+        /// Add storage bucket-to-content relationship. The storage provider should be registered
+        /// in the storage working group and operate the storage bucket assigned to the content bag.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_relationship(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            storage_bucket_id: T::StorageBucketId,
+            cid: T::ContentId
+        ) {
             // Origin should match storage provider.
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
             // Content ID must exist
-            ensure!(T::ContentIdExists::has_content(&cid), Error::<T>::CidNotFound);
+            let data_object = T::ContentIdExists::get_data_object(&cid)
+                .map_err(|_| Error::<T>::CidNotFound)?;
+
+            // Rejected content is not stored by the storage providers
+            ensure!(
+                data_object.liaison_judgement != LiaisonJudgement::Rejected,
+                Error::<T>::ContentRejected
+            );
+
+            let mut storage_bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            ensure!(
+                storage_bucket.operator == Some(storage_provider_id),
+                Error::<T>::NotStorageBucketOperator
+            );
+
+            // Content bag should be assigned to the storage bucket
+            ensure!(
+                Self::bag_storage_buckets(&data_object.owner).contains(&storage_bucket_id),
+                Error::<T>::StorageBucketNotAssignedToBag
+            );
+
+            ensure!(
+                !Self::relationships_by_content_id(cid)
+                    .iter()
+                    .filter_map(|id| Self::relationships(id))
+                    .any(|dosr| dosr.storage_bucket_id == storage_bucket_id),
+                Error::<T>::RelationshipAlreadyExists
+            );
+
+            // Ensure storage bucket capacity limits satisfied
+            storage_bucket.voucher = storage_bucket.voucher.fill_voucher::<T>(
+                data_directory::Delta { size: data_object.size, objects: 1 }
+            )?;
 
             // Create new ID, data.
             let new_id = Self::next_relationship_id();
             let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
                 content_id: cid,
                 storage_provider_id,
+                storage_bucket_id,
                 ready: false,
             };
 
@@ -162,6 +661,8 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            <StorageBucketById<T>>::insert(storage_bucket_id, storage_bucket);
+
             <Relationships<T>>::insert(new_id, dosr);
             <NextRelationshipId<T>>::mutate(|n| {
                 *n += T::DataObjectStorageRelationshipId::from(1);
@@ -175,12 +676,12 @@ decl_module! {
 
             // Emit event
             Self::deposit_event(
-                RawEvent::DataObjectStorageRelationshipAdded(new_id, cid, storage_provider_id)
+                RawEvent::DataObjectStorageRelationshipAdded(new_id, cid, storage_provider_id, storage_bucket_id)
             );
         }
 
         /// Activates storage provider-to-content relationship. The storage provider should be registered
-        /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
+        /// in the storage working group. Only the operator of the storage bucket may flip the ready state.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn set_relationship_ready(
             origin,
@@ -191,7 +692,7 @@ decl_module! {
         }
 
         /// Deactivates storage provider-to-content relationship. The storage provider should be registered
-        /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
+        /// in the storage working group. Only the operator of the storage bucket may flip the ready state.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn unset_relationship_ready(
             origin,
@@ -200,10 +701,141 @@ decl_module! {
         ) {
             Self::toggle_dosr_ready(origin, storage_provider_id, id, false)?;
         }
+
+        /// Responds to the storage challenge with the challenged content chunk and its merkle proof:
+        /// sibling hashes from the chunks level up to the chunks merkle root committed at the content
+        /// upload. The challenged storage provider can respond until the next period.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn respond_to_storage_challenge(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            chunk: Vec<u8>,
+            proof: Vec<T::Hash>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            let storage_challenge = Self::current_storage_challenge()
+                .ok_or(Error::<T>::NoActiveStorageChallenge)?;
+
+            let dosr = Self::relationships(storage_challenge.relationship_id)
+                .ok_or(Error::<T>::DataObjectStorageRelationshipNotFound)?;
+
+            ensure!(
+                dosr.storage_provider_id == storage_provider_id,
+                Error::<T>::NotChallengedStorageProvider
+            );
+
+            let data_object = T::ContentIdExists::get_data_object(&dosr.content_id)
+                .map_err(|_| Error::<T>::CidNotFound)?;
+
+            ensure!(
+                Self::verify_chunk_proof(
+                    &data_object.chunks_merkle_root,
+                    storage_challenge.chunk_index,
+                    data_object.chunks_count(),
+                    T::Hashing::hash(&chunk),
+                    &proof
+                ),
+                Error::<T>::InvalidStorageChallengeResponse
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <CurrentStorageChallenge<T>>::kill();
+
+            Self::deposit_event(
+                RawEvent::StorageChallengePassed(storage_challenge.relationship_id, storage_provider_id)
+            );
+        }
+
+        /// Sets the role stake slashed from the storage provider failing the storage challenge,
+        /// None disables slashing. Requires leader privileges.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_storage_challenge_slash_amount(origin, slash_amount: Option<BalanceOf<T>>) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            if let Some(slash_amount) = slash_amount {
+                ensure!(!slash_amount.is_zero(), Error::<T>::StorageChallengeSlashAmountIsZero);
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match slash_amount {
+                Some(slash_amount) => <StorageChallengeSlashAmount<T>>::put(slash_amount),
+                None => <StorageChallengeSlashAmount<T>>::kill(),
+            }
+
+            Self::deposit_event(RawEvent::StorageChallengeSlashAmountUpdated(slash_amount));
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
+    // Ensure storage bucket under given id exists, return corresponding one
+    fn ensure_storage_bucket_exists(
+        storage_bucket_id: &T::StorageBucketId,
+    ) -> Result<StorageBucket<T>, Error<T>> {
+        Self::storage_bucket_by_id(storage_bucket_id).ok_or(Error::<T>::StorageBucketNotFound)
+    }
+
+    // Assign the bag to the storage buckets with an operator, accepting new bags and having
+    // spare capacity, least loaded first. Returns the newly assigned storage buckets.
+    fn assign_storage_buckets_by_policy(bag_id: &BagId<T>) -> Vec<T::StorageBucketId> {
+        let mut bag_storage_buckets = Self::bag_storage_buckets(bag_id);
+
+        let storage_buckets_per_bag = Self::storage_buckets_per_bag() as usize;
+
+        if bag_storage_buckets.len() >= storage_buckets_per_bag {
+            return Vec::new();
+        }
+
+        let mut candidates: Vec<_> = <StorageBucketById<T>>::iter()
+            .filter(|(storage_bucket_id, storage_bucket)| {
+                storage_bucket.operator.is_some()
+                    && storage_bucket.accepting_new_bags
+                    && storage_bucket.voucher.get_objects_used()
+                        < storage_bucket.voucher.get_objects_limit()
+                    && !bag_storage_buckets.contains(storage_bucket_id)
+            })
+            .map(|(storage_bucket_id, storage_bucket)| {
+                (storage_bucket.assigned_bags, storage_bucket_id)
+            })
+            .collect();
+
+        // Storage map iteration order is arbitrary, sort to keep the policy deterministic
+        candidates.sort();
+
+        let assigned_storage_buckets: Vec<_> = candidates
+            .into_iter()
+            .take(storage_buckets_per_bag - bag_storage_buckets.len())
+            .map(|(_, storage_bucket_id)| storage_bucket_id)
+            .collect();
+
+        if !assigned_storage_buckets.is_empty() {
+            for storage_bucket_id in &assigned_storage_buckets {
+                <StorageBucketById<T>>::mutate(storage_bucket_id, |storage_bucket| {
+                    if let Some(storage_bucket) = storage_bucket {
+                        storage_bucket.assigned_bags += 1;
+                    }
+                });
+            }
+
+            bag_storage_buckets.extend(assigned_storage_buckets.iter().copied());
+            <BagStorageBuckets<T>>::insert(bag_id, bag_storage_buckets);
+
+            Self::deposit_event(RawEvent::BagStorageBucketsAssigned(
+                bag_id.clone(),
+                assigned_storage_buckets.clone(),
+            ));
+        }
+
+        assigned_storage_buckets
+    }
+
     fn toggle_dosr_ready(
         origin: T::Origin,
         storage_provider_id: StorageProviderId<T>,
@@ -216,11 +848,34 @@ impl<T: Trait> Module<T> {
         let mut dosr =
             Self::relationships(id).ok_or(Error::<T>::DataObjectStorageRelationshipNotFound)?;
 
+        // The current storage bucket operator stores the content, relationships migrated from
+        // before the storage buckets are not stored in any bucket and stay with their provider
+        let operator = match Self::storage_bucket_by_id(dosr.storage_bucket_id) {
+            Some(storage_bucket) => storage_bucket.operator,
+            None => Some(dosr.storage_provider_id),
+        };
+
         ensure!(
-            dosr.storage_provider_id == storage_provider_id,
+            operator == Some(storage_provider_id),
             Error::<T>::OnlyStorageProviderMayClaimReady
         );
 
+        dosr.storage_provider_id = storage_provider_id;
+
+        Self::set_dosr_ready(id, dosr, ready);
+
+        Ok(())
+    }
+
+    fn set_dosr_ready(
+        id: T::DataObjectStorageRelationshipId,
+        mut dosr: DataObjectStorageRelationship<T>,
+        ready: bool,
+    ) {
+        let storage_provider_id = dosr.storage_provider_id;
+        let content_id = dosr.content_id;
+        let ready_updated = dosr.ready != ready;
+
         // Flip to ready
         dosr.ready = ready;
 
@@ -232,6 +887,280 @@ impl<T: Trait> Module<T> {
             ready,
         ));
 
-        Ok(())
+        if ready_updated {
+            Self::update_ready_replicas(content_id, ready);
+
+            if ready {
+                if Self::has_chunks_merkle_root(&content_id) {
+                    Self::add_ready_relationship(id);
+                }
+            } else {
+                Self::remove_ready_relationship(id);
+            }
+        }
+    }
+
+    // Content uploaded before the chunks merkle roots cannot be challenged
+    fn has_chunks_merkle_root(content_id: &T::ContentId) -> bool {
+        T::ContentIdExists::get_data_object(content_id)
+            .map(|data_object| {
+                data_object.chunks_merkle_root.len() == T::Hash::default().as_ref().len()
+            })
+            .unwrap_or(false)
+    }
+
+    // Append the relationship to the ready relationships indexed for the storage challenges
+    fn add_ready_relationship(id: T::DataObjectStorageRelationshipId) {
+        let index = Self::ready_relationships_count();
+
+        <ReadyRelationshipByIndex<T>>::insert(index, id);
+        <ReadyRelationshipIndex<T>>::insert(id, index);
+        ReadyRelationshipsCount::put(index + 1);
+    }
+
+    // Remove the relationship from the ready relationships, moving the last one into its place
+    fn remove_ready_relationship(id: T::DataObjectStorageRelationshipId) {
+        if let Some(index) = <ReadyRelationshipIndex<T>>::take(id) {
+            let last_index = Self::ready_relationships_count().saturating_sub(1);
+
+            if index != last_index {
+                let last_id = Self::ready_relationship_by_index(last_index);
+
+                <ReadyRelationshipByIndex<T>>::insert(index, last_id);
+                <ReadyRelationshipIndex<T>>::insert(last_id, index);
+            }
+
+            <ReadyRelationshipByIndex<T>>::remove(last_index);
+            ReadyRelationshipsCount::put(last_index);
+        }
+    }
+
+    // Challenge a random ready relationship for a random chunk of its content
+    fn issue_storage_challenge(deadline: T::BlockNumber) {
+        let ready_relationships_count = Self::ready_relationships_count();
+
+        if ready_relationships_count == 0 {
+            return;
+        }
+
+        let random_seed = T::Randomness::random(STORAGE_CHALLENGE_RANDOMNESS_SUBJECT);
+        let mut random_input: &[u8] = random_seed.as_ref();
+        let relationship_random = u64::decode(&mut random_input).unwrap_or_default();
+        let chunk_random = u64::decode(&mut random_input).unwrap_or_default();
+
+        let relationship_id =
+            Self::ready_relationship_by_index(relationship_random % ready_relationships_count);
+
+        let dosr = match Self::relationships(relationship_id) {
+            Some(dosr) => dosr,
+            None => return,
+        };
+
+        let data_object = match T::ContentIdExists::get_data_object(&dosr.content_id) {
+            Ok(data_object) => data_object,
+            Err(_) => return,
+        };
+
+        // Responses to content uploaded before the chunks merkle roots cannot be verified
+        if data_object.chunks_merkle_root.len() != T::Hash::default().as_ref().len() {
+            return;
+        }
+
+        let chunk_index = chunk_random % data_object.chunks_count();
+
+        <CurrentStorageChallenge<T>>::put(StorageChallengeRecord {
+            relationship_id,
+            chunk_index,
+            deadline,
+        });
+
+        Self::deposit_event(RawEvent::StorageChallengeIssued(
+            relationship_id,
+            dosr.storage_provider_id,
+            chunk_index,
+        ));
+    }
+
+    // Fail the storage challenge not responded in time: unset the relationship ready state
+    // and slash the storage provider role stake if configured.
+    fn expire_storage_challenge() {
+        let storage_challenge = match <CurrentStorageChallenge<T>>::take() {
+            Some(storage_challenge) => storage_challenge,
+            None => return,
+        };
+
+        let dosr = match Self::relationships(storage_challenge.relationship_id) {
+            Some(dosr) => dosr,
+            None => return,
+        };
+
+        // The storage provider cannot be blamed for the content removed meanwhile
+        if !T::ContentIdExists::has_content(&dosr.content_id) {
+            return;
+        }
+
+        let storage_provider_id = dosr.storage_provider_id;
+
+        if dosr.ready {
+            Self::set_dosr_ready(storage_challenge.relationship_id, dosr, false);
+        }
+
+        if let Some(slash_amount) = Self::storage_challenge_slash_amount() {
+            // Storage providers hired without the role stake have nothing to slash
+            let _ =
+                <StorageWorkingGroup<T>>::slash_worker_stake(&storage_provider_id, slash_amount);
+        }
+
+        Self::deposit_event(RawEvent::StorageChallengeFailed(
+            storage_challenge.relationship_id,
+            storage_provider_id,
+        ));
+    }
+
+    // Verify the chunk hash against the chunks merkle root, hashing it with the proof siblings
+    // level by level. The last node of a level without a sibling is promoted to the next level.
+    pub(crate) fn verify_chunk_proof(
+        chunks_merkle_root: &[u8],
+        chunk_index: u64,
+        chunks_count: u64,
+        chunk_hash: T::Hash,
+        proof: &[T::Hash],
+    ) -> bool {
+        let mut hash = chunk_hash;
+        let mut index = chunk_index;
+        let mut level_width = chunks_count;
+        let mut siblings = proof.iter();
+
+        if index >= level_width {
+            return false;
+        }
+
+        while level_width > 1 {
+            if index % 2 == 1 || index + 1 < level_width {
+                let sibling = match siblings.next() {
+                    Some(sibling) => sibling,
+                    None => return false,
+                };
+
+                hash = if index % 2 == 1 {
+                    Self::hash_merkle_nodes(sibling, &hash)
+                } else {
+                    Self::hash_merkle_nodes(&hash, sibling)
+                };
+            }
+
+            index /= 2;
+            level_width = level_width / 2 + level_width % 2;
+        }
+
+        siblings.next().is_none() && hash.as_ref() == chunks_merkle_root
+    }
+
+    // Parent node of the chunks merkle tree
+    fn hash_merkle_nodes(left: &T::Hash, right: &T::Hash) -> T::Hash {
+        let mut nodes = left.as_ref().to_vec();
+        nodes.extend_from_slice(right.as_ref());
+
+        T::Hashing::hash(&nodes)
+    }
+
+    // Count the content replica as ready or not, alert when falling below the target replication factor
+    fn update_ready_replicas(content_id: T::ContentId, ready: bool) {
+        let ready_replicas = Self::ready_replicas_by_content_id(content_id);
+
+        let new_ready_replicas = if ready {
+            ready_replicas.saturating_add(1)
+        } else {
+            ready_replicas.saturating_sub(1)
+        };
+
+        <ReadyReplicasByContentId<T>>::insert(content_id, new_ready_replicas);
+
+        if !ready {
+            let replication_factor = Self::replication_factor(&content_id);
+
+            if new_ready_replicas < replication_factor && ready_replicas >= replication_factor {
+                Self::deposit_event(RawEvent::ContentUnderReplicated(
+                    content_id,
+                    new_ready_replicas,
+                    replication_factor,
+                ));
+            }
+        }
+    }
+
+    // Target number of ready replicas of the content
+    fn replication_factor(content_id: &T::ContentId) -> u32 {
+        match T::ContentIdExists::get_data_object(content_id) {
+            Ok(data_object) => {
+                <data_object_type_registry::Module<T>>::replication_factor(&data_object.type_id)
+            }
+            Err(_) => data_object_type_registry::DEFAULT_REPLICATION_FACTOR,
+        }
+    }
+
+    /// Content ids with less ready replicas than the target replication factor of
+    /// their data object type. Rejected content is not expected to be stored.
+    /// Returns at most `limit` content ids following `start_after` in the data objects
+    /// iteration order, the last returned content id starts the next page.
+    pub fn under_replicated_content_ids(
+        start_after: Option<T::ContentId>,
+        limit: u32,
+    ) -> Vec<T::ContentId> {
+        let mut data_objects = <data_directory::DataByContentId<T>>::iter();
+
+        if let Some(start_after) = start_after {
+            while let Some((content_id, _)) = data_objects.next() {
+                if content_id == start_after {
+                    break;
+                }
+            }
+        }
+
+        data_objects
+            .filter(|(content_id, data_object)| {
+                data_object.liaison_judgement != LiaisonJudgement::Rejected
+                    && Self::ready_replicas_by_content_id(content_id)
+                        < <data_object_type_registry::Module<T>>::replication_factor(
+                            &data_object.type_id,
+                        )
+            })
+            .map(|(content_id, _)| content_id)
+            .take(limit as usize)
+            .collect()
+    }
+}
+
+impl<T: Trait> data_directory::StorageBagAssigner<T> for Module<T> {
+    fn assign_storage_buckets(bag_id: &BagId<T>) {
+        Self::assign_storage_buckets_by_policy(bag_id);
+    }
+
+    fn remove_content_relationships(
+        content_id: &T::ContentId,
+        data_object: &data_directory::DataObject<T>,
+    ) {
+        let released = data_directory::Delta {
+            size: data_object.size,
+            objects: 1,
+        };
+
+        for id in <RelationshipsByContentId<T>>::take(content_id) {
+            if let Some(dosr) = <Relationships<T>>::take(id) {
+                if dosr.ready {
+                    Self::remove_ready_relationship(id);
+                }
+
+                <StorageBucketById<T>>::mutate(dosr.storage_bucket_id, |storage_bucket| {
+                    if let Some(storage_bucket) = storage_bucket {
+                        if let Ok(voucher) = storage_bucket.voucher.release_voucher::<T>(released) {
+                            storage_bucket.voucher = voucher;
+                        }
+                    }
+                });
+            }
+        }
+
+        <ReadyReplicasByContentId<T>>::remove(content_id);
     }
 }
@@ -33,6 +33,12 @@ use crate::{DataObjectTypeId, StorageWorkingGroup, StorageWorkingGroupInstance};
 const DEFAULT_TYPE_DESCRIPTION: &str = "Default data object type for audio and video content.";
 const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u8 = 1;
 
+/// Version of the module storage layout, the runtime upgrade migrates older layouts
+pub(crate) const STORAGE_VERSION: u32 = 1;
+
+/// The default number of ready replicas targeted for the data objects
+pub const DEFAULT_REPLICATION_FACTOR: u32 = 1;
+
 /// The _Data object type registry_ main _Trait_.
 pub trait Trait:
     frame_system::Trait
@@ -63,6 +69,9 @@ pub struct DataObjectType {
 
     /// Active/Disabled flag.
     pub active: bool,
+
+    /// Target number of ready replicas of the data objects.
+    pub replication_factor: u32,
 }
 
 impl Default for DataObjectType {
@@ -70,6 +79,7 @@ impl Default for DataObjectType {
         DataObjectType {
             description: DEFAULT_TYPE_DESCRIPTION.as_bytes().to_vec(),
             active: true,
+            replication_factor: DEFAULT_REPLICATION_FACTOR,
         }
     }
 }
@@ -87,6 +97,9 @@ decl_storage! {
         /// Mapping of Data object types.
         pub DataObjectTypes get(fn data_object_types): map hasher(blake2_128_concat)
             DataObjectTypeId<T> => Option<DataObjectType>;
+
+        /// Version of the storage layout, chains started before the replication factors are at version zero.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>| STORAGE_VERSION): u32;
     }
 }
 
@@ -140,6 +153,7 @@ decl_module! {
             let do_type: DataObjectType = DataObjectType {
                 description: data_object_type.description.clone(),
                 active: data_object_type.active,
+                replication_factor: data_object_type.replication_factor,
             };
 
             //
@@ -161,6 +175,7 @@ decl_module! {
 
             do_type.description = data_object_type.description.clone();
             do_type.active = data_object_type.active;
+            do_type.replication_factor = data_object_type.replication_factor;
 
             //
             // == MUTATION SAFE ==
@@ -210,6 +225,14 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    /// Target number of ready replicas of the data objects of given type.
+    /// Unknown types fall back to the default replication factor.
+    pub fn replication_factor(id: &T::DataObjectTypeId) -> u32 {
+        Self::data_object_types(id)
+            .map(|do_type| do_type.replication_factor)
+            .unwrap_or(DEFAULT_REPLICATION_FACTOR)
+    }
+
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, DispatchError> {
         Self::data_object_types(&id).ok_or_else(|| Error::<T>::DataObjectTypeNotFound.into())
     }
@@ -4,10 +4,13 @@
 pub mod data_directory;
 pub mod data_object_storage_registry;
 pub mod data_object_type_registry;
+pub mod migration;
+pub mod runtime_api;
 
 mod tests;
 
 pub use common::storage::StorageObjectOwner;
+pub use runtime_api::*;
 
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
@@ -0,0 +1,189 @@
+use codec::Decode;
+use frame_support::storage::{IterableStorageMap, StorageMap, StorageValue};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+use crate::data_directory::{
+    self, BlockAndTime, ContentHash, DataObjectInternal, LiaisonJudgement,
+};
+use crate::data_object_storage_registry::{self, DataObjectStorageRelationship};
+use crate::data_object_type_registry::{self, DataObjectType, DEFAULT_REPLICATION_FACTOR};
+use crate::*;
+
+/// Data object layout before content hashes and chunks merkle roots.
+#[derive(Decode)]
+pub struct OldDataObjectInternal<
+    MemberId,
+    ChannelId,
+    DAOId,
+    BlockNumber,
+    Moment,
+    DataObjectTypeId,
+    StorageProviderId,
+> {
+    pub owner: StorageObjectOwner<MemberId, ChannelId, DAOId>,
+    pub added_at: BlockAndTime<BlockNumber, Moment>,
+    pub type_id: DataObjectTypeId,
+    pub size: u64,
+    pub liaison: Option<StorageProviderId>,
+    pub liaison_judgement: LiaisonJudgement,
+    pub ipfs_content_id: Vec<u8>,
+}
+
+impl<MemberId, ChannelId, DAOId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId>
+    OldDataObjectInternal<
+        MemberId,
+        ChannelId,
+        DAOId,
+        BlockNumber,
+        Moment,
+        DataObjectTypeId,
+        StorageProviderId,
+    >
+{
+    /// Current data object layout. Content uploaded before the content hashes has neither
+    /// a content hash digest nor a chunks merkle root.
+    pub fn migrate(
+        self,
+    ) -> DataObjectInternal<
+        MemberId,
+        ChannelId,
+        DAOId,
+        BlockNumber,
+        Moment,
+        DataObjectTypeId,
+        StorageProviderId,
+    > {
+        DataObjectInternal {
+            owner: self.owner,
+            added_at: self.added_at,
+            type_id: self.type_id,
+            size: self.size,
+            liaison: self.liaison,
+            liaison_judgement: self.liaison_judgement,
+            ipfs_content_id: self.ipfs_content_id,
+            content_hash: ContentHash::default(),
+            chunks_merkle_root: Vec::new(),
+        }
+    }
+}
+
+/// Data object of the runtime, in the layout before content hashes and chunks merkle roots.
+pub type OldDataObject<T> = OldDataObjectInternal<
+    MemberId<T>,
+    ChannelId<T>,
+    DAOId<T>,
+    <T as frame_system::Trait>::BlockNumber,
+    <T as pallet_timestamp::Trait>::Moment,
+    DataObjectTypeId<T>,
+    StorageProviderId<T>,
+>;
+
+/// Data object type layout before replication factors.
+#[derive(Decode)]
+struct OldDataObjectType {
+    description: Vec<u8>,
+    active: bool,
+}
+
+/// Data object storage relationship layout before storage buckets.
+#[derive(Decode)]
+struct OldDataObjectStorageRelationship<ContentId, StorageProviderId> {
+    content_id: ContentId,
+    storage_provider_id: StorageProviderId,
+    ready: bool,
+}
+
+impl<T: data_directory::Trait> data_directory::Module<T> {
+    /// Translate data objects stored by the previous runtime into the current layout,
+    /// once per storage version.
+    pub fn migrate_data_objects() -> Weight {
+        if Self::storage_version() >= data_directory::STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: u64 = 0;
+
+        data_directory::DataByContentId::<T>::translate(|_, old: OldDataObject<T>| {
+            translated += 1;
+            Some(old.migrate())
+        });
+
+        data_directory::StorageVersion::put(data_directory::STORAGE_VERSION);
+
+        T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+    }
+}
+
+impl<T: data_object_type_registry::Trait> data_object_type_registry::Module<T> {
+    /// Translate data object types stored by the previous runtime into the current layout,
+    /// with the default replication factor, once per storage version.
+    pub fn migrate_data_object_types() -> Weight {
+        if Self::storage_version() >= data_object_type_registry::STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: u64 = 0;
+
+        data_object_type_registry::DataObjectTypes::<T>::translate(|_, old: OldDataObjectType| {
+            translated += 1;
+            Some(DataObjectType {
+                description: old.description,
+                active: old.active,
+                replication_factor: DEFAULT_REPLICATION_FACTOR,
+            })
+        });
+
+        data_object_type_registry::StorageVersion::put(data_object_type_registry::STORAGE_VERSION);
+
+        T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+    }
+}
+
+impl<T: data_object_storage_registry::Trait> data_object_storage_registry::Module<T> {
+    /// Translate storage relationships stored by the previous runtime into the current layout,
+    /// once per storage version. They are not stored in any storage bucket: bucket ids start
+    /// at one, so the default bucket id never refers to an existing bucket. Ready relationships
+    /// are counted as the content ready replicas.
+    pub fn migrate_relationships() -> Weight {
+        if Self::storage_version() >= data_object_storage_registry::STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: u64 = 0;
+        let mut ready = Vec::new();
+
+        data_object_storage_registry::Relationships::<T>::translate(
+            |_, old: OldDataObjectStorageRelationship<T::ContentId, StorageProviderId<T>>| {
+                translated += 1;
+                if old.ready {
+                    ready.push(old.content_id);
+                }
+
+                Some(DataObjectStorageRelationship {
+                    content_id: old.content_id,
+                    storage_provider_id: old.storage_provider_id,
+                    storage_bucket_id: T::StorageBucketId::default(),
+                    ready: old.ready,
+                })
+            },
+        );
+
+        let counted = ready.len() as u64;
+        for content_id in ready {
+            data_object_storage_registry::ReadyReplicasByContentId::<T>::mutate(
+                content_id,
+                |ready_replicas| *ready_replicas = ready_replicas.saturating_add(1),
+            );
+        }
+
+        data_object_storage_registry::StorageVersion::put(
+            data_object_storage_registry::STORAGE_VERSION,
+        );
+
+        T::DbWeight::get()
+            .reads_writes(translated + 1, translated + 1)
+            .saturating_add(T::DbWeight::get().reads_writes(counted, counted))
+    }
+}
@@ -0,0 +1,15 @@
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Storage system queries for storage nodes
+    pub trait StorageApi<ContentId>
+    where
+        ContentId: Codec,
+    {
+        /// Content ids with less ready replicas than the target replication factor
+        /// of their data object type, rejected content excluded. Returns at most `limit`
+        /// content ids following `start_after`, the last one starts the next page
+        fn under_replicated_content_ids(start_after: Option<ContentId>, limit: u32) -> Vec<ContentId>;
+    }
+}
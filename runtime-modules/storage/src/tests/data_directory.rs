@@ -1,7 +1,8 @@
 #![cfg(test)]
 
-use crate::data_directory::Error;
+use crate::data_directory::{DataByContentId, Error, LiaisonJudgement, Vouchers};
 use common::storage::StorageObjectOwner;
+use common::storage::StorageSystem;
 use frame_support::assert_ok;
 use frame_support::dispatch::DispatchError;
 use frame_system::RawOrigin;
@@ -19,6 +20,8 @@ fn succeed_adding_content() {
             type_id: 1234,
             size: 0,
             ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         let second_content_parameters = ContentParameters {
@@ -26,6 +29,8 @@ fn succeed_adding_content() {
             type_id: 2,
             size: 20,
             ipfs_content_id: vec![1, 2, 7, 9],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         let multi_content = vec![first_content_parameters, second_content_parameters];
@@ -36,6 +41,42 @@ fn succeed_adding_content() {
     });
 }
 
+#[test]
+fn dao_can_add_content_using_its_voucher() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let owner = StorageObjectOwner::DAO(1u64);
+
+        let content_parameters = ContentParameters {
+            content_id: 1,
+            type_id: 1234,
+            size: 20,
+            ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
+        };
+
+        // DAO content must be uploaded by the DAO itself
+        let res = TestDataDirectory::add_content(
+            RawOrigin::Root.into(),
+            owner.clone(),
+            vec![content_parameters.clone()],
+        );
+        assert_eq!(res, Err(DispatchError::Other("Bad origin")));
+
+        assert_ok!(TestDataDirectory::add_content(
+            Origin::signed(sender),
+            owner.clone(),
+            vec![content_parameters]
+        ));
+
+        // DAO voucher is used
+        let voucher = TestDataDirectory::vouchers(&owner);
+        assert_eq!(voucher.get_objects_used(), 1);
+        assert_eq!(voucher.get_size_used(), 20);
+    });
+}
+
 #[test]
 fn add_content_fails_with_invalid_origin() {
     with_default_mock_builder(|| {
@@ -46,6 +87,8 @@ fn add_content_fails_with_invalid_origin() {
             type_id: 1234,
             size: 0,
             ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         // Make an attempt to register a content with 1234 bytes of type 1, which should be recognized.
@@ -55,6 +98,57 @@ fn add_content_fails_with_invalid_origin() {
     });
 }
 
+#[test]
+fn add_content_fails_with_invalid_content_hash() {
+    with_default_mock_builder(|| {
+        let owner = StorageObjectOwner::Member(1u64);
+
+        let content_parameters = ContentParameters {
+            content_id: 1,
+            type_id: 1234,
+            size: 20,
+            ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
+        };
+
+        // Digest length should match the hashing algorithm
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            owner.clone(),
+            vec![ContentParameters {
+                content_hash: ContentHash {
+                    algorithm: ContentHashAlgorithm::Sha512,
+                    ..test_content_hash()
+                },
+                ..content_parameters.clone()
+            }],
+        );
+        assert_eq!(res, Err(Error::<Test>::InvalidContentHash.into()));
+
+        // Chunks merkle root should be of the runtime hash length
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            owner.clone(),
+            vec![ContentParameters {
+                chunks_merkle_root: vec![1, 2, 3, 4],
+                ..content_parameters.clone()
+            }],
+        );
+        assert_eq!(res, Err(Error::<Test>::InvalidChunksMerkleRoot.into()));
+
+        assert_ok!(TestDataDirectory::add_content(
+            Origin::signed(1),
+            owner,
+            vec![content_parameters]
+        ));
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(1).content_hash,
+            test_content_hash()
+        );
+    });
+}
+
 #[test]
 fn add_content_uploading_blocked() {
     ExtBuilder::default()
@@ -70,6 +164,8 @@ fn add_content_uploading_blocked() {
                 type_id: 1234,
                 size: 0,
                 ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
             };
 
             // Make an attempt to register a content, when uploading is blocked.
@@ -94,6 +190,8 @@ fn add_content_size_limit_reached() {
             type_id: 1234,
             size: DEFAULT_VOUCHER.get_size_limit() + 1,
             ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         // Make an attempt to register a content, when uploading is blocked.
@@ -118,6 +216,8 @@ fn add_content_objects_limit_reached() {
                 type_id: 1234,
                 size: 0,
                 ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
             };
             content.push(content_parameters);
         }
@@ -149,6 +249,8 @@ fn add_content_global_size_limit_reached() {
                 type_id: 1234,
                 size: global_voucher_size_limit + 1,
                 ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
             };
 
             // Make an attempt to register a content, when uploading is blocked.
@@ -182,6 +284,8 @@ fn add_content_global_objects_limit_reached() {
                 type_id: 1234,
                 size: 0,
                 ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
             };
 
             // Make an attempt to register a content, when uploading is blocked.
@@ -208,6 +312,8 @@ fn delete_content() {
             type_id: 1234,
             size: 1,
             ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         // Register a content with 1234 bytes of type 1, which should be recognized.
@@ -259,6 +365,8 @@ fn delete_content_owners_are_not_equal() {
             type_id: 1234,
             size: 1,
             ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         // Register a content with 1234 bytes of type 1, which should be recognized.
@@ -281,6 +389,52 @@ fn delete_content_owners_are_not_equal() {
     });
 }
 
+#[test]
+fn delete_all_owner_content() {
+    with_default_mock_builder(|| {
+        let owner = StorageObjectOwner::Channel(1u64);
+
+        let content: Vec<_> = (1..=2)
+            .map(|content_id| ContentParameters {
+                content_id,
+                type_id: 1234,
+                size: 1,
+                ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
+            })
+            .collect();
+
+        assert_ok!(TestDataDirectory::atomically_add_content(
+            owner.clone(),
+            content
+        ));
+
+        // Content ids must be unique
+        assert_eq!(
+            TestDataDirectory::atomically_remove_all_content(&owner, &[1, 1]),
+            Err(Error::<Test>::DuplicateContentIds.into())
+        );
+
+        // All the content of the owner must be removed
+        assert_eq!(
+            TestDataDirectory::atomically_remove_all_content(&owner, &[1]),
+            Err(Error::<Test>::OwnerContentNotFullyRemoved.into())
+        );
+
+        assert_ok!(TestDataDirectory::atomically_remove_all_content(
+            &owner,
+            &[1, 2]
+        ));
+
+        // Content removed and owner voucher released
+        assert!(!<DataByContentId<Test>>::contains_key(1));
+        assert!(!<DataByContentId<Test>>::contains_key(2));
+        assert!(!<Vouchers<Test>>::contains_key(&owner));
+        assert_eq!(TestDataDirectory::global_voucher().get_objects_used(), 0);
+    });
+}
+
 #[test]
 fn update_content_uploading_status() {
     with_default_mock_builder(|| {
@@ -409,6 +563,8 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
             type_id: 1234,
             size: 0,
             ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         let res =
@@ -452,6 +608,8 @@ fn accept_content_as_liaison() {
             type_id: 1234,
             size: 0,
             ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
         };
 
         let res =
@@ -485,6 +643,149 @@ fn accept_content_as_liaison() {
     });
 }
 
+#[test]
+fn reject_content_as_liaison() {
+    with_default_mock_builder(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let sender = 1u64;
+        let owner = StorageObjectOwner::Member(1u64);
+        let content_id = 1;
+
+        let content_parameters = ContentParameters {
+            content_id,
+            type_id: 1234,
+            size: 20,
+            ipfs_content_id: vec![1, 2, 3, 4],
+            content_hash: test_content_hash(),
+            chunks_merkle_root: test_chunks_merkle_root(),
+        };
+
+        assert_ok!(TestDataDirectory::add_content(
+            Origin::signed(sender),
+            owner.clone(),
+            vec![content_parameters]
+        ));
+
+        let global_voucher = TestDataDirectory::global_voucher();
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        assert_ok!(TestDataDirectory::reject_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+            b"corrupt upload".to_vec()
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::ContentRejected(
+                content_id,
+                storage_provider_id,
+                b"corrupt upload".to_vec()
+            ))
+        );
+
+        let data_object = TestDataDirectory::data_object_by_content_id(content_id);
+        assert_eq!(data_object.liaison_judgement, LiaisonJudgement::Rejected);
+        assert_eq!(data_object.liaison, Some(storage_provider_id));
+
+        // Voucher consumed by the upload is released
+        let voucher = TestDataDirectory::vouchers(&owner);
+        assert_eq!(voucher.get_objects_used(), 0);
+        assert_eq!(voucher.get_size_used(), 0);
+        assert_eq!(
+            TestDataDirectory::global_voucher().get_size_used(),
+            global_voucher.get_size_used() - 20
+        );
+
+        // Judgement can only be made once
+        let res = TestDataDirectory::reject_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+            vec![],
+        );
+        assert_eq!(res, Err(Error::<Test>::ContentIsNotPending.into()));
+
+        // Rejected content can still be removed by the owner
+        assert_ok!(TestDataDirectory::remove_content(
+            Origin::signed(sender),
+            owner.clone(),
+            vec![content_id]
+        ));
+        assert!(!<DataByContentId<Test>>::contains_key(content_id));
+        assert_eq!(TestDataDirectory::vouchers(&owner).get_objects_used(), 0);
+    });
+}
+
+#[test]
+fn pending_content_rejected_after_upload_timeout() {
+    with_default_mock_builder(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let sender = 1u64;
+        let owner = StorageObjectOwner::Member(1u64);
+
+        let content: Vec<_> = (1..=2)
+            .map(|content_id| ContentParameters {
+                content_id,
+                type_id: 1234,
+                size: 1,
+                ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
+            })
+            .collect();
+
+        assert_ok!(TestDataDirectory::add_content(
+            Origin::signed(sender),
+            owner.clone(),
+            content
+        ));
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        assert_ok!(TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            1
+        ));
+
+        run_to_block(1 + ContentUploadTimeout::get());
+
+        // Only the content still awaiting judgement is rejected
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::ContentUploadTimedOut(vec![2]))
+        );
+
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(1).liaison_judgement,
+            LiaisonJudgement::Accepted
+        );
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(2).liaison_judgement,
+            LiaisonJudgement::Rejected
+        );
+
+        let voucher = TestDataDirectory::vouchers(&owner);
+        assert_eq!(voucher.get_objects_used(), 1);
+        assert_eq!(voucher.get_size_used(), 1);
+    });
+}
+
 #[test]
 fn set_global_voucher_limits() {
     with_default_mock_builder(|| {
@@ -1,6 +1,60 @@
 #![cfg(test)]
 
 use super::mock::*;
+use crate::data_directory::{self, ContentIdExists, StorageBagAssigner};
+use crate::data_object_storage_registry::{
+    Error, ReadyReplicasByContentId, StorageBucketRecord, StorageChallengeRecord,
+};
+use frame_support::assert_ok;
+use frame_support::traits::Get;
+use frame_support::StorageMap;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+const STORAGE_BUCKET_SIZE_LIMIT: u64 = 10_000;
+const STORAGE_BUCKET_OBJECTS_LIMIT: u64 = 10;
+
+fn create_storage_bucket(size_limit: u64, accepting_new_bags: bool) -> u64 {
+    let storage_bucket_id = TestDataObjectStorageRegistry::next_storage_bucket_id();
+
+    assert_ok!(TestDataObjectStorageRegistry::create_storage_bucket(
+        Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+        size_limit,
+        STORAGE_BUCKET_OBJECTS_LIMIT,
+        accepting_new_bags
+    ));
+
+    storage_bucket_id
+}
+
+// Create a storage bucket operated by the storage provider, without assigning any bags to it
+fn create_operated_storage_bucket(storage_provider_id: u32, accepting_new_bags: bool) -> u64 {
+    let storage_bucket_id = create_storage_bucket(STORAGE_BUCKET_SIZE_LIMIT, accepting_new_bags);
+
+    assert_ok!(TestDataObjectStorageRegistry::set_storage_bucket_operator(
+        Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+        storage_bucket_id,
+        Some(storage_provider_id)
+    ));
+
+    storage_bucket_id
+}
+
+// Create a storage bucket operated by the storage provider and assign the bag of the mocked content to it
+fn create_storage_bucket_for_existing_content(storage_provider_id: u32) -> u64 {
+    SetLeadFixture::set_default_lead();
+
+    let storage_bucket_id = create_operated_storage_bucket(storage_provider_id, true);
+
+    assert_ok!(
+        TestDataObjectStorageRegistry::assign_storage_buckets_to_bag(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            StorageObjectOwner::Member(1)
+        )
+    );
+
+    storage_bucket_id
+}
 
 #[test]
 fn initial_state() {
@@ -20,6 +74,7 @@ fn add_relationship_fails_with_invalid_authorization() {
         let res = TestDataObjectStorageRegistry::add_relationship(
             Origin::signed(account_id),
             storage_provider_id,
+            1,
             TEST_MOCK_EXISTING_CID,
         );
         assert_eq!(res, Err(working_group::Error::<Test, crate::StorageWorkingGroupInstance>::WorkerDoesNotExist.into()));
@@ -30,10 +85,12 @@ fn add_relationship_fails_with_invalid_authorization() {
 fn set_relationship_ready_fails_with_invalid_authorization() {
     with_default_mock_builder(|| {
         let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
         // The content needs to exist - in our mock, that's with the content ID TEST_MOCK_EXISTING_CID
         let res = TestDataObjectStorageRegistry::add_relationship(
             Origin::signed(account_id),
             storage_provider_id,
+            storage_bucket_id,
             TEST_MOCK_EXISTING_CID,
         );
         assert!(res.is_ok());
@@ -52,10 +109,12 @@ fn set_relationship_ready_fails_with_invalid_authorization() {
 fn unset_relationship_ready_fails_with_invalid_authorization() {
     with_default_mock_builder(|| {
         let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
         // The content needs to exist - in our mock, that's with the content ID TEST_MOCK_EXISTING_CID
         let res = TestDataObjectStorageRegistry::add_relationship(
             Origin::signed(account_id),
             storage_provider_id,
+            storage_bucket_id,
             TEST_MOCK_EXISTING_CID,
         );
         assert!(res.is_ok());
@@ -74,10 +133,12 @@ fn unset_relationship_ready_fails_with_invalid_authorization() {
 fn test_add_relationship() {
     with_default_mock_builder(|| {
         let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
         // The content needs to exist - in our mock, that's with the content ID TEST_MOCK_EXISTING_CID
         let res = TestDataObjectStorageRegistry::add_relationship(
             Origin::signed(account_id),
             storage_provider_id,
+            storage_bucket_id,
             TEST_MOCK_EXISTING_CID,
         );
         assert_eq!(res, Ok(()));
@@ -88,9 +149,11 @@ fn test_add_relationship() {
 fn test_fail_adding_relationship_with_bad_content() {
     with_default_mock_builder(|| {
         let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
         let res = TestDataObjectStorageRegistry::add_relationship(
             Origin::signed(account_id),
             storage_provider_id,
+            storage_bucket_id,
             24,
         );
         assert!(res.is_err());
@@ -108,10 +171,12 @@ fn test_toggle_ready() {
         run_to_block(1);
 
         let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
         // Create a DOSR
         let res = TestDataObjectStorageRegistry::add_relationship(
             Origin::signed(account_id),
             storage_provider_id,
+            storage_bucket_id,
             TEST_MOCK_EXISTING_CID,
         );
         assert!(res.is_ok());
@@ -123,6 +188,7 @@ fn test_toggle_ready() {
                     dosr_id,
                     _content_id,
                     _account_id,
+                    _storage_bucket_id,
                 ),
             ) => dosr_id,
             _ => 0xdeadbeefu64, // invalid value, unlikely to match
@@ -164,3 +230,874 @@ fn test_toggle_ready() {
         );
     });
 }
+
+#[test]
+fn storage_bucket_management() {
+    with_default_mock_builder(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        // Only the lead can manage storage buckets
+        let res = TestDataObjectStorageRegistry::create_storage_bucket(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            STORAGE_BUCKET_SIZE_LIMIT,
+            STORAGE_BUCKET_OBJECTS_LIMIT,
+            true,
+        );
+        assert!(res.is_err());
+
+        SetLeadFixture::set_default_lead();
+
+        let storage_bucket_id = create_storage_bucket(STORAGE_BUCKET_SIZE_LIMIT, true);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageBucketCreated(
+                    storage_bucket_id,
+                    STORAGE_BUCKET_SIZE_LIMIT,
+                    STORAGE_BUCKET_OBJECTS_LIMIT,
+                    true,
+                )
+            )
+        );
+
+        // Operator must be a storage working group worker
+        let res = TestDataObjectStorageRegistry::set_storage_bucket_operator(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+            Some(5),
+        );
+        assert_eq!(res, Err(working_group::Error::<Test, crate::StorageWorkingGroupInstance>::WorkerDoesNotExist.into()));
+
+        let res = TestDataObjectStorageRegistry::set_storage_bucket_operator(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id + 1,
+            None,
+        );
+        assert_eq!(res, Err(Error::<Test>::StorageBucketNotFound.into()));
+
+        let (_, storage_provider_id) = hire_storage_provider();
+
+        assert_ok!(TestDataObjectStorageRegistry::set_storage_bucket_operator(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+            Some(storage_provider_id)
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageBucketOperatorUpdated(
+                    storage_bucket_id,
+                    Some(storage_provider_id),
+                )
+            )
+        );
+
+        assert_ok!(TestDataObjectStorageRegistry::update_storage_bucket_status(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+            false
+        ));
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_bucket_by_id(storage_bucket_id),
+            Some(StorageBucketRecord {
+                operator: Some(storage_provider_id),
+                accepting_new_bags: false,
+                voucher: data_directory::Voucher::new(
+                    STORAGE_BUCKET_SIZE_LIMIT,
+                    STORAGE_BUCKET_OBJECTS_LIMIT
+                ),
+                assigned_bags: 0,
+            })
+        );
+
+        let res = TestDataObjectStorageRegistry::update_storage_buckets_per_bag(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            0,
+        );
+        assert_eq!(res, Err(Error::<Test>::StorageBucketsPerBagIsZero.into()));
+    });
+}
+
+#[test]
+fn storage_bucket_limits_and_removal() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
+
+        assert_ok!(TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        ));
+
+        // Limits cannot be less than the used capacity
+        let res = TestDataObjectStorageRegistry::update_storage_bucket_limits(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+            1000,
+            STORAGE_BUCKET_OBJECTS_LIMIT,
+        );
+        assert_eq!(
+            res,
+            Err(Error::<Test>::StorageBucketLimitsLessThanUsed.into())
+        );
+
+        assert_ok!(TestDataObjectStorageRegistry::update_storage_bucket_limits(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+            2000,
+            1
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageBucketLimitsUpdated(
+                    storage_bucket_id,
+                    2000,
+                    1,
+                )
+            )
+        );
+
+        let voucher = TestDataObjectStorageRegistry::storage_bucket_by_id(storage_bucket_id)
+            .unwrap()
+            .voucher;
+        assert_eq!(voucher.get_size_limit(), 2000);
+        assert_eq!(voucher.get_objects_limit(), 1);
+
+        // Storage bucket with bags assigned cannot be deleted
+        let res = TestDataObjectStorageRegistry::delete_storage_bucket(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+        );
+        assert_eq!(res, Err(Error::<Test>::StorageBucketIsNotEmpty.into()));
+
+        assert_ok!(
+            TestDataObjectStorageRegistry::unassign_storage_bucket_from_bag(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                StorageObjectOwner::Member(1),
+                storage_bucket_id
+            )
+        );
+
+        assert!(
+            TestDataObjectStorageRegistry::bag_storage_buckets(&StorageObjectOwner::Member(1))
+                .is_empty()
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_bucket_by_id(storage_bucket_id)
+                .unwrap()
+                .assigned_bags,
+            0
+        );
+
+        let res = TestDataObjectStorageRegistry::unassign_storage_bucket_from_bag(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            StorageObjectOwner::Member(1),
+            storage_bucket_id,
+        );
+        assert_eq!(
+            res,
+            Err(Error::<Test>::StorageBucketNotAssignedToBag.into())
+        );
+
+        // Storage bucket with content stored cannot be deleted
+        let res = TestDataObjectStorageRegistry::delete_storage_bucket(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+        );
+        assert_eq!(res, Err(Error::<Test>::StorageBucketIsNotEmpty.into()));
+
+        let empty_storage_bucket_id = create_storage_bucket(STORAGE_BUCKET_SIZE_LIMIT, true);
+
+        assert_ok!(TestDataObjectStorageRegistry::delete_storage_bucket(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            empty_storage_bucket_id
+        ));
+
+        assert!(
+            TestDataObjectStorageRegistry::storage_bucket_by_id(empty_storage_bucket_id).is_none()
+        );
+    });
+}
+
+#[test]
+fn relationship_ready_set_by_storage_bucket_operator() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
+
+        let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+
+        assert_ok!(TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        ));
+
+        // Storage provider no longer operating the storage bucket cannot set the relationship ready
+        assert_ok!(TestDataObjectStorageRegistry::set_storage_bucket_operator(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+            None
+        ));
+
+        let res = TestDataObjectStorageRegistry::set_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+        );
+        assert_eq!(
+            res,
+            Err(Error::<Test>::OnlyStorageProviderMayClaimReady.into())
+        );
+
+        assert_ok!(TestDataObjectStorageRegistry::set_storage_bucket_operator(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            storage_bucket_id,
+            Some(storage_provider_id)
+        ));
+
+        assert_ok!(TestDataObjectStorageRegistry::set_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+        ));
+    });
+}
+
+#[test]
+fn bags_assigned_to_least_loaded_storage_buckets() {
+    with_default_mock_builder(|| {
+        SetLeadFixture::set_default_lead();
+        let (_, storage_provider_id) = hire_storage_provider();
+
+        let first_storage_bucket_id = create_operated_storage_bucket(storage_provider_id, true);
+        let second_storage_bucket_id = create_operated_storage_bucket(storage_provider_id, true);
+
+        // Storage buckets not accepting new bags or without an operator are skipped
+        create_operated_storage_bucket(storage_provider_id, false);
+        create_storage_bucket(STORAGE_BUCKET_SIZE_LIMIT, true);
+
+        let first_bag_id = StorageObjectOwner::Member(1);
+        let second_bag_id = StorageObjectOwner::Member(2);
+
+        assert_ok!(
+            TestDataObjectStorageRegistry::assign_storage_buckets_to_bag(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                first_bag_id.clone()
+            )
+        );
+        assert_ok!(
+            TestDataObjectStorageRegistry::assign_storage_buckets_to_bag(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                second_bag_id.clone()
+            )
+        );
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::bag_storage_buckets(&first_bag_id),
+            vec![first_storage_bucket_id]
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::bag_storage_buckets(&second_bag_id),
+            vec![second_storage_bucket_id]
+        );
+
+        // Raising the storage buckets per bag number tops up the bag assignment
+        assert_ok!(
+            TestDataObjectStorageRegistry::update_storage_buckets_per_bag(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                2
+            )
+        );
+
+        assert_ok!(
+            TestDataObjectStorageRegistry::assign_storage_buckets_to_bag(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                first_bag_id.clone()
+            )
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::bag_storage_buckets(&first_bag_id),
+            vec![first_storage_bucket_id, second_storage_bucket_id]
+        );
+
+        // Bag of the uploaded content is assigned on upload
+        let third_bag_id = StorageObjectOwner::Member(3);
+
+        assert_ok!(TestDataDirectory::add_content(
+            Origin::signed(1),
+            third_bag_id.clone(),
+            vec![ContentParameters {
+                content_id: 1,
+                type_id: 1234,
+                size: 1,
+                ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
+            }]
+        ));
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::bag_storage_buckets(&third_bag_id),
+            vec![first_storage_bucket_id, second_storage_bucket_id]
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_bucket_by_id(second_storage_bucket_id)
+                .unwrap()
+                .assigned_bags,
+            3
+        );
+    });
+}
+
+#[test]
+fn add_relationship_requires_storage_bucket_assigned_to_bag() {
+    with_default_mock_builder(|| {
+        SetLeadFixture::set_default_lead();
+        let (account_id, storage_provider_id) = hire_storage_provider();
+
+        // Storage bucket is not assigned to the bag of the mocked content
+        let storage_bucket_id = create_operated_storage_bucket(storage_provider_id, false);
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(
+            res,
+            Err(Error::<Test>::StorageBucketNotAssignedToBag.into())
+        );
+
+        // Storage bucket has not enough capacity for the mocked content
+        let small_storage_bucket_id = create_storage_bucket(100, true);
+        assert_ok!(TestDataObjectStorageRegistry::set_storage_bucket_operator(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            small_storage_bucket_id,
+            Some(storage_provider_id)
+        ));
+        assert_ok!(
+            TestDataObjectStorageRegistry::assign_storage_buckets_to_bag(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                StorageObjectOwner::Member(1)
+            )
+        );
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            small_storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::VoucherSizeLimitExceeded.into())
+        );
+
+        // Only the storage bucket operator can add the relationship
+        assert_ok!(TestDataObjectStorageRegistry::set_storage_bucket_operator(
+            Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+            small_storage_bucket_id,
+            None
+        ));
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            small_storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(res, Err(Error::<Test>::NotStorageBucketOperator.into()));
+
+        // Content is stored in the storage bucket only once
+        assert_ok!(
+            TestDataObjectStorageRegistry::update_storage_buckets_per_bag(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                2
+            )
+        );
+
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
+
+        assert_ok!(TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        ));
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(res, Err(Error::<Test>::RelationshipAlreadyExists.into()));
+
+        // Storage bucket capacity is used
+        let voucher = TestDataObjectStorageRegistry::storage_bucket_by_id(storage_bucket_id)
+            .unwrap()
+            .voucher;
+        assert_eq!(voucher.get_objects_used(), 1);
+        assert_eq!(voucher.get_size_used(), 1234);
+    });
+}
+
+#[test]
+fn content_removal_removes_relationships() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
+
+        let relationship_id = TestDataObjectStorageRegistry::next_relationship_id();
+
+        assert_ok!(TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        ));
+        assert_ok!(TestDataObjectStorageRegistry::set_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            relationship_id,
+        ));
+
+        let data_object = MockContent::get_data_object(&TEST_MOCK_EXISTING_CID).unwrap();
+
+        <TestDataObjectStorageRegistry as StorageBagAssigner<Test>>::remove_content_relationships(
+            &TEST_MOCK_EXISTING_CID,
+            &data_object,
+        );
+
+        assert!(TestDataObjectStorageRegistry::relationships(relationship_id).is_none());
+        assert!(
+            TestDataObjectStorageRegistry::relationships_by_content_id(TEST_MOCK_EXISTING_CID)
+                .is_empty()
+        );
+
+        // Ready replicas of the removed content are no longer tracked or challenged
+        assert!(!ReadyReplicasByContentId::<Test>::contains_key(
+            TEST_MOCK_EXISTING_CID
+        ));
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_relationship_index(relationship_id),
+            None
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_relationships_count(),
+            0
+        );
+
+        // Storage bucket capacity is released
+        let voucher = TestDataObjectStorageRegistry::storage_bucket_by_id(storage_bucket_id)
+            .unwrap()
+            .voucher;
+        assert_eq!(voucher.get_objects_used(), 0);
+        assert_eq!(voucher.get_size_used(), 0);
+    });
+}
+
+#[test]
+fn ready_replicas_counted_against_replication_factor() {
+    with_default_mock_builder(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
+
+        let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+        assert_ok!(TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID,
+        ));
+
+        // Replica is counted once, however many times the relationship is set ready
+        for _ in 0..2 {
+            assert_ok!(TestDataObjectStorageRegistry::set_relationship_ready(
+                Origin::signed(account_id),
+                storage_provider_id,
+                dosr_id,
+            ));
+        }
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(TEST_MOCK_EXISTING_CID),
+            1
+        );
+
+        // Mocked content type is unknown, so the default replication factor is targeted
+        assert_ok!(TestDataObjectStorageRegistry::unset_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+        ));
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(TEST_MOCK_EXISTING_CID),
+            0
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ContentUnderReplicated(
+                    TEST_MOCK_EXISTING_CID,
+                    0,
+                    data_object_type_registry::DEFAULT_REPLICATION_FACTOR,
+                )
+            )
+        );
+    });
+}
+
+#[test]
+fn under_replicated_content_ids() {
+    with_default_mock_builder(|| {
+        let owner = StorageObjectOwner::Member(1u64);
+
+        let content: Vec<_> = (1..=3)
+            .map(|content_id| ContentParameters {
+                content_id,
+                type_id: 1234,
+                size: 1,
+                ipfs_content_id: vec![1, 2, 3, 4],
+                content_hash: test_content_hash(),
+                chunks_merkle_root: test_chunks_merkle_root(),
+            })
+            .collect();
+
+        assert_ok!(TestDataDirectory::add_content(
+            Origin::signed(1),
+            owner,
+            content
+        ));
+
+        // Content replicated up to the default replication factor
+        <ReadyReplicasByContentId<Test>>::insert(1, 1);
+
+        // Rejected content is not expected to be stored
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        assert_ok!(TestDataDirectory::reject_content(
+            Origin::signed(account_id),
+            storage_provider_id,
+            2,
+            vec![]
+        ));
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(None, 10),
+            vec![3]
+        );
+
+        // Paginated in the data objects iteration order
+        <ReadyReplicasByContentId<Test>>::remove(1);
+
+        let all = TestDataObjectStorageRegistry::under_replicated_content_ids(None, 10);
+        assert_eq!(all.len(), 2);
+
+        let first_page = TestDataObjectStorageRegistry::under_replicated_content_ids(None, 1);
+        assert_eq!(first_page, vec![all[0]]);
+
+        let second_page =
+            TestDataObjectStorageRegistry::under_replicated_content_ids(Some(all[0]), 1);
+        assert_eq!(second_page, vec![all[1]]);
+
+        assert!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(Some(all[1]), 1).is_empty()
+        );
+    });
+}
+
+// Add a ready relationship of the mocked content, returns its id along with the storage provider
+fn create_ready_relationship_for_existing_content() -> (u64, u32, u64) {
+    let (account_id, storage_provider_id) = hire_storage_provider();
+    let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
+
+    let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+    assert_ok!(TestDataObjectStorageRegistry::add_relationship(
+        Origin::signed(account_id),
+        storage_provider_id,
+        storage_bucket_id,
+        TEST_MOCK_EXISTING_CID,
+    ));
+    assert_ok!(TestDataObjectStorageRegistry::set_relationship_ready(
+        Origin::signed(account_id),
+        storage_provider_id,
+        dosr_id,
+    ));
+
+    (account_id, storage_provider_id, dosr_id)
+}
+
+fn hash_merkle_nodes(left: &H256, right: &H256) -> H256 {
+    BlakeTwo256::hash(&[left.as_bytes(), right.as_bytes()].concat())
+}
+
+#[test]
+fn storage_challenge_passed() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id, dosr_id) =
+            create_ready_relationship_for_existing_content();
+
+        let challenge_period: u64 = StorageChallengePeriod::get();
+
+        // No challenge until the period starts
+        assert_eq!(
+            TestDataObjectStorageRegistry::respond_to_storage_challenge(
+                Origin::signed(account_id),
+                storage_provider_id,
+                TEST_MOCK_EXISTING_CONTENT_CHUNK.to_vec(),
+                vec![]
+            ),
+            Err(Error::<Test>::NoActiveStorageChallenge.into())
+        );
+
+        run_to_block(challenge_period);
+
+        // The mocked content has a single chunk
+        assert_eq!(
+            TestDataObjectStorageRegistry::current_storage_challenge(),
+            Some(StorageChallengeRecord {
+                relationship_id: dosr_id,
+                chunk_index: 0,
+                deadline: 2 * challenge_period,
+            })
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengeIssued(
+                    dosr_id,
+                    storage_provider_id,
+                    0
+                )
+            )
+        );
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::respond_to_storage_challenge(
+                Origin::signed(account_id),
+                storage_provider_id,
+                b"another chunk".to_vec(),
+                vec![]
+            ),
+            Err(Error::<Test>::InvalidStorageChallengeResponse.into())
+        );
+
+        // The chunk hash is computed from the submitted chunk
+        assert_eq!(
+            TestDataObjectStorageRegistry::respond_to_storage_challenge(
+                Origin::signed(account_id),
+                storage_provider_id,
+                BlakeTwo256::hash(TEST_MOCK_EXISTING_CONTENT_CHUNK)
+                    .as_bytes()
+                    .to_vec(),
+                vec![]
+            ),
+            Err(Error::<Test>::InvalidStorageChallengeResponse.into())
+        );
+
+        assert_ok!(TestDataObjectStorageRegistry::respond_to_storage_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CONTENT_CHUNK.to_vec(),
+            vec![]
+        ));
+        assert_eq!(
+            TestDataObjectStorageRegistry::current_storage_challenge(),
+            None
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengePassed(
+                    dosr_id,
+                    storage_provider_id
+                )
+            )
+        );
+
+        // Passed challenge keeps the relationship ready
+        run_to_block(2 * challenge_period);
+
+        assert!(
+            TestDataObjectStorageRegistry::relationships(dosr_id)
+                .unwrap()
+                .ready
+        );
+        assert!(TestDataObjectStorageRegistry::current_storage_challenge().is_some());
+    });
+}
+
+#[test]
+fn storage_challenge_failure_unsets_relationship_ready() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (_, storage_provider_id, dosr_id) = create_ready_relationship_for_existing_content();
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::set_storage_challenge_slash_amount(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                Some(0)
+            ),
+            Err(Error::<Test>::StorageChallengeSlashAmountIsZero.into())
+        );
+        assert_ok!(
+            TestDataObjectStorageRegistry::set_storage_challenge_slash_amount(
+                Origin::signed(DEFAULT_LEADER_ACCOUNT_ID),
+                Some(100)
+            )
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_challenge_slash_amount(),
+            Some(100)
+        );
+
+        let challenge_period: u64 = StorageChallengePeriod::get();
+
+        run_to_block(challenge_period);
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_relationships_count(),
+            1
+        );
+
+        // The challenge is not responded until the next period
+        run_to_block(2 * challenge_period);
+
+        assert!(
+            !TestDataObjectStorageRegistry::relationships(dosr_id)
+                .unwrap()
+                .ready
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(TEST_MOCK_EXISTING_CID),
+            0
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_relationships_count(),
+            0
+        );
+
+        // No ready relationships left to challenge
+        assert_eq!(
+            TestDataObjectStorageRegistry::current_storage_challenge(),
+            None
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengeFailed(
+                    dosr_id,
+                    storage_provider_id
+                )
+            )
+        );
+    });
+}
+
+#[test]
+fn content_without_chunks_merkle_root_is_not_challenged() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let storage_bucket_id = create_storage_bucket_for_existing_content(storage_provider_id);
+
+        let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+        assert_ok!(TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            storage_bucket_id,
+            TEST_MOCK_EXISTING_CID_WITHOUT_MERKLE_ROOT,
+        ));
+        assert_ok!(TestDataObjectStorageRegistry::set_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+        ));
+
+        // Counted as a ready replica but not indexed for the storage challenges
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(
+                TEST_MOCK_EXISTING_CID_WITHOUT_MERKLE_ROOT
+            ),
+            1
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_relationships_count(),
+            0
+        );
+
+        run_to_block(StorageChallengePeriod::get());
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::current_storage_challenge(),
+            None
+        );
+
+        assert_ok!(TestDataObjectStorageRegistry::unset_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+        ));
+    });
+}
+
+#[test]
+fn verify_chunk_proof() {
+    with_default_mock_builder(|| {
+        let chunks: Vec<H256> = (0u8..5).map(|i| BlakeTwo256::hash(&[i])).collect();
+
+        // The last node of a level without a sibling is promoted to the next level
+        let first_pair = hash_merkle_nodes(&chunks[0], &chunks[1]);
+        let second_pair = hash_merkle_nodes(&chunks[2], &chunks[3]);
+        let first_four = hash_merkle_nodes(&first_pair, &second_pair);
+        let root = hash_merkle_nodes(&first_four, &chunks[4]);
+
+        let verify = |chunk_index: u64, chunk_hash: H256, proof: &[H256]| {
+            TestDataObjectStorageRegistry::verify_chunk_proof(
+                root.as_bytes(),
+                chunk_index,
+                chunks.len() as u64,
+                chunk_hash,
+                proof,
+            )
+        };
+
+        assert!(verify(2, chunks[2], &[chunks[3], first_pair, chunks[4]]));
+        assert!(verify(4, chunks[4], &[first_four]));
+
+        // Wrong chunk, sibling order or proof length
+        assert!(!verify(2, chunks[3], &[chunks[3], first_pair, chunks[4]]));
+        assert!(!verify(2, chunks[2], &[first_pair, chunks[3], chunks[4]]));
+        assert!(!verify(4, chunks[4], &[first_four, chunks[0]]));
+        assert!(!verify(4, chunks[4], &[]));
+
+        // Chunk index out of range
+        assert!(!verify(5, chunks[4], &[first_four]));
+    });
+}
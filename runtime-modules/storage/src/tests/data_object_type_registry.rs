@@ -34,6 +34,7 @@ fn succeed_register() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -59,6 +60,7 @@ fn activate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -99,6 +101,7 @@ fn deactivate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: true,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -139,6 +142,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -150,6 +154,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
 
         let invalid_leader_account_id = 2;
@@ -184,6 +189,7 @@ fn update_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -198,6 +204,7 @@ fn update_existing() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -210,6 +217,7 @@ fn update_existing() {
         let updated3: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -237,6 +245,7 @@ fn register_data_object_type_failed_with_no_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -264,6 +273,7 @@ fn activate_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -1,18 +1,20 @@
 #![cfg(test)]
 
 use frame_support::storage::StorageMap;
-use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::traits::{OnFinalize, OnInitialize, Randomness};
 use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash, IdentityLookup},
     Perbill,
 };
 
 use crate::data_directory::ContentIdExists;
 pub use crate::data_directory::Voucher;
-pub use crate::data_directory::{ContentParameters, StorageObjectOwner};
+pub use crate::data_directory::{
+    ContentHash, ContentHashAlgorithm, ContentParameters, StorageObjectOwner,
+};
 use crate::data_object_type_registry::IsActiveDataObjectType;
 use crate::ContentId;
 pub use crate::StorageWorkingGroupInstance;
@@ -83,6 +85,28 @@ pub const TEST_FIRST_RELATIONSHIP_ID: u64 = 3000;
 
 pub const TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID: u32 = 1;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_MOCK_EXISTING_CONTENT_CHUNK: &[u8] = b"mock content chunk";
+pub const TEST_MOCK_EXISTING_CID_WITHOUT_MERKLE_ROOT: u64 = 43;
+
+// Content hash with the digest length matching the hashing algorithm
+pub fn test_content_hash() -> ContentHash {
+    ContentHash {
+        algorithm: ContentHashAlgorithm::Blake2b256,
+        digest: vec![1; 32],
+    }
+}
+
+// Chunks merkle root of the runtime hash length
+pub fn test_chunks_merkle_root() -> Vec<u8> {
+    H256::repeat_byte(1).as_bytes().to_vec()
+}
+
+pub struct MockRandomness;
+impl Randomness<H256> for MockRandomness {
+    fn random(_subject: &[u8]) -> H256 {
+        H256::zero()
+    }
+}
 
 pub struct AnyDataObjectTypeIsActive {}
 impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataObjectTypeIsActive {
@@ -94,7 +118,7 @@ impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataO
 pub struct MockContent {}
 impl ContentIdExists<Test> for MockContent {
     fn has_content(which: &ContentId<Test>) -> bool {
-        *which == TEST_MOCK_EXISTING_CID
+        *which == TEST_MOCK_EXISTING_CID || *which == TEST_MOCK_EXISTING_CID_WITHOUT_MERKLE_ROOT
     }
 
     fn get_data_object(
@@ -112,6 +136,16 @@ impl ContentIdExists<Test> for MockContent {
                 liaison: Some(TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID),
                 liaison_judgement: data_directory::LiaisonJudgement::Pending,
                 ipfs_content_id: vec![],
+                content_hash: test_content_hash(),
+                // The mocked content fits into a single chunk
+                chunks_merkle_root: BlakeTwo256::hash(TEST_MOCK_EXISTING_CONTENT_CHUNK)
+                    .as_ref()
+                    .to_vec(),
+            }),
+            // Content uploaded before the chunks merkle roots
+            TEST_MOCK_EXISTING_CID_WITHOUT_MERKLE_ROOT => Ok(data_directory::DataObjectInternal {
+                chunks_merkle_root: Vec::new(),
+                ..Self::get_data_object(&TEST_MOCK_EXISTING_CID)?
             }),
             _ => Err(data_directory::Error::<Test>::CidNotFound),
         }
@@ -208,10 +242,17 @@ impl data_object_type_registry::Trait for Test {
     type Event = MetaEvent;
 }
 
+parameter_types! {
+    pub const ContentUploadTimeout: u64 = 10;
+}
+
 impl data_directory::Trait for Test {
     type Event = MetaEvent;
     type IsActiveDataObjectType = AnyDataObjectTypeIsActive;
     type MemberOriginValidator = ();
+    type DaoOriginValidator = ();
+    type ContentUploadTimeout = ContentUploadTimeout;
+    type StorageBagAssigner = TestDataObjectStorageRegistry;
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -222,10 +263,17 @@ impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
     }
 }
 
+parameter_types! {
+    pub const StorageChallengePeriod: u64 = 10;
+}
+
 impl data_object_storage_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectStorageRelationshipId = u64;
+    type StorageBucketId = u64;
     type ContentIdExists = MockContent;
+    type Randomness = MockRandomness;
+    type StorageChallengePeriod = StorageChallengePeriod;
 }
 
 parameter_types! {
@@ -937,27 +937,7 @@ decl_module! {
             // Ensure lead is set or it is the council terminating the leader.
             Self::ensure_origin_for_leader(origin, worker_id)?;
 
-            // Ensuring worker actually exists.
-            let worker = Self::ensure_worker_exists(&worker_id)?;
-
-            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
-
-            let stake_profile = worker.role_stake_profile.ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
-
-            //
-            // == MUTATION SAFE ==
-            //
-
-            // This external module call both checks and mutates the state.
-            ensure_on_wrapped_error!(
-                <stake::Module<T>>::slash_immediate(
-                    &stake_profile.stake_id,
-                    balance,
-                    false
-                )
-            )?;
-
-            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+            Self::slash_worker_stake(&worker_id, balance)?;
         }
 
         /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
@@ -1433,6 +1413,38 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         <NegativeImbalance<T>>::zero()
     }
 
+    /// Slashes the worker stake without an origin check. Used by other modules to punish
+    /// misbehaving workers. If slashing balance greater than the existing stake - stake is
+    /// slashed to zero.
+    pub fn slash_worker_stake(worker_id: &WorkerId<T>, balance: BalanceOf<T>) -> DispatchResult {
+        // Ensuring worker actually exists.
+        let worker = Self::ensure_worker_exists(worker_id)?;
+
+        ensure!(
+            balance != <BalanceOf<T>>::zero(),
+            Error::<T, I>::StakeBalanceCannotBeZero
+        );
+
+        let stake_profile = worker
+            .role_stake_profile
+            .ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // This external module call both checks and mutates the state.
+        ensure_on_wrapped_error!(<stake::Module<T>>::slash_immediate(
+            &stake_profile.stake_id,
+            balance,
+            false
+        ))?;
+
+        Self::deposit_event(RawEvent::StakeSlashed(*worker_id));
+
+        Ok(())
+    }
+
     /// Returns all existing worker id list excluding the current leader worker id.
     pub fn get_regular_worker_ids() -> Vec<WorkerId<T>> {
         let lead_worker_id = Self::current_lead();
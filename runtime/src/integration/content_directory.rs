@@ -44,4 +44,8 @@ impl content::ContentActorAuthenticator for Runtime {
     fn is_valid_curator_id(curator_id: &Self::CuratorId) -> bool {
         ContentDirectoryWorkingGroup::<Runtime>::ensure_worker_exists(curator_id).is_ok()
     }
+
+    fn is_dao_account(dao_id: &Self::DAOId, account_id: &AccountId) -> bool {
+        dao::Module::<Runtime>::is_dao_account(*dao_id, account_id)
+    }
 }
@@ -48,7 +48,7 @@ use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_core::crypto::KeyTypeId;
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT, IdentityLookup, OpaqueKeys, Saturating};
-use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, Perbill};
+use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, ModuleId, Perbill};
 use sp_std::boxed::Box;
 use sp_std::vec::Vec;
 #[cfg(feature = "std")]
@@ -74,18 +74,19 @@ pub use pallet_balances::Call as BalancesCall;
 pub use pallet_staking::StakerStatus;
 pub use proposals_codex::ProposalsConfigParameters;
 use storage::data_directory::Voucher;
-pub use storage::{data_directory, data_object_type_registry};
+pub use storage::{data_directory, data_object_type_registry, migration as storage_migration};
 pub use working_group;
 
 pub use content;
 pub use content::MaxNumber;
+pub use dao;
 
 /// This runtime version.
 pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 9,
-    spec_version: 8,
+    spec_version: 9,
     impl_version: 0,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -430,12 +431,29 @@ impl pallet_finality_tracker::Trait for Runtime {
 
 parameter_types! {
     pub const MaxNumberOfCuratorsPerGroup: MaxNumber = 50;
+    pub const MaxNumberOfVideosPerPlaylist: MaxNumber = 200;
+    pub const MaxNumberOfFeaturedVideos: MaxNumber = 20;
+    pub const MaxNumberOfVideosPerBatch: MaxNumber = 20;
+    pub const MaxCategoryDepth: MaxNumber = 3;
     pub const ChannelOwnershipPaymentEscrowId: [u8; 8] = *b"chescrow";
+    pub const CensorshipAppealStake: Balance = 1000;
+    pub const CensorshipAppealPeriod: BlockNumber = 7 * DAYS;
+    pub const NftPaymentEscrowId: [u8; 8] = *b"nftescrw";
+    pub const ContentReportPoolId: [u8; 8] = *b"reportpl";
+    pub const ContentReportStake: Balance = 100;
+    pub const ContentReportReward: Balance = 50;
+    pub const MaxCreatorRoyalty: Perbill = Perbill::from_percent(50);
+    pub const MinAuctionDuration: BlockNumber = HOURS;
+    pub const MaxAuctionDuration: BlockNumber = 30 * DAYS;
+    pub const MinSubscriptionPeriod: BlockNumber = DAYS;
+    pub const MaxSubscriptionRenewalsPerBlock: u32 = 100;
 }
 
 impl content::Trait for Runtime {
     type Event = Event;
     type ChannelOwnershipPaymentEscrowId = ChannelOwnershipPaymentEscrowId;
+    type NftPaymentEscrowId = NftPaymentEscrowId;
+    type ContentReportPoolId = ContentReportPoolId;
     type ChannelCategoryId = ChannelCategoryId;
     type VideoId = VideoId;
     type VideoCategoryId = VideoCategoryId;
@@ -443,10 +461,41 @@ impl content::Trait for Runtime {
     type PersonId = PersonId;
     type SeriesId = SeriesId;
     type ChannelOwnershipTransferRequestId = ChannelOwnershipTransferRequestId;
+    type CensorshipAppealId = CensorshipAppealId;
+    type CommentId = CommentId;
+    type ContentReportId = ContentReportId;
+    type SubscriptionTierId = SubscriptionTierId;
+    type CensorshipAppealStake = CensorshipAppealStake;
+    type CensorshipAppealPeriod = CensorshipAppealPeriod;
+    type ContentReportStake = ContentReportStake;
+    type ContentReportReward = ContentReportReward;
     type MaxNumberOfCuratorsPerGroup = MaxNumberOfCuratorsPerGroup;
+    type MaxNumberOfVideosPerPlaylist = MaxNumberOfVideosPerPlaylist;
+    type MaxNumberOfFeaturedVideos = MaxNumberOfFeaturedVideos;
+    type MaxNumberOfVideosPerBatch = MaxNumberOfVideosPerBatch;
+    type MaxCategoryDepth = MaxCategoryDepth;
+    type MaxCreatorRoyalty = MaxCreatorRoyalty;
+    type MinAuctionDuration = MinAuctionDuration;
+    type MaxAuctionDuration = MaxAuctionDuration;
+    type MinSubscriptionPeriod = MinSubscriptionPeriod;
+    type MaxSubscriptionRenewalsPerBlock = MaxSubscriptionRenewalsPerBlock;
     type StorageSystem = data_directory::Module<Self>;
 }
 
+parameter_types! {
+    pub const DaoModuleId: ModuleId = ModuleId(*b"jsdaoacc");
+    pub const MaxMembersPerDao: u32 = 50;
+}
+
+impl dao::Trait for Runtime {
+    type Event = Event;
+    type DaoProposalId = u64;
+    type DaoCall = Call;
+    type MemberOriginValidator = MembershipOriginValidator<Self>;
+    type DaoModuleId = DaoModuleId;
+    type MaxMembersPerDao = MaxMembersPerDao;
+}
+
 impl hiring::Trait for Runtime {
     type OpeningId = u64;
     type ApplicationId = u64;
@@ -522,6 +571,8 @@ impl memo::Trait for Runtime {
 
 parameter_types! {
     pub const DefaultVoucher: Voucher = Voucher::new(5000, 50);
+    pub const ContentUploadTimeout: BlockNumber = DAYS;
+    pub const StorageChallengePeriod: BlockNumber = HOURS;
 }
 
 impl storage::data_object_type_registry::Trait for Runtime {
@@ -532,12 +583,18 @@ impl storage::data_directory::Trait for Runtime {
     type Event = Event;
     type IsActiveDataObjectType = DataObjectTypeRegistry;
     type MemberOriginValidator = MembershipOriginValidator<Self>;
+    type DaoOriginValidator = Dao;
+    type ContentUploadTimeout = ContentUploadTimeout;
+    type StorageBagAssigner = DataObjectStorageRegistry;
 }
 
 impl storage::data_object_storage_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectStorageRelationshipId = u64;
+    type StorageBucketId = u64;
     type ContentIdExists = DataDirectory;
+    type Randomness = RandomnessCollectiveFlip;
+    type StorageChallengePeriod = StorageChallengePeriod;
 }
 
 parameter_types! {
@@ -727,5 +784,7 @@ construct_runtime!(
         ContentDirectoryWorkingGroup: working_group::<Instance3>::{Module, Call, Storage, Config<T>, Event<T>},
         OperationsWorkingGroup: working_group::<Instance4>::{Module, Call, Storage, Config<T>, Event<T>},
         GatewayWorkingGroup: working_group::<Instance5>::{Module, Call, Storage, Config<T>, Event<T>},
+        // --- DAOs
+        Dao: dao::{Module, Call, Storage, Event<T>},
     }
 );
@@ -70,6 +70,18 @@ pub type SeriesId = u64;
 /// Content Directory Channel transfer request identifier.
 pub type ChannelOwnershipTransferRequestId = u64;
 
+/// Content Directory censorship appeal identifier.
+pub type CensorshipAppealId = u64;
+
+/// Content Directory video comment identifier.
+pub type CommentId = u64;
+
+/// Content Directory content report identifier.
+pub type ContentReportId = u64;
+
+/// Content Directory channel subscription tier identifier.
+pub type SubscriptionTierId = u64;
+
 /// Represents a thread identifier for both Forum and Proposals Discussion
 ///
 /// Note: Both modules expose type names ThreadId and PostId (which are defined on their Trait) and
@@ -13,12 +13,13 @@ use sp_std::vec::Vec;
 use crate::constants::PRIMARY_PROBABILITY;
 
 use crate::{
-    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration, GrandpaAuthorityList,
-    GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, ChannelId, ContentId, EpochDuration,
+    GrandpaAuthorityList, GrandpaId, Hash, Index, MemberId, RuntimeVersion, Signature, VERSION,
 };
 use crate::{
-    AllModules, AuthorityDiscovery, Babe, Call, Grandpa, Historical, InherentDataExt,
-    RandomnessCollectiveFlip, Runtime, SessionKeys, System, TransactionPayment,
+    AllModules, AuthorityDiscovery, Babe, Call, Content, DataDirectory, DataObjectStorageRegistry,
+    DataObjectTypeRegistry, Grandpa, Historical, InherentDataExt, RandomnessCollectiveFlip,
+    Runtime, SessionKeys, System, TransactionPayment,
 };
 use frame_support::weights::Weight;
 
@@ -58,7 +59,15 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, Call, Signa
 pub struct CustomOnRuntimeUpgrade;
 impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
     fn on_runtime_upgrade() -> Weight {
-        10_000_000 // TODO: adjust weight
+        // Storage is migrated first, the content migration reads the migrated data objects
+        let storage_weight = DataDirectory::migrate_data_objects()
+            .saturating_add(DataObjectTypeRegistry::migrate_data_object_types())
+            .saturating_add(DataObjectStorageRegistry::migrate_relationships());
+        let content_weight = Content::migrate_content_storage();
+
+        storage_weight
+            .saturating_add(content_weight)
+            .saturating_add(10_000_000) // TODO: adjust weight
     }
 }
 
@@ -241,4 +250,16 @@ impl_runtime_apis! {
             SessionKeys::decode_into_raw_public_keys(&encoded)
         }
     }
+
+    impl content::ContentApi<Block, MemberId, ChannelId> for Runtime {
+        fn has_active_subscription(member_id: MemberId, channel_id: ChannelId) -> bool {
+            Content::has_active_subscription(&member_id, &channel_id)
+        }
+    }
+
+    impl storage::StorageApi<Block, ContentId> for Runtime {
+        fn under_replicated_content_ids(start_after: Option<ContentId>, limit: u32) -> Vec<ContentId> {
+            DataObjectStorageRegistry::under_replicated_content_ids(start_after, limit)
+        }
+    }
 }
@@ -1,5 +1,6 @@
 import axios, { AxiosRequestConfig } from 'axios'
 import fs from 'fs'
+import crypto from 'crypto'
 import ipfsHash from 'ipfs-only-hash'
 import { ContentId, DataObject } from '@joystream/types/storage'
 import BN from 'bn.js'
@@ -7,12 +8,15 @@ import { BaseCommand } from './base'
 import Debug from 'debug'
 import chalk from 'chalk'
 import { aliceKeyPair } from './dev'
+import { chunkHashes, chunksMerkleRoot } from '@joystream/storage-node-backend/chunks'
 const debug = Debug('joystream:storage-cli:upload')
 
 // Defines the necessary parameters for the AddContent runtime tx.
 interface AddContentParams {
   accountId: string
   ipfsCid: string
+  contentHash: { algorithm: string; digest: Buffer }
+  chunksMerkleRoot: Uint8Array
   contentId: ContentId
   fileSize: BN
   dataObjectTypeId: number
@@ -65,6 +69,29 @@ export class UploadCommand extends BaseCommand {
     return await ipfsHash.of(file)
   }
 
+  // Reads the file from the filesystem and computes its sha256 hash.
+  private async computeSha256(): Promise<Buffer> {
+    const hash = crypto.createHash('sha256')
+
+    return new Promise<Buffer>((resolve) => {
+      fs.createReadStream(this.mediaSourceFilePath)
+        .on('error', (err) => {
+          this.fail(`File read failed: ${err}`)
+        })
+        .on('data', (data) => hash.update(data))
+        .on('end', () => resolve(hash.digest()))
+    })
+  }
+
+  // Reads the file from the filesystem and computes the merkle root of its chunk hashes.
+  private async computeChunksMerkleRoot(): Promise<Uint8Array> {
+    const file = fs.createReadStream(this.mediaSourceFilePath).on('error', (err) => {
+      this.fail(`File read failed: ${err}`)
+    })
+
+    return chunksMerkleRoot(await chunkHashes(file))
+  }
+
   // Read the file size from the file system.
   private getFileSize(): number {
     const stats = fs.statSync(this.mediaSourceFilePath)
@@ -89,6 +116,8 @@ export class UploadCommand extends BaseCommand {
     return {
       accountId,
       ipfsCid: await this.computeIpfsHash(),
+      contentHash: { algorithm: 'Sha256', digest: await this.computeSha256() },
+      chunksMerkleRoot: await this.computeChunksMerkleRoot(),
       contentId: ContentId.generate(this.api.api.registry),
       fileSize: new BN(this.getFileSize()),
       dataObjectTypeId,
@@ -105,7 +134,9 @@ export class UploadCommand extends BaseCommand {
         p.contentId,
         p.dataObjectTypeId,
         p.fileSize,
-        p.ipfsCid
+        p.ipfsCid,
+        p.contentHash,
+        p.chunksMerkleRoot
       )
 
       return dataObject
@@ -21,6 +21,9 @@ const PROJECT_ROOT = path.resolve(__dirname, '..')
 // Number of milliseconds to wait between synchronization runs.
 const SYNC_PERIOD_MS = 120000 // 2min
 
+// Number of milliseconds to wait between checks for storage challenges.
+const CHALLENGE_PERIOD_MS = 60000 // 1min
+
 // Parse CLI
 const FLAG_DEFINITIONS = {
   port: {
@@ -280,6 +283,9 @@ const commands = {
 
     if (!cli.flags.anonymous) {
       announcePublicUrl(api, publicUrl)
+
+      const { startRespondingToChallenges } = require('../lib/challenges')
+      startRespondingToChallenges(api, { challengePeriod: CHALLENGE_PERIOD_MS }, store)
     }
 
     return startAllServices({ store, api, port, ipfsHttpGatewayUrl, anonymous: cli.flags.anonymous })
@@ -0,0 +1,104 @@
+/*
+ * This file is part of the storage node for the Joystream project.
+ * Copyright (C) 2019 Joystream Contributors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+'use strict'
+
+const debug = require('debug')('joystream:challenges')
+const { chunkMerkleProof } = require('@joystream/storage-node-backend')
+
+// Identifies the challenge, the same relationship may be challenged again later
+function challengeKey(challenge) {
+  return `${challenge.relationship_id}:${challenge.chunk_index}:${challenge.deadline}`
+}
+
+async function respondToChallenge({ api, storage, challenge }) {
+  const roleAddress = api.identities.key.address
+  const providerId = api.storageProviderId
+
+  const relationship = await api.assets.getStorageRelationship(challenge.relationship_id)
+
+  // Only challenges of our own relationships concern us
+  if (!relationship || !relationship.storage_provider.eq(providerId)) {
+    return false
+  }
+
+  const chunkIndex = challenge.chunk_index.toNumber()
+  const chunkHashes = await storage.chunkHashes(relationship.content_id)
+
+  if (chunkIndex >= chunkHashes.length) {
+    throw new Error(`Challenged chunk ${chunkIndex} is out of range of content ${relationship.content_id.encode()}`)
+  }
+
+  // The runtime hashes the submitted chunk itself
+  const chunk = await storage.chunk(relationship.content_id, chunkIndex)
+
+  debug(`Responding to storage challenge of relationship ${challenge.relationship_id} chunk ${chunkIndex}`)
+
+  await api.assets.respondToStorageChallenge(roleAddress, providerId, chunk, chunkMerkleProof(chunkHashes, chunkIndex))
+
+  return true
+}
+
+async function challengesPeriodic({ api, flags, storage, respondedChallenges }) {
+  const retry = () => {
+    setTimeout(challengesPeriodic, flags.challengePeriod, {
+      api,
+      flags,
+      storage,
+      respondedChallenges,
+    })
+  }
+
+  try {
+    const chainIsSyncing = await api.chainIsSyncing()
+    if (chainIsSyncing) {
+      debug('Chain is syncing. Postponing storage challenge check.')
+      return retry()
+    }
+
+    if (!(await api.providerIsActiveWorker())) {
+      debug('storage provider role account and storageProviderId are not associated with a worker.')
+      return retry()
+    }
+
+    const challenge = await api.assets.getCurrentStorageChallenge()
+
+    if (challenge && !respondedChallenges.has(challengeKey(challenge))) {
+      if (await respondToChallenge({ api, storage, challenge })) {
+        respondedChallenges.clear()
+        respondedChallenges.add(challengeKey(challenge))
+      }
+    }
+  } catch (err) {
+    debug(`Error responding to storage challenge ${err.stack}`)
+  }
+
+  // always try again
+  retry()
+}
+
+function startRespondingToChallenges(api, flags, storage) {
+  // challenge already responded to, until the response is included in a block
+  const respondedChallenges = new Set()
+
+  challengesPeriodic({ api, flags, storage, respondedChallenges })
+}
+
+module.exports = {
+  startRespondingToChallenges,
+}
@@ -21,9 +21,22 @@
 const debug = require('debug')('joystream:sync')
 const _ = require('lodash')
 const { ContentId } = require('@joystream/types/storage')
+const { chunksMerkleRoot, chunksMerkleRootMatches } = require('@joystream/storage-node-backend')
 // The number of concurrent sync sessions allowed. Must be greater than zero.
 const MAX_CONCURRENT_SYNC_ITEMS = 20
 
+// Ensure the synced content matches the chunks merkle root of its data object, otherwise
+// the storage challenges of the content could never be passed
+async function verifyChunksMerkleRoot({ api, storage, contentId }) {
+  const dataObject = await api.assets.getDataObject(contentId)
+  const chunkHashes = await storage.chunkHashes(contentId)
+
+  return (
+    dataObject !== null &&
+    chunksMerkleRootMatches(dataObject.chunks_merkle_root.toU8a(true), chunksMerkleRoot(chunkHashes))
+  )
+}
+
 async function syncContent({ api, storage, contentBeingSynced, contentCompleteSynced }) {
   const knownEncodedContentIds = (await api.assets.getAcceptedContentIds()).map((id) => id.encode())
 
@@ -47,13 +60,21 @@ async function syncContent({ api, storage, contentBeingSynced, contentCompleteSy
     try {
       contentBeingSynced.set(id)
       const contentId = ContentId.decode(api.api.registry, id)
-      await storage.synchronize(contentId, (err, status) => {
+      await storage.synchronize(contentId, async (err, status) => {
         if (err) {
           contentBeingSynced.delete(id)
           debug(`Error Syncing ${err}`)
         } else if (status.synced) {
+          try {
+            if (await verifyChunksMerkleRoot({ api, storage, contentId })) {
+              contentCompleteSynced.set(id)
+            } else {
+              debug(`Synced content ${id} does not match its chunks merkle root`)
+            }
+          } catch (err) {
+            debug(`Error verifying chunks merkle root of ${id}: ${err}`)
+          }
           contentBeingSynced.delete(id)
-          contentCompleteSynced.set(id)
         }
       })
     } catch (err) {
@@ -20,6 +20,7 @@
 
 const debug = require('debug')('joystream:colossus:api:asset')
 const filter = require('@joystream/storage-node-backend/filter')
+const { chunksMerkleRootMatches } = require('@joystream/storage-node-backend/chunks')
 const ipfsProxy = require('../../../lib/middleware/ipfs_proxy')
 const assert = require('assert')
 
@@ -159,13 +160,26 @@ module.exports = function (storage, runtime, ipfsHttpGatewayUrl, anonymous) {
 
         // At end of stream we should have file info and computed ipfs hash - this event is emitted
         // only by explicitly calling stream.info() in the stream.on('finish') event handler
-        stream.once('info', async (info, hash) => {
+        stream.once('info', async (info, hash, chunksMerkleRoot) => {
           if (hash === dataObject.ipfs_content_id.toString()) {
             const filterResult = filter({}, req.headers, info.mimeType)
             if (filterResult.code !== 200) {
               debug('Rejecting content')
               stream.cleanup()
               res.status(400).send({ message: 'Rejecting content type' })
+            } else if (!chunksMerkleRootMatches(dataObject.chunks_merkle_root.toU8a(true), chunksMerkleRoot)) {
+              // Storage challenges of the content could never be passed
+              debug('Rejecting content with unexpected chunks merkle root')
+              stream.cleanup()
+              res.status(400).send({ message: 'Rejecting content - Not expected chunks merkle root for content' })
+
+              try {
+                if (dataObject.liaison_judgement.type === 'Pending') {
+                  await runtime.assets.rejectContent(roleAddress, providerId, id, 'Unexpected chunks merkle root')
+                }
+              } catch (err) {
+                debug(`${err.message}`)
+              }
             } else {
               try {
                 await stream.commit()
@@ -30,7 +30,7 @@ class AssetsApi {
   /*
    * Create and return a data object.
    */
-  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid) {
+  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid, contentHash, chunksMerkleRoot) {
     contentId = parseContentId(contentId)
     const owner = {
       Member: memberId,
@@ -41,6 +41,8 @@ class AssetsApi {
         type_id: doTypeId,
         size,
         ipfs_content_id: ipfsCid,
+        content_hash: contentHash,
+        chunks_merkle_root: chunksMerkleRoot,
       },
     ]
     const tx = this.base.api.tx.dataDirectory.addContent(owner, content)
@@ -102,6 +104,15 @@ class AssetsApi {
     return this.base.signAndSend(providerAccoundId, tx)
   }
 
+  /*
+   * Sets the data object liaison judgement to Rejected
+   */
+  async rejectContent(providerAccoundId, storageProviderId, contentId, rationale) {
+    contentId = parseContentId(contentId)
+    const tx = this.base.api.tx.dataDirectory.rejectContent(storageProviderId, contentId, rationale)
+    return this.base.signAndSend(providerAccoundId, tx)
+  }
+
   /*
    * Gets storage relationship for contentId for the given provider
    */
@@ -121,13 +132,43 @@ class AssetsApi {
     return {}
   }
 
+  /*
+   * Returns the id of the storage bucket operated by the provider, that the
+   * data object bag is assigned to. Returns null if there is no such bucket.
+   */
+  async getStorageBucketIdForDataObject(storageProviderId, contentId) {
+    const obj = await this.getDataObject(contentId)
+
+    if (!obj) {
+      return null
+    }
+
+    const bucketIds = await this.base.api.query.dataObjectStorageRegistry.bagStorageBuckets(obj.owner)
+
+    for (const bucketId of bucketIds) {
+      const bucket = await this.base.api.query.dataObjectStorageRegistry.storageBucketById(bucketId)
+      if (bucket.isSome && bucket.unwrap().operator.eq(storageProviderId)) {
+        return bucketId
+      }
+    }
+
+    return null
+  }
+
   /*
    * Creates storage relationship for a data object and provider and
    * returns the relationship id
    */
   async createStorageRelationship(providerAccountId, storageProviderId, contentId) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataObjectStorageRegistry.addRelationship(storageProviderId, contentId)
+
+    const bucketId = await this.getStorageBucketIdForDataObject(storageProviderId, contentId)
+
+    if (bucketId === null) {
+      throw new Error(`No storage bucket operated by this storage node for the content ID: ${contentId}`)
+    }
+
+    const tx = this.base.api.tx.dataObjectStorageRegistry.addRelationship(storageProviderId, bucketId, contentId)
 
     return this.base.signAndSendThenGetEventResult(providerAccountId, tx, {
       module: 'dataObjectStorageRegistry',
@@ -147,6 +188,30 @@ class AssetsApi {
     return this.base.signAndSend(providerAccountId, tx)
   }
 
+  /*
+   * Returns the storage challenge awaiting the response, or null if there is none.
+   */
+  async getCurrentStorageChallenge() {
+    const challenge = await this.base.api.query.dataObjectStorageRegistry.currentStorageChallenge()
+    return challenge.isSome ? challenge.unwrap() : null
+  }
+
+  /*
+   * Returns the data object storage relationship, or null if it doesn't exist.
+   */
+  async getStorageRelationship(dosrId) {
+    const relationship = await this.base.api.query.dataObjectStorageRegistry.relationships(dosrId)
+    return relationship.isSome ? relationship.unwrap() : null
+  }
+
+  /*
+   * Responds to the storage challenge with the challenged chunk bytes and its merkle proof
+   */
+  async respondToStorageChallenge(providerAccountId, storageProviderId, chunk, proof) {
+    const tx = this.base.api.tx.dataObjectStorageRegistry.respondToStorageChallenge(storageProviderId, chunk, proof)
+    return this.base.signAndSend(providerAccountId, tx)
+  }
+
   /*
    * Returns array of all the content ids in storage
    */
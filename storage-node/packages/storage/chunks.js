@@ -0,0 +1,150 @@
+/*
+ * This file is part of the storage node for the Joystream project.
+ * Copyright (C) 2019 Joystream Contributors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+'use strict'
+
+const { blake2AsU8a } = require('@polkadot/util-crypto')
+const { u8aConcat } = require('@polkadot/util')
+
+// Size of the content chunks the merkle tree is built from, must match the runtime.
+const DATA_OBJECT_CHUNK_SIZE = 262144
+
+/*
+ * Hashes of the consecutive content chunks read from the stream. Empty content
+ * consists of a single empty chunk.
+ */
+function chunkHashes(readable) {
+  return new Promise((resolve, reject) => {
+    const hashes = []
+    let buf = Buffer.alloc(0)
+
+    readable.on('data', (data) => {
+      buf = Buffer.concat([buf, data])
+
+      while (buf.byteLength >= DATA_OBJECT_CHUNK_SIZE) {
+        hashes.push(blake2AsU8a(buf.subarray(0, DATA_OBJECT_CHUNK_SIZE)))
+        buf = buf.subarray(DATA_OBJECT_CHUNK_SIZE)
+      }
+    })
+
+    readable.on('end', () => {
+      if (buf.byteLength || !hashes.length) {
+        hashes.push(blake2AsU8a(buf))
+      }
+      resolve(hashes)
+    })
+
+    readable.on('error', reject)
+  })
+}
+
+/*
+ * Content chunk of the given index read from the stream, null if the content has
+ * less chunks. Empty content consists of a single empty chunk.
+ */
+function readChunk(readable, chunkIndex) {
+  return new Promise((resolve, reject) => {
+    const start = chunkIndex * DATA_OBJECT_CHUNK_SIZE
+    const end = start + DATA_OBJECT_CHUNK_SIZE
+    const parts = []
+    let offset = 0
+
+    readable.on('data', (data) => {
+      const from = Math.max(start - offset, 0)
+      const to = Math.min(end - offset, data.byteLength)
+
+      if (from < to) {
+        parts.push(data.subarray(from, to))
+      }
+      offset += data.byteLength
+    })
+
+    readable.on('end', () => {
+      resolve(offset > start || chunkIndex === 0 ? Buffer.concat(parts) : null)
+    })
+
+    readable.on('error', reject)
+  })
+}
+
+/*
+ * Next level of the merkle tree, the last node of a level without a sibling
+ * is promoted to the next level.
+ */
+function nextLevel(level) {
+  const next = []
+
+  for (let i = 0; i < level.length; i += 2) {
+    next.push(i + 1 < level.length ? blake2AsU8a(u8aConcat(level[i], level[i + 1])) : level[i])
+  }
+
+  return next
+}
+
+/*
+ * Root of the merkle tree with the chunk hashes as leaves.
+ */
+function chunksMerkleRoot(hashes) {
+  let level = hashes
+
+  while (level.length > 1) {
+    level = nextLevel(level)
+  }
+
+  return level[0]
+}
+
+/*
+ * Sibling hashes proving the chunk hash is a leaf of the merkle tree, from the
+ * bottom level up. Levels where the node is promoted have no sibling.
+ */
+function chunkMerkleProof(hashes, chunkIndex) {
+  const proof = []
+  let level = hashes
+  let index = chunkIndex
+
+  while (level.length > 1) {
+    const siblingIndex = index % 2 === 1 ? index - 1 : index + 1
+
+    if (siblingIndex < level.length) {
+      proof.push(level[siblingIndex])
+    }
+
+    level = nextLevel(level)
+    index = Math.floor(index / 2)
+  }
+
+  return proof
+}
+
+/*
+ * Whether the merkle root matches the one expected by the data object. Content
+ * uploaded before the chunks merkle roots has none, so any root matches.
+ */
+function chunksMerkleRootMatches(expectedRoot, root) {
+  return !expectedRoot.length || Buffer.from(expectedRoot).equals(Buffer.from(root))
+}
+
+module.exports = {
+  DATA_OBJECT_CHUNK_SIZE,
+  chunkHashes,
+  readChunk,
+  chunksMerkleRoot,
+  chunkMerkleProof,
+  chunksMerkleRootMatches,
+}
@@ -19,7 +19,11 @@
 'use strict'
 
 const { Storage } = require('./storage')
+const { chunksMerkleRoot, chunkMerkleProof, chunksMerkleRootMatches } = require('./chunks')
 
 module.exports = {
   Storage,
+  chunksMerkleRoot,
+  chunkMerkleProof,
+  chunksMerkleRootMatches,
 }
@@ -27,6 +27,8 @@ const Promise = require('bluebird')
 
 const Hash = require('ipfs-only-hash')
 
+const { chunkHashes, readChunk, chunksMerkleRoot } = require('./chunks')
+
 Promise.config({
   cancellation: true,
 })
@@ -161,11 +163,17 @@ class StorageWriteStream extends Transform {
       this.hash = await Hash.of(read)
     }
 
-    this.emit('info', this.fileInfo, this.hash)
+    if (!this.chunksMerkleRoot) {
+      const read = fs.createReadStream(this.temp.path)
+      this.chunksMerkleRoot = chunksMerkleRoot(await chunkHashes(read))
+    }
+
+    this.emit('info', this.fileInfo, this.hash, this.chunksMerkleRoot)
 
     return {
       info: this.fileInfo,
       hash: this.hash,
+      chunksMerkleRoot: this.chunksMerkleRoot,
     }
   }
 
@@ -391,6 +399,22 @@ class Storage {
     })
   }
 
+  /*
+   * Hashes of the content chunks, the leaves of the content chunks merkle tree
+   */
+  async chunkHashes(contentId, timeout) {
+    const stream = await this.createReadStream(contentId, timeout)
+    return chunkHashes(stream)
+  }
+
+  /*
+   * Content chunk of the given index, null if the content has less chunks
+   */
+  async chunk(contentId, chunkIndex, timeout) {
+    const stream = await this.createReadStream(contentId, timeout)
+    return readChunk(stream, chunkIndex)
+  }
+
   /*
    * Synchronize the given content ID
    */
@@ -0,0 +1,128 @@
+/*
+ * This file is part of the storage node for the Joystream project.
+ * Copyright (C) 2019 Joystream Contributors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+'use strict'
+
+const expect = require('chai').expect
+const { Readable } = require('stream')
+const { blake2AsU8a } = require('@polkadot/util-crypto')
+const { u8aConcat } = require('@polkadot/util')
+
+const {
+  DATA_OBJECT_CHUNK_SIZE,
+  chunkHashes,
+  readChunk,
+  chunksMerkleRoot,
+  chunkMerkleProof,
+  chunksMerkleRootMatches,
+} = require('../chunks')
+
+// Recompute the root from the chunk hash and proof the same way the runtime verifies it
+function verifyProof(root, chunkIndex, chunksCount, chunkHash, proof) {
+  const siblings = [...proof]
+  let hash = chunkHash
+  let index = chunkIndex
+  let levelWidth = chunksCount
+
+  while (levelWidth > 1) {
+    if (index % 2 === 1 || index + 1 < levelWidth) {
+      const sibling = siblings.shift()
+      hash = index % 2 === 1 ? blake2AsU8a(u8aConcat(sibling, hash)) : blake2AsU8a(u8aConcat(hash, sibling))
+    }
+    index = Math.floor(index / 2)
+    levelWidth = Math.floor(levelWidth / 2) + (levelWidth % 2)
+  }
+
+  return !siblings.length && Buffer.from(hash).equals(Buffer.from(root))
+}
+
+describe('storage/chunks', () => {
+  describe('chunkHashes()', () => {
+    it('hashes consecutive chunks regardless of stream data boundaries', async () => {
+      const content = Buffer.alloc(2 * DATA_OBJECT_CHUNK_SIZE + 10, 7)
+      const hashes = await chunkHashes(Readable.from([content.subarray(0, 100), content.subarray(100)]))
+
+      expect(hashes.length).to.equal(3)
+      expect(hashes[2]).to.eql(blake2AsU8a(content.subarray(2 * DATA_OBJECT_CHUNK_SIZE)))
+    })
+
+    it('treats empty content as a single empty chunk', async () => {
+      const hashes = await chunkHashes(Readable.from([]))
+
+      expect(hashes).to.eql([blake2AsU8a(new Uint8Array())])
+    })
+  })
+
+  describe('readChunk()', () => {
+    it('reads the chunk regardless of stream data boundaries', async () => {
+      const content = Buffer.alloc(2 * DATA_OBJECT_CHUNK_SIZE + 10, 7)
+      content[DATA_OBJECT_CHUNK_SIZE] = 1
+      const readable = () => Readable.from([content.subarray(0, 100), content.subarray(100)])
+
+      const secondChunk = content.subarray(DATA_OBJECT_CHUNK_SIZE, 2 * DATA_OBJECT_CHUNK_SIZE)
+
+      expect(await readChunk(readable(), 1)).to.eql(secondChunk)
+      expect(await readChunk(readable(), 2)).to.eql(content.subarray(2 * DATA_OBJECT_CHUNK_SIZE))
+      expect(await readChunk(readable(), 3)).to.equal(null)
+    })
+
+    it('reads the single empty chunk of empty content', async () => {
+      expect(await readChunk(Readable.from([]), 0)).to.eql(Buffer.alloc(0))
+    })
+  })
+
+  describe('chunksMerkleRoot()', () => {
+    it('is the chunk hash of single chunk content', () => {
+      const hash = blake2AsU8a(Buffer.from('single chunk'))
+
+      expect(chunksMerkleRoot([hash])).to.eql(hash)
+    })
+
+    it('promotes the last node of a level without a sibling', () => {
+      const hashes = [1, 2, 3].map((i) => blake2AsU8a(Buffer.from([i])))
+      const left = blake2AsU8a(u8aConcat(hashes[0], hashes[1]))
+
+      expect(chunksMerkleRoot(hashes)).to.eql(blake2AsU8a(u8aConcat(left, hashes[2])))
+    })
+  })
+
+  describe('chunkMerkleProof()', () => {
+    it('proves every chunk against the root', () => {
+      const hashes = [1, 2, 3, 4, 5].map((i) => blake2AsU8a(Buffer.from([i])))
+      const root = chunksMerkleRoot(hashes)
+
+      hashes.forEach((hash, index) => {
+        const proof = chunkMerkleProof(hashes, index)
+        expect(verifyProof(root, index, hashes.length, hash, proof)).to.equal(true)
+      })
+    })
+  })
+
+  describe('chunksMerkleRootMatches()', () => {
+    it('compares the root with the expected one', () => {
+      const root = blake2AsU8a(Buffer.from([1]))
+
+      expect(chunksMerkleRootMatches(root, root)).to.equal(true)
+      expect(chunksMerkleRootMatches(blake2AsU8a(Buffer.from([2])), root)).to.equal(false)
+    })
+
+    it('matches any root of content without the expected root', () => {
+      expect(chunksMerkleRootMatches(new Uint8Array(), blake2AsU8a(Buffer.from([1])))).to.equal(true)
+    })
+  })
+})
@@ -1,8 +1,8 @@
 import { Vec, Option, Tuple } from '@polkadot/types'
 import { bool, u64, u32, u128, Null, Bytes } from '@polkadot/types/primitive'
 import { MemberId } from '../members'
-import { JoyStructDecorated, JoyEnum, ChannelId, JoyBTreeSet, DAOId, Url } from '../common'
-import { ContentParameters } from '../storage'
+import { JoyStructDecorated, JoyEnum, ChannelId, JoyBTreeSet, DAOId, Url, Hash } from '../common'
+import { ContentId, ContentParameters } from '../storage'
 import { GenericAccountId as AccountId } from '@polkadot/types/generic/AccountId'
 
 export class CuratorId extends u64 {}
@@ -22,15 +22,25 @@ export class NewAsset extends JoyEnum({
   Urls: Vec.with(Url),
 }) {}
 
+export class CuratorGroupPermission extends JoyEnum({
+  CreateChannels: Null,
+  ManageChannels: Null,
+  CensorContent: Null,
+  ManageCategories: Null,
+  SetFeaturedVideos: Null,
+}) {}
+
 export class CuratorGroup extends JoyStructDecorated({
   curators: JoyBTreeSet(CuratorId),
   active: bool,
+  permissions: JoyBTreeSet(CuratorGroupPermission),
 }) {}
 
 export class ContentActor extends JoyEnum({
   Curator: Tuple.with([CuratorGroupId, CuratorId]),
   Member: MemberId,
   Lead: Null,
+  Dao: DAOId,
 }) {}
 
 export class ChannelOwner extends JoyEnum({
@@ -39,6 +49,12 @@ export class ChannelOwner extends JoyEnum({
   Dao: DAOId,
 }) {}
 
+export class ChannelCollaboratorPermission extends JoyEnum({
+  UploadVideos: Null,
+  EditMetadata: Null,
+  ManagePlaylists: Null,
+}) {}
+
 export class Channel extends JoyStructDecorated({
   owner: ChannelOwner,
   videos: Vec.with(VideoId),
@@ -46,6 +62,8 @@ export class Channel extends JoyStructDecorated({
   series: Vec.with(SeriesId),
   is_censored: bool,
   reward_account: Option.with(AccountId),
+  assets: Vec.with(ContentId),
+  category: Option.with(ChannelCategoryId),
 }) {}
 
 export class ChannelCreationParameters extends JoyStructDecorated({
@@ -61,18 +79,92 @@ export class ChannelUpdateParameters extends JoyStructDecorated({
 }) {}
 
 export class ChannelOwnershipTransferRequest extends JoyStructDecorated({
+  channel_id: ChannelId,
+  new_owner: ChannelOwner,
+  payment: u128,
+  payer: AccountId,
+  new_reward_account: Option.with(AccountId),
+}) {}
+
+export class ChannelOwnershipTransferRequestParameters extends JoyStructDecorated({
   channel_id: ChannelId,
   new_owner: ChannelOwner,
   payment: u128,
   new_reward_account: Option.with(AccountId),
 }) {}
 
+export class CensorshipAppealId extends u64 {}
+
+export class CensoredContent extends JoyEnum({
+  Channel: ChannelId,
+  Video: VideoId,
+}) {}
+
+export class CensorshipAppealStatus extends JoyEnum({
+  Pending: Null,
+  Accepted: Null,
+  Rejected: Null,
+  Expired: Null,
+  Closed: Null,
+}) {}
+
+export class CensorshipAppeal extends JoyStructDecorated({
+  censored: CensoredContent,
+  appellant: AccountId,
+  stake: u128,
+  deadline: u32,
+  status: CensorshipAppealStatus,
+}) {}
+
+export class ContentReportId extends u64 {}
+export class SubscriptionTierId extends u64 {}
+
+export class SubscriptionTier extends JoyStructDecorated({
+  price: u128,
+  period: u32,
+}) {}
+
+export class Subscription extends JoyStructDecorated({
+  tier_id: SubscriptionTierId,
+  payer: AccountId,
+  paid_until: u32,
+  auto_renew: bool,
+}) {}
+
+export class ReportReason extends JoyEnum({
+  Spam: Null,
+  Harassment: Null,
+  IllegalContent: Null,
+  CopyrightInfringement: Null,
+  Other: Null,
+}) {}
+
+export class ContentReportStatus extends JoyEnum({
+  Pending: Null,
+  Upheld: Null,
+  Dismissed: Null,
+  Closed: Null,
+}) {}
+
+export class ContentReport extends JoyStructDecorated({
+  content: CensoredContent,
+  reporter: MemberId,
+  reporter_account: AccountId,
+  stake: u128,
+  reason: ReportReason,
+  status: ContentReportStatus,
+}) {}
+
 export class ChannelCategory extends JoyStructDecorated({
-  // No runtime information is currently stored for a Category.
+  parent_category_id: Option.with(ChannelCategoryId),
+  depth: u32,
+  subcategories_count: u32,
+  items_count: u64,
 }) {}
 
 export class ChannelCategoryCreationParameters extends JoyStructDecorated({
   meta: Bytes,
+  parent_category_id: Option.with(ChannelCategoryId),
 }) {}
 
 export class ChannelCategoryUpdateParameters extends JoyStructDecorated({
@@ -80,26 +172,106 @@ export class ChannelCategoryUpdateParameters extends JoyStructDecorated({
 }) {}
 
 export class VideoCategory extends JoyStructDecorated({
-  // No runtime information is currently stored for a Category.
+  parent_category_id: Option.with(VideoCategoryId),
+  depth: u32,
+  subcategories_count: u32,
+  items_count: u64,
 }) {}
 
 export class VideoCategoryCreationParameters extends JoyStructDecorated({
   meta: Bytes,
+  parent_category_id: Option.with(VideoCategoryId),
 }) {}
 
 export class VideoCategoryUpdateParameters extends JoyStructDecorated({
   new_meta: Bytes,
 }) {}
 
+export class VideoVisibility extends JoyEnum({
+  Draft: Null,
+  Unlisted: Null,
+  Public: Null,
+}) {}
+
 export class Video extends JoyStructDecorated({
   in_channel: ChannelId,
   in_series: Option.with(SeriesId),
   is_censored: bool,
+  assets: Vec.with(ContentId),
+  category: Option.with(VideoCategoryId),
+  visibility: VideoVisibility,
+  publish_at: Option.with(u32),
+}) {}
+
+export class NftOwner extends JoyEnum({
+  ChannelOwner: Null,
+  Member: MemberId,
+}) {}
+
+export class FixedPriceSale extends JoyStructDecorated({
+  price: u128,
+  seller_account: AccountId,
+}) {}
+
+export class Bid extends JoyStructDecorated({
+  bidder: MemberId,
+  bidder_account: AccountId,
+  amount: u128,
+}) {}
+
+export class EnglishAuction extends JoyStructDecorated({
+  starting_price: u128,
+  min_bid_step: u128,
+  end: u32,
+  seller_account: AccountId,
+  top_bid: Option.with(Bid),
+}) {}
+
+export class AuctionParams extends JoyStructDecorated({
+  starting_price: u128,
+  min_bid_step: u128,
+  duration: u32,
+}) {}
+
+export class NftTransactionalStatus extends JoyEnum({
+  Idle: Null,
+  BuyNow: FixedPriceSale,
+  Auction: EnglishAuction,
+}) {}
+
+export class OwnedNft extends JoyStructDecorated({
+  owner: NftOwner,
+  creator_royalty: Option.with(u32),
+  transactional_status: NftTransactionalStatus,
+}) {}
+
+export class ChannelRewardClaim extends JoyStructDecorated({
+  channel_id: ChannelId,
+  cumulative_earnings: u128,
+}) {}
+
+export class Side extends JoyEnum({
+  Left: Null,
+  Right: Null,
+}) {}
+
+export class ProofElement extends JoyStructDecorated({
+  hash: Hash,
+  side: Side,
+}) {}
+
+export class CommentId extends u64 {}
+
+export class Comment extends JoyStructDecorated({
+  author: MemberId,
+  parent_comment_id: Option.with(CommentId),
+  is_moderated: bool,
 }) {}
 
 export class VideoCreationParameters extends JoyStructDecorated({
   assets: Vec.with(NewAsset),
   meta: Bytes,
+  visibility: VideoVisibility,
 }) {}
 
 export class VideoUpdateParameters extends JoyStructDecorated({
@@ -109,14 +281,19 @@ export class VideoUpdateParameters extends JoyStructDecorated({
 
 export class Playlist extends JoyStructDecorated({
   in_channel: ChannelId,
+  videos: Vec.with(VideoId),
+  cross_channel: bool,
 }) {}
 
 export class PlaylistCreationParameters extends JoyStructDecorated({
+  videos: Vec.with(VideoId),
+  cross_channel: bool,
   meta: Bytes,
 }) {}
 
 export class PlaylistUpdateParameters extends JoyStructDecorated({
-  new_meta: Bytes,
+  videos: Option.with(Vec.with(VideoId)),
+  new_meta: Option.with(Bytes),
 }) {}
 
 export class EpisodeParemters extends JoyEnum({
@@ -137,6 +314,7 @@ export class SeasonParameters extends JoyStructDecorated({
 export class Series extends JoyStructDecorated({
   in_channel: ChannelId,
   seasons: Vec.with(Season),
+  assets: Vec.with(ContentId),
 }) {}
 
 export class SeriesParameters extends JoyStructDecorated({
@@ -152,6 +330,7 @@ export class PersonController extends JoyEnum({
 
 export class Person extends JoyStructDecorated({
   controlled_by: PersonController,
+  assets: Vec.with(ContentId),
 }) {}
 
 export class PersonCreationParameters extends JoyStructDecorated({
@@ -161,7 +340,7 @@ export class PersonCreationParameters extends JoyStructDecorated({
 
 export class PersonUpdateParameters extends JoyStructDecorated({
   assets: Option.with(Vec.with(NewAsset)),
-  meta: Option.with(Bytes),
+  new_meta: Option.with(Bytes),
 }) {}
 
 export class PersonActor extends JoyEnum({
@@ -172,11 +351,13 @@ export class PersonActor extends JoyEnum({
 export const contentTypes = {
   CuratorId,
   CuratorGroupId,
+  CuratorGroupPermission,
   CuratorGroup,
   ContentActor,
   NewAsset,
   Channel,
   ChannelOwner,
+  ChannelCollaboratorPermission,
   ChannelCategoryId,
   ChannelCategory,
   ChannelCategoryCreationParameters,
@@ -185,14 +366,39 @@ export const contentTypes = {
   ChannelUpdateParameters,
   ChannelOwnershipTransferRequestId,
   ChannelOwnershipTransferRequest,
+  ChannelOwnershipTransferRequestParameters,
+  CensorshipAppealId,
+  CensoredContent,
+  CensorshipAppealStatus,
+  CensorshipAppeal,
+  ContentReportId,
+  ReportReason,
+  ContentReportStatus,
+  ContentReport,
+  SubscriptionTierId,
+  SubscriptionTier,
+  Subscription,
   Video,
   VideoId,
+  VideoVisibility,
   VideoCategoryId,
   VideoCategory,
   VideoCategoryCreationParameters,
   VideoCategoryUpdateParameters,
   VideoCreationParameters,
   VideoUpdateParameters,
+  CommentId,
+  Comment,
+  NftOwner,
+  FixedPriceSale,
+  Bid,
+  EnglishAuction,
+  AuctionParams,
+  NftTransactionalStatus,
+  OwnedNft,
+  ChannelRewardClaim,
+  Side,
+  ProofElement,
   Person,
   PersonId,
   PersonController,
@@ -0,0 +1,26 @@
+import { u32, u64, Bytes } from '@polkadot/types'
+import { RegistryTypes } from '@polkadot/types/types'
+import { JoyStructDecorated, JoyBTreeSet } from './common'
+import { MemberId } from './members'
+
+export class DaoProposalId extends u64 {}
+
+export class DaoRecord extends JoyStructDecorated({
+  members: JoyBTreeSet(MemberId),
+  threshold: u32,
+}) {}
+
+export class DaoProposal extends JoyStructDecorated({
+  proposer: MemberId,
+  call: Bytes,
+  approvals: JoyBTreeSet(MemberId),
+  weight: u64, // Weight
+}) {}
+
+export const daoTypes: RegistryTypes = {
+  DaoProposalId,
+  DaoRecord,
+  DaoProposal,
+}
+
+export default daoTypes
@@ -12,6 +12,7 @@ import workingGroup from './working-group'
 import storage from './storage'
 import proposals from './proposals'
 import content from './content'
+import dao from './dao'
 import legacy from './legacy'
 import { InterfaceTypes } from '@polkadot/types/types/registry'
 import { TypeRegistry, Text, UInt, Null, bool, Option, Vec, BTreeSet, BTreeMap } from '@polkadot/types'
@@ -33,6 +34,7 @@ export {
   storage,
   proposals,
   content,
+  dao,
 }
 
 export const types: RegistryTypes = {
@@ -51,6 +53,7 @@ export const types: RegistryTypes = {
   ...storage,
   ...proposals,
   ...content,
+  ...dao,
 }
 
 // Allows creating types without api instance (it's not a recommended way though, so should be used just for mocks)
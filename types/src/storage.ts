@@ -1,4 +1,4 @@
-import { BTreeMap, u64, bool, Text, Null, Bytes, Option } from '@polkadot/types'
+import { BTreeMap, u32, u64, bool, Text, Null, Bytes, Option } from '@polkadot/types'
 import { BlockAndTime, JoyEnum, JoyStructDecorated, Hash, ChannelId, DAOId, WorkingGroup } from './common'
 import { MemberId } from './members'
 import { StorageProviderId } from './working-group' // this should be in discovery really
@@ -28,10 +28,12 @@ export class ContentId extends Hash {
 
 export class DataObjectTypeId extends u64 {}
 export class DataObjectStorageRelationshipId extends u64 {}
+export class StorageBucketId extends u64 {}
 
 export const LiaisonJudgementDef = {
   Pending: Null,
   Accepted: Null,
+  Rejected: Null,
 } as const
 export type LiaisonJudgementKey = keyof typeof LiaisonJudgementDef
 export class LiaisonJudgement extends JoyEnum(LiaisonJudgementDef) {}
@@ -44,11 +46,24 @@ export class StorageObjectOwner extends JoyEnum({
   WorkingGroup: WorkingGroup,
 }) {}
 
+export class ContentHashAlgorithm extends JoyEnum({
+  Sha256: Null,
+  Sha512: Null,
+  Blake2b256: Null,
+}) {}
+
+export class ContentHash extends JoyStructDecorated({
+  algorithm: ContentHashAlgorithm,
+  digest: Bytes,
+}) {}
+
 export class ContentParameters extends JoyStructDecorated({
   content_id: ContentId,
   type_id: DataObjectTypeId,
   size: u64,
   ipfs_content_id: Bytes,
+  content_hash: ContentHash,
+  chunks_merkle_root: Bytes,
 }) {
   /** Actually it's 'size', but 'size' is already reserved by a parent class. */
   get size_in_bytes(): u64 {
@@ -64,6 +79,8 @@ export class DataObject extends JoyStructDecorated({
   liaison: Option.with(StorageProviderId),
   liaison_judgement: LiaisonJudgement,
   ipfs_content_id: Text,
+  content_hash: ContentHash,
+  chunks_merkle_root: Bytes,
 }) {
   /** Actually it's 'size', but 'size' is already reserved by a parent class. */
   get size_in_bytes(): u64 {
@@ -74,12 +91,14 @@ export class DataObject extends JoyStructDecorated({
 export class DataObjectStorageRelationship extends JoyStructDecorated({
   content_id: ContentId,
   storage_provider: StorageProviderId,
+  storage_bucket_id: StorageBucketId,
   ready: bool,
 }) {}
 
 export class DataObjectType extends JoyStructDecorated({
   description: Text,
   active: bool,
+  replication_factor: u32,
 }) {}
 
 export class DataObjectsMap extends BTreeMap.with(ContentId, DataObject) {}
@@ -93,6 +112,19 @@ export class Voucher extends JoyStructDecorated({
   objects_used: u64,
 }) {}
 
+export class StorageBucket extends JoyStructDecorated({
+  operator: Option.with(StorageProviderId),
+  accepting_new_bags: bool,
+  voucher: Voucher,
+  assigned_bags: u64,
+}) {}
+
+export class StorageChallenge extends JoyStructDecorated({
+  relationship_id: DataObjectStorageRelationshipId,
+  chunk_index: u64,
+  deadline: u32, // BlockNumber
+}) {}
+
 // These types names only in the data_directory Events, do they really need a type name alias?
 export class VoucherLimit extends u64 {}
 export class UploadingStatus extends bool {}
@@ -103,12 +135,18 @@ export const mediaTypes: RegistryTypes = {
   DataObject,
   DataObjectStorageRelationshipId,
   DataObjectStorageRelationship,
+  StorageBucketId,
+  StorageBucket,
+  StorageChallenge,
   DataObjectTypeId,
   DataObjectType,
   DataObjectsMap,
+  ContentHashAlgorithm,
+  ContentHash,
   ContentParameters,
   StorageObjectOwner,
   ObjectOwner: StorageObjectOwner,
+  BagId: StorageObjectOwner,
   Voucher,
   VoucherLimit,
   UploadingStatus,
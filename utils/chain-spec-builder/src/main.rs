@@ -89,6 +89,10 @@ enum ChainSpecBuilder {
         /// The path to an initial content directory data file
         #[structopt(long, short)]
         initial_content_path: Option<PathBuf>,
+        /// The path to an initial content directory catalogue (curator groups, categories,
+        /// channels and videos) data file
+        #[structopt(long)]
+        initial_content_directory_path: Option<PathBuf>,
         /// The path to an initial balances file
         #[structopt(long, short)]
         initial_balances_path: Option<PathBuf>,
@@ -124,6 +128,10 @@ enum ChainSpecBuilder {
         /// The path to an initial content directory data file
         #[structopt(long, short)]
         initial_content_path: Option<PathBuf>,
+        /// The path to an initial content directory catalogue (curator groups, categories,
+        /// channels and videos) data file
+        #[structopt(long)]
+        initial_content_directory_path: Option<PathBuf>,
         /// The path to an initial balances file
         #[structopt(long, short)]
         initial_balances_path: Option<PathBuf>,
@@ -186,6 +194,20 @@ impl ChainSpecBuilder {
         }
     }
 
+    /// Returns the path to load initial content directory catalogue from
+    fn initial_content_directory_path(&self) -> &Option<PathBuf> {
+        match self {
+            ChainSpecBuilder::New {
+                initial_content_directory_path,
+                ..
+            } => initial_content_directory_path,
+            ChainSpecBuilder::Generate {
+                initial_content_directory_path,
+                ..
+            } => initial_content_directory_path,
+        }
+    }
+
     /// Returns the path to load initial platform content from
     fn initial_balances_path(&self) -> &Option<PathBuf> {
         match self {
@@ -224,6 +246,7 @@ fn genesis_constructor(
     initial_members_path: &Option<PathBuf>,
     initial_forum_path: &Option<PathBuf>,
     initial_content_path: &Option<PathBuf>,
+    initial_content_directory_path: &Option<PathBuf>,
     initial_balances_path: &Option<PathBuf>,
 ) -> chain_spec::GenesisConfig {
     let authorities = authority_seeds
@@ -250,6 +273,11 @@ fn genesis_constructor(
         content_config::empty_data_directory_config()
     };
 
+    let content_cfg = initial_content_directory_path
+        .as_ref()
+        .map(|path| content_config::content_config_from_json(path.as_path()))
+        .unwrap_or_else(content_config::empty_content_config);
+
     let initial_account_balances = initial_balances_path
         .as_ref()
         .map(|path| initial_balances::from_json(path.as_path()))
@@ -269,6 +297,7 @@ fn genesis_constructor(
         members,
         forum_cfg,
         data_directory_config,
+        content_cfg,
         initial_account_balances,
     )
 }
@@ -284,6 +313,7 @@ fn generate_chain_spec(
     initial_members_path: Option<PathBuf>,
     initial_forum_path: Option<PathBuf>,
     initial_content_path: Option<PathBuf>,
+    initial_content_directory_path: Option<PathBuf>,
     initial_balances_path: Option<PathBuf>,
 ) -> Result<String, String> {
     let parse_account = |address: &String| {
@@ -318,6 +348,7 @@ fn generate_chain_spec(
                 &initial_members_path,
                 &initial_forum_path,
                 &initial_content_path,
+                &initial_content_directory_path,
                 &initial_balances_path,
             )
         },
@@ -399,6 +430,7 @@ fn main() -> Result<(), String> {
     let initial_members_path = builder.initial_members_path().clone();
     let initial_forum_path = builder.initial_forum_path().clone();
     let initial_content_path = builder.initial_content_path().clone();
+    let initial_content_directory_path = builder.initial_content_directory_path().clone();
     let initial_balances_path = builder.initial_balances_path().clone();
     let deployment = builder.chain_deployment();
 
@@ -451,6 +483,7 @@ fn main() -> Result<(), String> {
         initial_members_path,
         initial_forum_path,
         initial_content_path,
+        initial_content_directory_path,
         initial_balances_path,
     )?;
 